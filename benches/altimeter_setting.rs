@@ -0,0 +1,19 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::altimeter_setting;
+
+pub fn altimeter_setting_benchmark(c: &mut Criterion) {
+    c.bench_function("altimeter_setting::wmo1", |b| {
+        b.iter(|| altimeter_setting::wmo1(black_box(90000.0), black_box(1000.0)))
+    });
+
+    c.bench_function("altimeter_setting::wmo2", |b| {
+        b.iter(|| altimeter_setting::wmo2(black_box(100_000.0), black_box(1000.0)))
+    });
+
+    c.bench_function("altimeter_setting::nws1", |b| {
+        b.iter(|| altimeter_setting::nws1(black_box(90000.0), black_box(1000.0)))
+    });
+}
+
+criterion_group!(benches, altimeter_setting_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::apparent_temperature;
+
+pub fn apparent_temperature_benchmark(c: &mut Criterion) {
+    c.bench_function("apparent_temperature::steadman1", |b| {
+        b.iter(|| {
+            apparent_temperature::steadman1(black_box(303.0), black_box(2000.0), black_box(3.0))
+        })
+    });
+
+    c.bench_function("apparent_temperature::steadman2", |b| {
+        b.iter(|| {
+            apparent_temperature::steadman2(
+                black_box(303.0),
+                black_box(2000.0),
+                black_box(3.0),
+                black_box(400.0),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, apparent_temperature_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::comfort_index;
+
+pub fn comfort_index_benchmark(c: &mut Criterion) {
+    c.bench_function("comfort_index::humidex1", |b| {
+        b.iter(|| comfort_index::humidex1(black_box(303.0), black_box(293.0)))
+    });
+
+    c.bench_function("comfort_index::humidex2", |b| {
+        b.iter(|| comfort_index::humidex2(black_box(303.0), black_box(2000.0)))
+    });
+
+    c.bench_function("comfort_index::discomfort_index1", |b| {
+        b.iter(|| comfort_index::discomfort_index1(black_box(303.0), black_box(0.5)))
+    });
+
+    c.bench_function("comfort_index::temperature_humidity_index1", |b| {
+        b.iter(|| comfort_index::temperature_humidity_index1(black_box(303.0), black_box(0.5)))
+    });
+
+    c.bench_function("comfort_index::effective_temperature1", |b| {
+        b.iter(|| comfort_index::effective_temperature1(black_box(303.0), black_box(0.5)))
+    });
+}
+
+criterion_group!(benches, comfort_index_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::contrail;
+
+pub fn contrail_benchmark(c: &mut Criterion) {
+    c.bench_function("contrail::mixing_line_slope1", |b| {
+        b.iter(|| contrail::mixing_line_slope1(black_box(25000.0), black_box(0.3)))
+    });
+
+    c.bench_function("contrail::threshold_temperature1", |b| {
+        b.iter(|| {
+            contrail::threshold_temperature1(black_box(0.5), black_box(25000.0), black_box(0.3))
+        })
+    });
+
+    c.bench_function("contrail::schmidt_appleman1", |b| {
+        b.iter(|| {
+            contrail::schmidt_appleman1(
+                black_box(220.0),
+                black_box(0.5),
+                black_box(25000.0),
+                black_box(0.3),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, contrail_benchmark);
+criterion_main!(benches);
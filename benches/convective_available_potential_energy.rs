@@ -0,0 +1,48 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::{convective_available_potential_energy, parcel::ParcelKind, sounding::Sounding};
+
+pub fn convective_available_potential_energy_benchmark(c: &mut Criterion) {
+    let sounding = Sounding::from_hpa_celsius(
+        &[
+            1000.0, 925.0, 850.0, 700.0, 500.0, 400.0, 300.0, 250.0, 200.0,
+        ],
+        &[30.0, 23.5, 18.0, 8.0, -8.5, -19.0, -33.0, -42.0, -52.0],
+        &[22.0, 19.0, 14.0, 1.0, -20.0, -32.0, -45.0, -53.0, -62.0],
+    )
+    .unwrap();
+
+    c.bench_function("convective_available_potential_energy::general1", |b| {
+        b.iter(|| {
+            convective_available_potential_energy::general1(
+                black_box(&sounding),
+                black_box(ParcelKind::SurfaceBased),
+                black_box(Some([273.15, 250.15])),
+            )
+        })
+    });
+
+    c.bench_function(
+        "convective_available_potential_energy::doswell_rasmussen1",
+        |b| {
+            b.iter(|| {
+                convective_available_potential_energy::doswell_rasmussen1(
+                    black_box(&sounding),
+                    black_box(ParcelKind::SurfaceBased),
+                    black_box(Some([273.15, 250.15])),
+                )
+            })
+        },
+    );
+
+    c.bench_function("convective_available_potential_energy::downdraft1", |b| {
+        b.iter(|| {
+            convective_available_potential_energy::downdraft1(
+                black_box(&sounding),
+                black_box([100_000.0, 60000.0]),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, convective_available_potential_energy_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,13 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::density_altitude;
+
+pub fn density_altitude_benchmark(c: &mut Criterion) {
+    c.bench_function("density_altitude::general1", |b| {
+        b.iter(|| {
+            density_altitude::general1(black_box(85000.0), black_box(300.0), black_box(2000.0))
+        })
+    });
+}
+
+criterion_group!(benches, density_altitude_benchmark);
+criterion_main!(benches);
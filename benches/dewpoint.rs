@@ -0,0 +1,33 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::{dewpoint, vapour_pressure::Psychrometer};
+
+pub fn dewpoint_benchmark(c: &mut Criterion) {
+    c.bench_function("dewpoint::buck1", |b| {
+        b.iter(|| dewpoint::buck1(black_box(1800.0), black_box(101325.0)))
+    });
+
+    c.bench_function("dewpoint::psychrometric1", |b| {
+        b.iter(|| {
+            dewpoint::psychrometric1(
+                black_box(293.0),
+                black_box(288.0),
+                black_box(101325.0),
+                black_box(Psychrometer::Aspirated),
+            )
+        })
+    });
+
+    c.bench_function("dewpoint::psychrometric2", |b| {
+        b.iter(|| {
+            dewpoint::psychrometric2(
+                black_box(268.0),
+                black_box(266.0),
+                black_box(101325.0),
+                black_box(Psychrometer::Aspirated),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, dewpoint_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,17 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use floccus::{dual::Dual, equivalent_potential_temperature};
+
+pub fn dual_benchmark(c: &mut Criterion) {
+    c.bench_function("dual::bolton1", |b| {
+        b.iter(|| {
+            equivalent_potential_temperature::bolton1(
+                Dual::<3>::variable(black_box(300.0), 0),
+                Dual::<3>::variable(black_box(101325.0), 1),
+                Dual::<3>::variable(black_box(3000.0), 2),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, dual_benchmark);
+criterion_main!(benches);
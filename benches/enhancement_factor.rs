@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::enhancement_factor;
+
+pub fn enhancement_factor_benchmark(c: &mut Criterion) {
+    c.bench_function("enhancement_factor::buck1", |b| {
+        b.iter(|| enhancement_factor::buck1(black_box(300.0), black_box(101325.0)))
+    });
+
+    c.bench_function("enhancement_factor::buck2", |b| {
+        b.iter(|| enhancement_factor::buck2(black_box(250.0), black_box(101325.0)))
+    });
+
+    c.bench_function("enhancement_factor::buck3", |b| {
+        b.iter(|| enhancement_factor::buck3(black_box(101325.0)))
+    });
+
+    c.bench_function("enhancement_factor::buck4", |b| {
+        b.iter(|| enhancement_factor::buck4(black_box(101325.0)))
+    });
+
+    c.bench_function("enhancement_factor::hardy1", |b| {
+        b.iter(|| enhancement_factor::hardy1(black_box(300.0), black_box(101325.0)))
+    });
+
+    c.bench_function("enhancement_factor::hardy2", |b| {
+        b.iter(|| enhancement_factor::hardy2(black_box(250.0), black_box(101325.0)))
+    });
+
+    c.bench_function("enhancement_factor::greenspan1", |b| {
+        b.iter(|| enhancement_factor::greenspan1(black_box(300.0), black_box(101325.0)))
+    });
+
+    c.bench_function("enhancement_factor::greenspan2", |b| {
+        b.iter(|| enhancement_factor::greenspan2(black_box(250.0), black_box(101325.0)))
+    });
+}
+
+criterion_group!(benches, enhancement_factor_benchmark);
+criterion_main!(benches);
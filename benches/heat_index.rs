@@ -0,0 +1,19 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::heat_index;
+
+pub fn heat_index_benchmark(c: &mut Criterion) {
+    c.bench_function("heat_index::rothfusz1", |b| {
+        b.iter(|| heat_index::rothfusz1(black_box(305.0), black_box(0.5)))
+    });
+
+    c.bench_function("heat_index::steadman1", |b| {
+        b.iter(|| heat_index::steadman1(black_box(294.0), black_box(0.5)))
+    });
+
+    c.bench_function("heat_index::lu_romps1", |b| {
+        b.iter(|| heat_index::lu_romps1(black_box(305.0), black_box(0.5)))
+    });
+}
+
+criterion_group!(benches, heat_index_benchmark);
+criterion_main!(benches);
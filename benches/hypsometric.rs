@@ -0,0 +1,44 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::{hypsometric, sounding::Sounding};
+
+pub fn hypsometric_benchmark(c: &mut Criterion) {
+    let sounding = Sounding::from_hpa_celsius(
+        &[1000.0, 925.0, 850.0, 700.0, 500.0, 400.0, 300.0, 250.0],
+        &[15.0, 10.5, 6.0, -3.0, -21.0, -32.0, -45.0, -52.0],
+        &[10.0, 6.0, 1.0, -10.0, -30.0, -42.0, -55.0, -62.0],
+    )
+    .unwrap();
+
+    c.bench_function("hypsometric::thickness1", |b| {
+        b.iter(|| {
+            hypsometric::thickness1(black_box(273.15), black_box(100_000.0), black_box(50000.0))
+        })
+    });
+
+    c.bench_function("hypsometric::heights1", |b| {
+        b.iter(|| hypsometric::heights1(black_box(&sounding), black_box(110.0)))
+    });
+
+    c.bench_function("hypsometric::height_at_pressure1", |b| {
+        b.iter(|| {
+            hypsometric::height_at_pressure1(
+                black_box(&sounding),
+                black_box(110.0),
+                black_box(60000.0),
+            )
+        })
+    });
+
+    c.bench_function("hypsometric::pressure_at_height1", |b| {
+        b.iter(|| {
+            hypsometric::pressure_at_height1(
+                black_box(&sounding),
+                black_box(110.0),
+                black_box(4000.0),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, hypsometric_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::latent_heat;
+
+pub fn latent_heat_benchmark(c: &mut Criterion) {
+    c.bench_function("latent_heat::vaporisation1", |b| {
+        b.iter(|| latent_heat::vaporisation1(black_box(300.0)))
+    });
+
+    c.bench_function("latent_heat::vaporisation2", |b| {
+        b.iter(|| latent_heat::vaporisation2(black_box(250.0)))
+    });
+
+    c.bench_function("latent_heat::fusion1", |b| {
+        b.iter(|| latent_heat::fusion1(black_box(250.0)))
+    });
+
+    c.bench_function("latent_heat::fusion2", |b| {
+        b.iter(|| latent_heat::fusion2(black_box(250.0)))
+    });
+
+    c.bench_function("latent_heat::sublimation1", |b| {
+        b.iter(|| latent_heat::sublimation1(black_box(250.0)))
+    });
+
+    c.bench_function("latent_heat::sublimation2", |b| {
+        b.iter(|| latent_heat::sublimation2(black_box(250.0)))
+    });
+}
+
+criterion_group!(benches, latent_heat_benchmark);
+criterion_main!(benches);
@@ -1,5 +1,5 @@
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use floccus::mixing_ratio;
+use floccus::{mixing_ratio, vapour_pressure::PhaseBlending};
 
 pub fn mixing_ratio_benchmark(c: &mut Criterion) {
     c.bench_function("mixing_ratio::general1", |b| {
@@ -11,6 +11,15 @@ pub fn mixing_ratio_benchmark(c: &mut Criterion) {
     c.bench_function("mixing_ratio::accuracy1", |b| {
         b.iter(|| mixing_ratio::accuracy1(black_box(300.0), black_box(101325.0)))
     });
+    c.bench_function("mixing_ratio::mixed_phase1", |b| {
+        b.iter(|| {
+            mixing_ratio::mixed_phase1(
+                black_box(260.0),
+                black_box(80000.0),
+                black_box(PhaseBlending::Ifs),
+            )
+        })
+    });
 }
 
 criterion_group!(benches, mixing_ratio_benchmark);
@@ -0,0 +1,42 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::moist_adiabat;
+
+pub fn moist_adiabat_benchmark(c: &mut Criterion) {
+    c.bench_function("moist_adiabat::reversible1", |b| {
+        b.iter(|| {
+            moist_adiabat::reversible1(
+                black_box(300.0),
+                black_box(100_000.0),
+                black_box(0.02),
+                black_box(&[85000.0, 70000.0, 50000.0, 30000.0]),
+                black_box(Some([273.15, 253.15])),
+            )
+        })
+    });
+
+    c.bench_function("moist_adiabat::pseudo1", |b| {
+        b.iter(|| {
+            moist_adiabat::pseudo1(
+                black_box(300.0),
+                black_box(100_000.0),
+                black_box(0.02),
+                black_box(&[85000.0, 70000.0, 50000.0, 30000.0]),
+                black_box(Some([273.15, 253.15])),
+            )
+        })
+    });
+
+    c.bench_function("moist_adiabat::saturated_descent1", |b| {
+        b.iter(|| {
+            moist_adiabat::saturated_descent1(
+                black_box(260.0),
+                black_box(50000.0),
+                black_box(&[70000.0, 85000.0, 100_000.0]),
+                black_box(Some([273.15, 253.15])),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, moist_adiabat_benchmark);
+criterion_main!(benches);
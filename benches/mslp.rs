@@ -0,0 +1,52 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::mslp;
+
+pub fn mslp_benchmark(c: &mut Criterion) {
+    c.bench_function("mslp::wmo1", |b| {
+        b.iter(|| {
+            mslp::wmo1(
+                black_box(95000.0),
+                black_box(500.0),
+                black_box(288.15),
+                black_box(1000.0),
+            )
+        })
+    });
+
+    c.bench_function("mslp::hypsometric1", |b| {
+        b.iter(|| {
+            mslp::hypsometric1(
+                black_box(95000.0),
+                black_box(500.0),
+                black_box(288.15),
+                black_box(286.15),
+                black_box(1000.0),
+            )
+        })
+    });
+
+    c.bench_function("mslp::shuell1", |b| {
+        b.iter(|| {
+            mslp::shuell1(
+                black_box(95000.0),
+                black_box(500.0),
+                black_box(288.15),
+                black_box(1000.0),
+            )
+        })
+    });
+
+    c.bench_function("mslp::ifs1", |b| {
+        b.iter(|| {
+            mslp::ifs1(
+                black_box(95000.0),
+                black_box(500.0),
+                black_box(288.15),
+                black_box(1000.0),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, mslp_benchmark);
+criterion_main!(benches);
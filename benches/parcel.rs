@@ -0,0 +1,47 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::parcel::{self, ParcelKind};
+
+pub fn parcel_benchmark(c: &mut Criterion) {
+    let pressure = [
+        100_000.0, 92500.0, 85000.0, 70000.0, 50000.0, 40000.0, 30000.0, 25000.0,
+    ];
+    let temperature = [303.0, 297.0, 292.0, 282.0, 266.0, 255.0, 241.0, 231.0];
+    let dewpoint = [295.0, 292.0, 285.0, 272.0, 250.0, 235.0, 220.0, 210.0];
+
+    c.bench_function("parcel::lifting_condensation_level1", |b| {
+        b.iter(|| {
+            parcel::lifting_condensation_level1(
+                black_box(300.0),
+                black_box(100_000.0),
+                black_box(0.0128),
+            )
+        })
+    });
+
+    c.bench_function("parcel::ascent1", |b| {
+        b.iter(|| {
+            parcel::ascent1(
+                black_box(300.0),
+                black_box(100_000.0),
+                black_box(0.0128),
+                black_box(&pressure),
+                black_box(Some([273.15, 250.15])),
+            )
+        })
+    });
+
+    c.bench_function("parcel::profile1", |b| {
+        b.iter(|| {
+            parcel::profile1(
+                black_box(&pressure),
+                black_box(&temperature),
+                black_box(&dewpoint),
+                black_box(ParcelKind::MixedLayer(10000.0)),
+                black_box(Some([273.15, 250.15])),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, parcel_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::{
+    precipitable_water::{self, Integration},
+    sounding::Sounding,
+};
+
+pub fn precipitable_water_benchmark(c: &mut Criterion) {
+    let sounding = Sounding::from_hpa_celsius(
+        &[1000.0, 925.0, 850.0, 700.0, 500.0, 400.0, 300.0, 250.0],
+        &[30.0, 24.0, 18.0, 8.0, -8.5, -19.0, -33.0, -42.0],
+        &[22.0, 19.0, 14.0, 1.0, -20.0, -32.0, -45.0, -53.0],
+    )
+    .unwrap();
+
+    c.bench_function("precipitable_water::general1", |b| {
+        b.iter(|| {
+            precipitable_water::general1(black_box(&sounding), black_box(Integration::LogPressure))
+        })
+    });
+
+    c.bench_function("precipitable_water::general2", |b| {
+        b.iter(|| {
+            precipitable_water::general2(black_box(&sounding), black_box(Integration::LogPressure))
+        })
+    });
+
+    c.bench_function("precipitable_water::layer1", |b| {
+        b.iter(|| {
+            precipitable_water::layer1(
+                black_box(&sounding),
+                black_box([100_000.0, 70000.0]),
+                black_box(Integration::Trapezoidal),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, precipitable_water_benchmark);
+criterion_main!(benches);
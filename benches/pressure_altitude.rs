@@ -0,0 +1,15 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::pressure_altitude;
+
+pub fn pressure_altitude_benchmark(c: &mut Criterion) {
+    c.bench_function("pressure_altitude::general1", |b| {
+        b.iter(|| pressure_altitude::general1(black_box(85000.0)))
+    });
+
+    c.bench_function("pressure_altitude::nws1", |b| {
+        b.iter(|| pressure_altitude::nws1(black_box(85000.0)))
+    });
+}
+
+criterion_group!(benches, pressure_altitude_benchmark);
+criterion_main!(benches);
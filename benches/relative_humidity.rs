@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use floccus::relative_humidity;
+use floccus::{relative_humidity, vapour_pressure::Psychrometer};
 
 pub fn relative_humidity_benchmark(c: &mut Criterion) {
     c.bench_function("relative_humidity::general1", |b| {
@@ -21,6 +21,58 @@ pub fn relative_humidity_benchmark(c: &mut Criterion) {
     c.bench_function("relative_humidity::general5", |b| {
         b.iter(|| relative_humidity::general5(black_box(300.0), black_box(290.0), black_box(101325.0)))
     });
+
+    c.bench_function("relative_humidity::psychrometric1", |b| {
+        b.iter(|| {
+            relative_humidity::psychrometric1(
+                black_box(293.0),
+                black_box(288.0),
+                black_box(101325.0),
+                black_box(Psychrometer::Aspirated),
+            )
+        })
+    });
+
+    c.bench_function("relative_humidity::psychrometric2", |b| {
+        b.iter(|| {
+            relative_humidity::psychrometric2(
+                black_box(268.0),
+                black_box(266.0),
+                black_box(101325.0),
+                black_box(Psychrometer::Aspirated),
+            )
+        })
+    });
+
+    c.bench_function("relative_humidity::ice1", |b| {
+        b.iter(|| relative_humidity::ice1(black_box(15.0), black_box(233.0), black_box(25000.0)))
+    });
+
+    c.bench_function("relative_humidity::ice2", |b| {
+        b.iter(|| relative_humidity::ice2(black_box(15.0), black_box(233.0), black_box(25000.0)))
+    });
+
+    c.bench_function("relative_humidity::ice3", |b| {
+        b.iter(|| relative_humidity::ice3(black_box(15.0), black_box(233.0)))
+    });
+
+    c.bench_function("relative_humidity::ice_supersaturation1", |b| {
+        b.iter(|| {
+            relative_humidity::ice_supersaturation1(
+                black_box(15.0),
+                black_box(233.0),
+                black_box(25000.0),
+            )
+        })
+    });
+
+    c.bench_function("relative_humidity::water_to_ice1", |b| {
+        b.iter(|| relative_humidity::water_to_ice1(black_box(0.7), black_box(213.0)))
+    });
+
+    c.bench_function("relative_humidity::ice_to_water1", |b| {
+        b.iter(|| relative_humidity::ice_to_water1(black_box(1.2), black_box(213.0)))
+    });
 }
 
 criterion_group!(benches, relative_humidity_benchmark);
@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::sounding::Sounding;
+
+pub fn sounding_benchmark(c: &mut Criterion) {
+    let pressure = [100_000.0, 85000.0, 70000.0, 50000.0];
+    let temperature = [298.15, 288.15, 278.15, 261.15];
+
+    c.bench_function("sounding::Sounding::from_hpa_celsius", |b| {
+        b.iter(|| {
+            Sounding::from_hpa_celsius(
+                black_box(&[1000.0, 850.0, 700.0, 500.0]),
+                black_box(&[25.0, 15.0, 5.0, -12.0]),
+                black_box(&[18.0, 10.0, -2.0, -25.0]),
+            )
+        })
+    });
+
+    c.bench_function("sounding::Sounding::from_relative_humidity", |b| {
+        b.iter(|| {
+            Sounding::from_relative_humidity(
+                black_box(&pressure),
+                black_box(&temperature),
+                black_box(&[0.65, 0.7, 0.6, 0.35]),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, sounding_benchmark);
+criterion_main!(benches);
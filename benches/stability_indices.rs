@@ -0,0 +1,58 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::{parcel::ParcelKind, sounding::Sounding, stability_indices};
+
+pub fn stability_indices_benchmark(c: &mut Criterion) {
+    let sounding = Sounding::from_hpa_celsius(
+        &[1000.0, 925.0, 850.0, 700.0, 500.0, 400.0, 300.0, 250.0],
+        &[30.0, 24.0, 18.0, 8.0, -8.5, -18.0, -33.0, -42.0],
+        &[22.0, 19.0, 14.0, 1.0, -20.0, -35.0, -45.0, -55.0],
+    )
+    .unwrap()
+    .with_height(&[
+        110.0, 800.0, 1530.0, 3100.0, 5800.0, 7400.0, 9500.0, 10800.0,
+    ])
+    .unwrap()
+    .with_wind(
+        &[5.0, 8.0, 10.0, 15.0, 20.0, 25.0, 30.0, 35.0],
+        &[160.0, 170.0, 180.0, 210.0, 240.0, 245.0, 250.0, 255.0],
+    )
+    .unwrap();
+
+    c.bench_function("stability_indices::lifted_index1", |b| {
+        b.iter(|| {
+            stability_indices::lifted_index1(
+                black_box(&sounding),
+                black_box(ParcelKind::MixedLayer(10000.0)),
+                black_box(Some([273.15, 250.15])),
+            )
+        })
+    });
+
+    c.bench_function("stability_indices::showalter_index1", |b| {
+        b.iter(|| {
+            stability_indices::showalter_index1(
+                black_box(&sounding),
+                black_box(Some([273.15, 250.15])),
+            )
+        })
+    });
+
+    c.bench_function("stability_indices::k_index1", |b| {
+        b.iter(|| stability_indices::k_index1(black_box(&sounding)))
+    });
+
+    c.bench_function("stability_indices::total_totals1", |b| {
+        b.iter(|| stability_indices::total_totals1(black_box(&sounding)))
+    });
+
+    c.bench_function("stability_indices::sweat_index1", |b| {
+        b.iter(|| stability_indices::sweat_index1(black_box(&sounding)))
+    });
+
+    c.bench_function("stability_indices::boyden_index1", |b| {
+        b.iter(|| stability_indices::boyden_index1(black_box(&sounding)))
+    });
+}
+
+criterion_group!(benches, stability_indices_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::standard_atmosphere::{self, Model};
+
+pub fn standard_atmosphere_benchmark(c: &mut Criterion) {
+    c.bench_function("standard_atmosphere::temperature1", |b| {
+        b.iter(|| standard_atmosphere::temperature1(black_box(25000.0), black_box(Model::Isa)))
+    });
+
+    c.bench_function("standard_atmosphere::pressure1", |b| {
+        b.iter(|| standard_atmosphere::pressure1(black_box(25000.0), black_box(Model::Isa)))
+    });
+
+    c.bench_function("standard_atmosphere::density1", |b| {
+        b.iter(|| standard_atmosphere::density1(black_box(25000.0), black_box(Model::Us76)))
+    });
+
+    c.bench_function("standard_atmosphere::speed_of_sound1", |b| {
+        b.iter(|| standard_atmosphere::speed_of_sound1(black_box(25000.0), black_box(Model::Us76)))
+    });
+
+    c.bench_function("standard_atmosphere::altitude1", |b| {
+        b.iter(|| standard_atmosphere::altitude1(black_box(2500.0), black_box(Model::Us76)))
+    });
+}
+
+criterion_group!(benches, standard_atmosphere_benchmark);
+criterion_main!(benches);
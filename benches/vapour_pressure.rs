@@ -1,15 +1,23 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use floccus::vapour_pressure;
+use floccus::vapour_pressure::{self, PhaseBlending, Psychrometer};
 
 pub fn vapour_pressure_benchmark(c: &mut Criterion) {
     c.bench_function("vapour_pressure::general1", |b| {
         b.iter(|| vapour_pressure::general1(black_box(0.022), black_box(101325.0)))
     });
 
+    c.bench_function("vapour_pressure::general2", |b| {
+        b.iter(|| vapour_pressure::general2(black_box(0.022), black_box(101325.0)))
+    });
+
     c.bench_function("vapour_pressure::tetens1", |b| {
         b.iter(|| vapour_pressure::tetens1(black_box(300.0)))
     });
 
+    c.bench_function("vapour_pressure::masterton_richardson1", |b| {
+        b.iter(|| vapour_pressure::masterton_richardson1(black_box(300.0)))
+    });
+
     c.bench_function("vapour_pressure::buck1", |b| {
         b.iter(|| vapour_pressure::buck1(black_box(300.0), black_box(101325.0)))
     });
@@ -49,6 +57,80 @@ pub fn vapour_pressure_benchmark(c: &mut Criterion) {
     c.bench_function("vapour_pressure::wexler2", |b| {
         b.iter(|| vapour_pressure::wexler2(black_box(250.0)))
     });
+
+    c.bench_function("vapour_pressure::goff_gratch1", |b| {
+        b.iter(|| vapour_pressure::goff_gratch1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure::goff_gratch2", |b| {
+        b.iter(|| vapour_pressure::goff_gratch2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure::hyland_wexler1", |b| {
+        b.iter(|| vapour_pressure::hyland_wexler1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure::hyland_wexler2", |b| {
+        b.iter(|| vapour_pressure::hyland_wexler2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure::sonntag1", |b| {
+        b.iter(|| vapour_pressure::sonntag1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure::sonntag2", |b| {
+        b.iter(|| vapour_pressure::sonntag2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure::murphy_koop1", |b| {
+        b.iter(|| vapour_pressure::murphy_koop1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure::murphy_koop2", |b| {
+        b.iter(|| vapour_pressure::murphy_koop2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure::huang1", |b| {
+        b.iter(|| vapour_pressure::huang1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure::huang2", |b| {
+        b.iter(|| vapour_pressure::huang2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure::iapws1", |b| {
+        b.iter(|| vapour_pressure::iapws1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure::iapws2", |b| {
+        b.iter(|| vapour_pressure::iapws2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure::psychrometric1", |b| {
+        b.iter(|| {
+            vapour_pressure::psychrometric1(
+                black_box(293.0),
+                black_box(288.0),
+                black_box(101325.0),
+                black_box(Psychrometer::Aspirated),
+            )
+        })
+    });
+
+    c.bench_function("vapour_pressure::psychrometric2", |b| {
+        b.iter(|| {
+            vapour_pressure::psychrometric2(
+                black_box(268.0),
+                black_box(266.0),
+                black_box(101325.0),
+                black_box(Psychrometer::Aspirated),
+            )
+        })
+    });
+
+    c.bench_function("vapour_pressure::mixed_phase1", |b| {
+        b.iter(|| vapour_pressure::mixed_phase1(black_box(260.0), black_box(PhaseBlending::Ifs)))
+    });
 }
 
 criterion_group!(benches, vapour_pressure_benchmark);
@@ -0,0 +1,125 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::{vapour_pressure::PhaseBlending, vapour_pressure_slope};
+
+pub fn vapour_pressure_slope_benchmark(c: &mut Criterion) {
+    c.bench_function("vapour_pressure_slope::clausius_clapeyron1", |b| {
+        b.iter(|| vapour_pressure_slope::clausius_clapeyron1(black_box(300.0), black_box(3500.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::clausius_clapeyron2", |b| {
+        b.iter(|| vapour_pressure_slope::clausius_clapeyron2(black_box(300.0), black_box(3500.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck1", |b| {
+        b.iter(|| vapour_pressure_slope::buck1(black_box(300.0), black_box(101325.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck2", |b| {
+        b.iter(|| vapour_pressure_slope::buck2(black_box(250.0), black_box(101325.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck3", |b| {
+        b.iter(|| vapour_pressure_slope::buck3(black_box(300.0), black_box(101325.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck3_simplified", |b| {
+        b.iter(|| vapour_pressure_slope::buck3_simplified(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck4", |b| {
+        b.iter(|| vapour_pressure_slope::buck4(black_box(250.0), black_box(101325.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck4_simplified", |b| {
+        b.iter(|| vapour_pressure_slope::buck4_simplified(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck1_pressure", |b| {
+        b.iter(|| vapour_pressure_slope::buck1_pressure(black_box(300.0), black_box(101325.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck2_pressure", |b| {
+        b.iter(|| vapour_pressure_slope::buck2_pressure(black_box(250.0), black_box(101325.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck3_pressure", |b| {
+        b.iter(|| vapour_pressure_slope::buck3_pressure(black_box(300.0), black_box(101325.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::buck4_pressure", |b| {
+        b.iter(|| vapour_pressure_slope::buck4_pressure(black_box(250.0), black_box(101325.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::tetens1", |b| {
+        b.iter(|| vapour_pressure_slope::tetens1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::masterton_richardson1", |b| {
+        b.iter(|| vapour_pressure_slope::masterton_richardson1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::wexler1", |b| {
+        b.iter(|| vapour_pressure_slope::wexler1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::wexler2", |b| {
+        b.iter(|| vapour_pressure_slope::wexler2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::goff_gratch1", |b| {
+        b.iter(|| vapour_pressure_slope::goff_gratch1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::goff_gratch2", |b| {
+        b.iter(|| vapour_pressure_slope::goff_gratch2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::hyland_wexler1", |b| {
+        b.iter(|| vapour_pressure_slope::hyland_wexler1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::hyland_wexler2", |b| {
+        b.iter(|| vapour_pressure_slope::hyland_wexler2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::sonntag1", |b| {
+        b.iter(|| vapour_pressure_slope::sonntag1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::sonntag2", |b| {
+        b.iter(|| vapour_pressure_slope::sonntag2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::murphy_koop1", |b| {
+        b.iter(|| vapour_pressure_slope::murphy_koop1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::murphy_koop2", |b| {
+        b.iter(|| vapour_pressure_slope::murphy_koop2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::huang1", |b| {
+        b.iter(|| vapour_pressure_slope::huang1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::huang2", |b| {
+        b.iter(|| vapour_pressure_slope::huang2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::iapws1", |b| {
+        b.iter(|| vapour_pressure_slope::iapws1(black_box(300.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::iapws2", |b| {
+        b.iter(|| vapour_pressure_slope::iapws2(black_box(250.0)))
+    });
+
+    c.bench_function("vapour_pressure_slope::mixed_phase1", |b| {
+        b.iter(|| {
+            vapour_pressure_slope::mixed_phase1(black_box(260.0), black_box(PhaseBlending::Ifs))
+        })
+    });
+}
+
+criterion_group!(benches, vapour_pressure_slope_benchmark);
+criterion_main!(benches);
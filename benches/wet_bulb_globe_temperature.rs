@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::wet_bulb_globe_temperature;
+
+pub fn wet_bulb_globe_temperature_benchmark(c: &mut Criterion) {
+    c.bench_function("wet_bulb_globe_temperature::outdoor1", |b| {
+        b.iter(|| {
+            wet_bulb_globe_temperature::outdoor1(
+                black_box(303.0),
+                black_box(296.0),
+                black_box(318.0),
+            )
+        })
+    });
+
+    c.bench_function("wet_bulb_globe_temperature::indoor1", |b| {
+        b.iter(|| wet_bulb_globe_temperature::indoor1(black_box(296.0), black_box(318.0)))
+    });
+
+    c.bench_function("wet_bulb_globe_temperature::liljegren1", |b| {
+        b.iter(|| {
+            wet_bulb_globe_temperature::liljegren1(
+                black_box(303.0),
+                black_box(0.5),
+                black_box(101_325.0),
+                black_box(3.0),
+                black_box(800.0),
+                black_box(30.0),
+            )
+        })
+    });
+
+    c.bench_function(
+        "wet_bulb_globe_temperature::natural_wet_bulb_temperature1",
+        |b| {
+            b.iter(|| {
+                wet_bulb_globe_temperature::natural_wet_bulb_temperature1(
+                    black_box(303.0),
+                    black_box(0.5),
+                    black_box(101_325.0),
+                    black_box(3.0),
+                    black_box(800.0),
+                    black_box(30.0),
+                )
+            })
+        },
+    );
+
+    c.bench_function("wet_bulb_globe_temperature::globe_temperature1", |b| {
+        b.iter(|| {
+            wet_bulb_globe_temperature::globe_temperature1(
+                black_box(303.0),
+                black_box(0.5),
+                black_box(101_325.0),
+                black_box(3.0),
+                black_box(800.0),
+                black_box(30.0),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, wet_bulb_globe_temperature_benchmark);
+criterion_main!(benches);
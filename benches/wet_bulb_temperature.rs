@@ -5,6 +5,32 @@ pub fn wet_bulb_temperature_benchmark(c: &mut Criterion) {
     c.bench_function("wet_bulb_temperature::stull1", |b| {
         b.iter(|| wet_bulb_temperature::stull1(black_box(300.0), black_box(0.5)))
     });
+
+    c.bench_function("wet_bulb_temperature::normand1", |b| {
+        b.iter(|| {
+            wet_bulb_temperature::normand1(black_box(300.0), black_box(290.0), black_box(101325.0))
+        })
+    });
+
+    c.bench_function("wet_bulb_temperature::psychrometric1", |b| {
+        b.iter(|| {
+            wet_bulb_temperature::psychrometric1(
+                black_box(300.0),
+                black_box(101325.0),
+                black_box(1900.0),
+            )
+        })
+    });
+
+    c.bench_function("wet_bulb_temperature::davies_jones1", |b| {
+        b.iter(|| {
+            wet_bulb_temperature::davies_jones1(
+                black_box(300.0),
+                black_box(101325.0),
+                black_box(1900.0),
+            )
+        })
+    });
 }
 
 criterion_group!(benches, wet_bulb_temperature_benchmark);
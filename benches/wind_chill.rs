@@ -0,0 +1,11 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use floccus::wind_chill;
+
+pub fn wind_chill_benchmark(c: &mut Criterion) {
+    c.bench_function("wind_chill::osczevski_bluestein1", |b| {
+        b.iter(|| wind_chill::osczevski_bluestein1(black_box(263.0), black_box(5.0)))
+    });
+}
+
+criterion_group!(benches, wind_chill_benchmark);
+criterion_main!(benches);
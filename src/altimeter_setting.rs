@@ -0,0 +1,181 @@
+//!Functions to calculate altimeter setting (QNH) in Pa.
+//!
+//!Altimeter setting is the pressure to which altimeter must be set so that it indicates
+//!the elevation of an aerodrome when on the ground [Wikipedia](https://en.wikipedia.org/wiki/Altimeter_setting).
+//!It is computed from the pressure at aerodrome elevation (QFE) assuming the standard atmosphere
+//!between the aerodrome and mean sea level.
+
+use crate::Float;
+use crate::{
+    constants::{G, R_D},
+    errors::InputError,
+    standard_atmosphere::{LAPSE_RATE, SEA_LEVEL_PRESSURE, SEA_LEVEL_TEMPERATURE},
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing altimeter setting (QNH) from pressure at aerodrome elevation (QFE) and the elevation.
+///
+///Provided in WMO (2018). Guide to Instruments and Methods of Observation, WMO-No. 8, Volume I, Chapter 3.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `qfe` range: 50000Pa - 110000Pa\
+///Valid `elevation` range: -500m - 5000m
+#[cfg_attr(feature = "debug", logerr)]
+pub fn wmo1(qfe: Float, elevation: Float) -> Result<Float, InputError> {
+    if !(50_000.0..=110_000.0).contains(&qfe) {
+        return Err(InputError::OutOfRange(String::from("qfe")));
+    }
+
+    if !(-500.0..=5000.0).contains(&elevation) {
+        return Err(InputError::OutOfRange(String::from("elevation")));
+    }
+
+    let exponent = R_D * LAPSE_RATE / G;
+
+    let result = SEA_LEVEL_PRESSURE
+        * ((qfe / SEA_LEVEL_PRESSURE).powf(exponent)
+            + LAPSE_RATE * elevation / SEA_LEVEL_TEMPERATURE)
+            .powf(1.0 / exponent);
+
+    Ok(result)
+}
+
+///Formula for computing pressure at aerodrome elevation (QFE) from altimeter setting (QNH) and the elevation.
+///Reverse function of [`wmo1`].
+///
+///Provided in WMO (2018). Guide to Instruments and Methods of Observation, WMO-No. 8, Volume I, Chapter 3.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `altimeter_setting` range: 85000Pa - 110000Pa\
+///Valid `elevation` range: -500m - 5000m
+#[cfg_attr(feature = "debug", logerr)]
+pub fn wmo2(altimeter_setting: Float, elevation: Float) -> Result<Float, InputError> {
+    if !(85_000.0..=110_000.0).contains(&altimeter_setting) {
+        return Err(InputError::OutOfRange(String::from("altimeter_setting")));
+    }
+
+    if !(-500.0..=5000.0).contains(&elevation) {
+        return Err(InputError::OutOfRange(String::from("elevation")));
+    }
+
+    let exponent = R_D * LAPSE_RATE / G;
+
+    let result = SEA_LEVEL_PRESSURE
+        * ((altimeter_setting / SEA_LEVEL_PRESSURE).powf(exponent)
+            - LAPSE_RATE * elevation / SEA_LEVEL_TEMPERATURE)
+            .powf(1.0 / exponent);
+
+    Ok(result)
+}
+
+///Formula for computing altimeter setting from station pressure and station elevation
+///used by the U.S. National Weather Service.
+///
+///The formula subtracts 0.3hPa from station pressure, as specified for ASOS stations.
+///
+///Provided in National Weather Service (1998). Federal Meteorological Handbook No. 1,
+///Surface Weather Observations and Reports. FCM-H1-1998.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `station_pressure` range: 50000Pa - 110000Pa\
+///Valid `elevation` range: -500m - 5000m
+#[cfg_attr(feature = "debug", logerr)]
+pub fn nws1(station_pressure: Float, elevation: Float) -> Result<Float, InputError> {
+    if !(50_000.0..=110_000.0).contains(&station_pressure) {
+        return Err(InputError::OutOfRange(String::from("station_pressure")));
+    }
+
+    if !(-500.0..=5000.0).contains(&elevation) {
+        return Err(InputError::OutOfRange(String::from("elevation")));
+    }
+
+    //convert units
+    let station_pressure = station_pressure / 100.0 - 0.3;
+    let sea_level_pressure = SEA_LEVEL_PRESSURE / 100.0;
+
+    let result = station_pressure
+        * (1.0
+            + (sea_level_pressure.powf(0.190_284) * LAPSE_RATE / SEA_LEVEL_TEMPERATURE)
+                * elevation
+                / station_pressure.powf(0.190_284))
+        .powf(1.0 / 0.190_284);
+
+    Ok(result * 100.0)
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use crate::{
+        altimeter_setting,
+        tests_framework::{self, Argument},
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn wmo1() {
+        assert!(tests_framework::test_with_2args(
+            &altimeter_setting::wmo1,
+            Argument {
+                name: "qfe",
+                def_val: 90000.0,
+                range: [50_000.0, 110_000.0]
+            },
+            Argument {
+                name: "elevation",
+                def_val: 1000.0,
+                range: [-500.0, 5000.0]
+            },
+            101_463.014
+        ));
+    }
+
+    #[test]
+    fn wmo2() {
+        assert!(tests_framework::test_with_2args(
+            &altimeter_setting::wmo2,
+            Argument {
+                name: "altimeter_setting",
+                def_val: 100_000.0,
+                range: [85_000.0, 110_000.0]
+            },
+            Argument {
+                name: "elevation",
+                def_val: 1000.0,
+                range: [-500.0, 5000.0]
+            },
+            88672.512
+        ));
+
+        let qnh = altimeter_setting::wmo1(70000.0, 3000.0).unwrap();
+        let qfe = altimeter_setting::wmo2(qnh, 3000.0).unwrap();
+        assert_approx_eq!(Float, qfe, 70000.0, epsilon = 0.1);
+    }
+
+    #[test]
+    fn nws1() {
+        assert!(tests_framework::test_with_2args(
+            &altimeter_setting::nws1,
+            Argument {
+                name: "station_pressure",
+                def_val: 95000.0,
+                range: [50_000.0, 110_000.0]
+            },
+            Argument {
+                name: "elevation",
+                def_val: 500.0,
+                range: [-500.0, 5000.0]
+            },
+            100_809.224
+        ));
+    }
+}
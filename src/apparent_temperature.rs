@@ -0,0 +1,153 @@
+//!Functions to calculate apparent temperature in K.
+//!
+//!Apparent temperature is the temperature perceived by humans, caused by the combined effects
+//!of air temperature, humidity and wind speed [Wikipedia](https://en.wikipedia.org/wiki/Apparent_temperature).
+//!Formulas in this module are the versions of R. G. Steadman's model used by the Australian Bureau of Meteorology.
+//!Wind speed is measured at the standard height of 10m.
+
+use crate::Float;
+use crate::{constants::ZERO_CELSIUS, errors::InputError};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing apparent temperature of a person in the shade
+///from air temperature, vapour pressure and wind speed.
+///
+///Derived by R. G. Steadman (1994). Norms of apparent temperature in Australia. Australian Meteorological Magazine, 43, 1-16.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 233K - 323K\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa\
+///Valid `wind_speed` range: 0m/s - 50m/s
+#[cfg_attr(feature = "debug", logerr)]
+pub fn steadman1(
+    temperature: Float,
+    vapour_pressure: Float,
+    wind_speed: Float,
+) -> Result<Float, InputError> {
+    validate_inputs(temperature, vapour_pressure, wind_speed)?;
+
+    //convert units
+    let temperature = temperature - ZERO_CELSIUS;
+    let vapour_pressure = vapour_pressure / 100.0;
+
+    let result = temperature + 0.33 * vapour_pressure - 0.70 * wind_speed - 4.00;
+
+    Ok(result + ZERO_CELSIUS)
+}
+
+///Formula for computing apparent temperature of a person exposed to solar radiation
+///from air temperature, vapour pressure, wind speed and net radiation absorbed per unit area of body surface.
+///
+///Derived by R. G. Steadman (1994). Norms of apparent temperature in Australia. Australian Meteorological Magazine, 43, 1-16.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 233K - 323K\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa\
+///Valid `wind_speed` range: 0m/s - 50m/s\
+///Valid `absorbed_radiation` range: -500W/m^2 - 1500W/m^2
+#[cfg_attr(feature = "debug", logerr)]
+pub fn steadman2(
+    temperature: Float,
+    vapour_pressure: Float,
+    wind_speed: Float,
+    absorbed_radiation: Float,
+) -> Result<Float, InputError> {
+    validate_inputs(temperature, vapour_pressure, wind_speed)?;
+
+    if !(-500.0..=1500.0).contains(&absorbed_radiation) {
+        return Err(InputError::OutOfRange(String::from("absorbed_radiation")));
+    }
+
+    //convert units
+    let temperature = temperature - ZERO_CELSIUS;
+    let vapour_pressure = vapour_pressure / 100.0;
+
+    let result = temperature + 0.348 * vapour_pressure - 0.70 * wind_speed
+        + 0.70 * absorbed_radiation / (wind_speed + 10.0)
+        - 4.25;
+
+    Ok(result + ZERO_CELSIUS)
+}
+
+fn validate_inputs(
+    temperature: Float,
+    vapour_pressure: Float,
+    wind_speed: Float,
+) -> Result<(), InputError> {
+    if !(233.0..=323.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+        return Err(InputError::OutOfRange(String::from("vapour_pressure")));
+    }
+
+    if !(0.0..=50.0).contains(&wind_speed) {
+        return Err(InputError::OutOfRange(String::from("wind_speed")));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        apparent_temperature,
+        errors::InputError,
+        tests_framework::{self, Argument},
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn steadman1() {
+        assert!(tests_framework::test_with_3args(
+            &apparent_temperature::steadman1,
+            Argument {
+                name: "temperature",
+                def_val: 303.15,
+                range: [233.0, 323.0]
+            },
+            Argument {
+                name: "vapour_pressure",
+                def_val: 2000.0,
+                range: [0.0, 10_000.0]
+            },
+            Argument {
+                name: "wind_speed",
+                def_val: 3.0,
+                range: [0.0, 50.0]
+            },
+            303.65
+        ));
+    }
+
+    #[test]
+    fn steadman2() {
+        let result = apparent_temperature::steadman2(303.15, 2000.0, 3.0, 400.0).unwrap();
+        assert_approx_eq!(Float, result, 325.298, epsilon = 0.01);
+
+        //without absorbed radiation the formula differs from steadman1 only by its constants
+        let shade = apparent_temperature::steadman1(303.15, 2000.0, 3.0).unwrap();
+        let result = apparent_temperature::steadman2(303.15, 2000.0, 3.0, 0.0).unwrap();
+        assert_approx_eq!(Float, result, shade + 0.11, epsilon = 0.01);
+
+        for (args, name) in [
+            ((200.0, 2000.0, 3.0, 400.0), "temperature"),
+            ((303.15, -1.0, 3.0, 400.0), "vapour_pressure"),
+            ((303.15, 2000.0, 60.0, 400.0), "wind_speed"),
+            ((303.15, 2000.0, 3.0, 2000.0), "absorbed_radiation"),
+        ] {
+            assert_eq!(
+                apparent_temperature::steadman2(args.0, args.1, args.2, args.3).unwrap_err(),
+                InputError::OutOfRange(String::from(name))
+            );
+        }
+    }
+}
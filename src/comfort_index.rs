@@ -0,0 +1,263 @@
+//!Functions to calculate indices of human thermal comfort in hot and humid conditions.
+//!
+//!Comfort indices combine air temperature and humidity into a single value
+//!describing the perceived heat stress [Wikipedia](https://en.wikipedia.org/wiki/Thermal_comfort).
+//!Indices expressed on a temperature scale are returned in K,
+//!the temperature-humidity index is returned on its conventional (dimensionless) scale.
+//!
+//!For indices including the effect of wind see [`apparent_temperature`](crate::apparent_temperature)
+//!and [`wind_chill`](crate::wind_chill) modules.
+
+use crate::Float;
+use crate::{constants::ZERO_CELSIUS, errors::InputError, vapour_pressure};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing humidex from temperature and dewpoint
+///used by the Meteorological Service of Canada.
+///
+///Vapour pressure is computed from dewpoint with [`vapour_pressure::masterton_richardson1`],
+///as in the original definition of humidex. To use other vapour pressure formula see [`humidex2`].
+///
+///Derived by J. M. Masterton and F. A. Richardson (1979). Humidex: a method of quantifying human discomfort
+///due to excessive heat and humidity. Environment Canada, CLI 1-79.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 273K - 333K\
+///Valid `dewpoint` range: 232K - 324K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn humidex1(temperature: Float, dewpoint: Float) -> Result<Float, InputError> {
+    if !(273.0..=333.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(232.0..=324.0).contains(&dewpoint) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    let vapour_pressure = vapour_pressure::masterton_richardson1(dewpoint)?;
+
+    Ok(humidex(temperature, vapour_pressure))
+}
+
+///Formula for computing humidex from temperature and vapour pressure.
+///
+///Vapour pressure can be computed from dewpoint with any function from
+///[`vapour_pressure`] module.
+///
+///Derived by J. M. Masterton and F. A. Richardson (1979). Humidex: a method of quantifying human discomfort
+///due to excessive heat and humidity. Environment Canada, CLI 1-79.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 273K - 333K\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn humidex2(temperature: Float, vapour_pressure: Float) -> Result<Float, InputError> {
+    if !(273.0..=333.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+        return Err(InputError::OutOfRange(String::from("vapour_pressure")));
+    }
+
+    Ok(humidex(temperature, vapour_pressure))
+}
+
+///Formula for computing discomfort index from temperature and relative humidity.
+///
+///Derived by E. C. Thom (1959) [(doi:10.1080/00431672.1959.9926960)](https://doi.org/10.1080/00431672.1959.9926960),
+///in the form given by B. D. Giles et al. (1990) [(doi:10.1007/BF01093454)](https://doi.org/10.1007/BF01093454).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 253K - 333K\
+///Valid `relative_humidity` range: 0.0 - 1.0
+#[cfg_attr(feature = "debug", logerr)]
+pub fn discomfort_index1(
+    temperature: Float,
+    relative_humidity: Float,
+) -> Result<Float, InputError> {
+    validate_inputs(temperature, relative_humidity)?;
+
+    //convert units
+    let temperature = temperature - ZERO_CELSIUS;
+
+    let result = temperature - 0.55 * (1.0 - relative_humidity) * (temperature - 14.5);
+
+    Ok(result + ZERO_CELSIUS)
+}
+
+///Formula for computing temperature-humidity index from temperature and relative humidity.
+///
+///The index is dimensionless and is expressed on a scale close to temperature in °F.
+///
+///Provided in National Research Council (1971). A Guide to Environmental Research on Animals.
+///National Academy of Sciences, Washington, DC.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 253K - 333K\
+///Valid `relative_humidity` range: 0.0 - 1.0
+#[cfg_attr(feature = "debug", logerr)]
+pub fn temperature_humidity_index1(
+    temperature: Float,
+    relative_humidity: Float,
+) -> Result<Float, InputError> {
+    validate_inputs(temperature, relative_humidity)?;
+
+    //convert units
+    let temperature = temperature - ZERO_CELSIUS;
+    let relative_humidity = relative_humidity * 100.0;
+
+    let result = (1.8 * temperature + 32.0)
+        - (0.55 - 0.0055 * relative_humidity) * (1.8 * temperature - 26.0);
+
+    Ok(result)
+}
+
+///Formula for computing effective temperature from temperature and relative humidity.
+///
+///Derived by F. A. Missenard (1937). Température effective d'une atmosphère. Généralisation.
+///Température résultante d'un milieu. Encyclopédie Industrielle et Commerciale, 131-185.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 253K - 333K\
+///Valid `relative_humidity` range: 0.0 - 1.0
+#[cfg_attr(feature = "debug", logerr)]
+pub fn effective_temperature1(
+    temperature: Float,
+    relative_humidity: Float,
+) -> Result<Float, InputError> {
+    validate_inputs(temperature, relative_humidity)?;
+
+    //convert units
+    let temperature = temperature - ZERO_CELSIUS;
+
+    let result = temperature - 0.4 * (temperature - 10.0) * (1.0 - relative_humidity);
+
+    Ok(result + ZERO_CELSIUS)
+}
+
+fn humidex(temperature: Float, vapour_pressure: Float) -> Float {
+    temperature + 0.5555 * ((vapour_pressure / 100.0) - 10.0)
+}
+
+fn validate_inputs(temperature: Float, relative_humidity: Float) -> Result<(), InputError> {
+    if !(253.0..=333.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(0.0..=1.0).contains(&relative_humidity) {
+        return Err(InputError::OutOfRange(String::from("relative_humidity")));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        comfort_index,
+        tests_framework::{self, Argument},
+    };
+
+    #[test]
+    fn humidex1() {
+        assert!(tests_framework::test_with_2args(
+            &comfort_index::humidex1,
+            Argument {
+                name: "temperature",
+                def_val: 303.15,
+                range: [273.0, 333.0]
+            },
+            Argument {
+                name: "dewpoint",
+                def_val: 293.15,
+                range: [232.0, 324.0]
+            },
+            310.7198
+        ));
+    }
+
+    #[test]
+    fn humidex2() {
+        assert!(tests_framework::test_with_2args(
+            &comfort_index::humidex2,
+            Argument {
+                name: "temperature",
+                def_val: 303.15,
+                range: [273.0, 333.0]
+            },
+            Argument {
+                name: "vapour_pressure",
+                def_val: 2000.0,
+                range: [0.0, 10_000.0]
+            },
+            308.705
+        ));
+    }
+
+    #[test]
+    fn discomfort_index1() {
+        assert!(tests_framework::test_with_2args(
+            &comfort_index::discomfort_index1,
+            Argument {
+                name: "temperature",
+                def_val: 303.15,
+                range: [253.0, 333.0]
+            },
+            Argument {
+                name: "relative_humidity",
+                def_val: 0.5,
+                range: [0.0, 1.0]
+            },
+            298.8875
+        ));
+    }
+
+    #[test]
+    fn temperature_humidity_index1() {
+        assert!(tests_framework::test_with_2args(
+            &comfort_index::temperature_humidity_index1,
+            Argument {
+                name: "temperature",
+                def_val: 303.15,
+                range: [253.0, 333.0]
+            },
+            Argument {
+                name: "relative_humidity",
+                def_val: 0.5,
+                range: [0.0, 1.0]
+            },
+            78.3
+        ));
+    }
+
+    #[test]
+    fn effective_temperature1() {
+        assert!(tests_framework::test_with_2args(
+            &comfort_index::effective_temperature1,
+            Argument {
+                name: "temperature",
+                def_val: 303.15,
+                range: [253.0, 333.0]
+            },
+            Argument {
+                name: "relative_humidity",
+                def_val: 0.5,
+                range: [0.0, 1.0]
+            },
+            299.15
+        ));
+    }
+}
@@ -8,13 +8,19 @@ pub const ZERO_CELSIUS: Float = 273.15;
 ///Gravitational acceleration in `m s^-2`
 pub const G: Float = 9.80665;
 
+///Stefan-Boltzmann constant in `W m^-2 K^-4`
+#[allow(clippy::excessive_precision)]
+pub const STEFAN_BOLTZMANN: Float = 5.670_374_419e-8;
+
 ///Universal gas constant in `J K^-1 mol^-1`
+#[allow(clippy::excessive_precision)]
 pub const R: Float = 8.314_462_618_153_24;
 
 ///Molar mass of dry air in `kg mol^-1` (ECMWF, 2020)
 pub const M_D: Float = 0.028_964_4;
 
 ///Molar mass of water vapour in `kg mol^-1`
+#[allow(clippy::excessive_precision)]
 pub const M_V: Float = 0.018_015_283_3;
 
 ///Specific heat capacity of dry air at constant pressure in `J kg^-1 K^-1` (ECMWF, 2020)
@@ -38,6 +44,12 @@ pub const C_S: Float = 2106.0;
 ///Mass latent heat of vapourization of water in `J kg^1`  (ECMWF, 2020)
 pub const L_V: Float = 2_500_800.0;
 
+///Mass latent heat of fusion of water in `J kg^1`  (ECMWF, 2020)
+pub const L_F: Float = 333_700.0;
+
+///Mass latent heat of sublimation of water in `J kg^1`  (ECMWF, 2020)
+pub const L_S: Float = L_V + L_F;
+
 ///Ratio of molar masses of dry air and water vapour in `no unit`
 pub const EPSILON: Float = M_V / M_D;
 
@@ -46,3 +58,6 @@ pub const R_D: Float = R / M_D;
 
 ///Specific gas constant for water vapour in `J kg^-1 K^-1`
 pub const R_V: Float = R / M_V;
+
+///Ratio of specific gas constant and specific heat capacity at constant pressure of dry air in `no unit`
+pub const KAPPA: Float = R_D / C_P;
@@ -0,0 +1,225 @@
+//!Functions to diagnose formation of condensation trails (contrails) behind aircraft.
+//!
+//!Contrails form when the mixture of engine exhaust and ambient air reaches saturation
+//!with respect to liquid water (Schmidt-Appleman criterion). Contrails formed in air
+//!supersaturated with respect to ice are persistent, which can be checked with
+//![`relative_humidity::ice_supersaturation1`](crate::relative_humidity::ice_supersaturation1).
+//!
+//!All formulas assume kerosene fuel with water vapour emission index of 1.25 kg/kg
+//!and combustion heat of 43.2 MJ/kg. Relative humidity is always with respect to water.
+
+use crate::Float;
+use crate::{
+    constants::{C_P, EPSILON, ZERO_CELSIUS},
+    errors::InputError,
+    vapour_pressure,
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+//water vapour emission index of kerosene in kg/kg
+const EMISSION_INDEX: Float = 1.25;
+
+//combustion heat of kerosene in J/kg
+const COMBUSTION_HEAT: Float = 43.2e6;
+
+///Formula for computing slope of the mixing line of engine exhaust and ambient air in Pa*K^-1
+///from pressure and overall propulsion efficiency of the aircraft.
+///
+///Derived by U. Schumann (1996) [(doi:10.1127/metz/5/1996/4)](https://doi.org/10.1127/metz/5/1996/4).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `pressure` range: 5000Pa - 110000Pa\
+///Valid `propulsion_efficiency` range: 0.0 - 0.6
+#[cfg_attr(feature = "debug", logerr)]
+pub fn mixing_line_slope1(
+    pressure: Float,
+    propulsion_efficiency: Float,
+) -> Result<Float, InputError> {
+    if !(5000.0..=110_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    if !(0.0..=0.6).contains(&propulsion_efficiency) {
+        return Err(InputError::OutOfRange(String::from(
+            "propulsion_efficiency",
+        )));
+    }
+
+    let result = (EMISSION_INDEX * C_P * pressure)
+        / (EPSILON * COMBUSTION_HEAT * (1.0 - propulsion_efficiency));
+
+    Ok(result)
+}
+
+///Formula for computing threshold temperature of contrail formation in K
+///from relative humidity, pressure and overall propulsion efficiency of the aircraft.
+///Contrails form when ambient temperature is lower than the threshold temperature.
+///
+///Derived by U. Schumann (1996) [(doi:10.1127/metz/5/1996/4)](https://doi.org/10.1127/metz/5/1996/4).
+///Threshold temperature for saturated air is computed with the approximation from the paper,
+///for unsaturated air it is found with bisection using [`vapour_pressure::sonntag1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `relative_humidity` range: 0.0 - 1.0\
+///Valid `pressure` range: 5000Pa - 110000Pa\
+///Valid `propulsion_efficiency` range: 0.0 - 0.6
+#[cfg_attr(feature = "debug", logerr)]
+pub fn threshold_temperature1(
+    relative_humidity: Float,
+    pressure: Float,
+    propulsion_efficiency: Float,
+) -> Result<Float, InputError> {
+    if !(0.0..=1.0).contains(&relative_humidity) {
+        return Err(InputError::OutOfRange(String::from("relative_humidity")));
+    }
+
+    let slope = mixing_line_slope1(pressure, propulsion_efficiency)?;
+
+    //threshold temperature for saturated air, where mixing line is tangent to saturation curve
+    let ln_slope = (slope - 0.053).ln();
+    let saturated_threshold = -46.46 + 9.43 * ln_slope + 0.72 * ln_slope * ln_slope + ZERO_CELSIUS;
+
+    let saturated_vapour_pressure = vapour_pressure::sonntag1(saturated_threshold)?;
+
+    //mixing line starting at ambient air must reach saturation curve
+    let excess = |temperature: Float| -> Result<Float, InputError> {
+        Ok(saturated_vapour_pressure
+            - slope * (saturated_threshold - temperature)
+            - relative_humidity * vapour_pressure::sonntag1(temperature)?)
+    };
+
+    let mut lower = saturated_threshold - saturated_vapour_pressure / slope;
+    let mut upper = saturated_threshold;
+
+    if excess(upper)? <= 0.0 {
+        return Ok(saturated_threshold);
+    }
+
+    //excess increases monotonically with temperature below saturated threshold
+    for _ in 0..40 {
+        let middle = 0.5 * (lower + upper);
+
+        if excess(middle)? < 0.0 {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+
+    Ok(0.5 * (lower + upper))
+}
+
+///Schmidt-Appleman criterion of contrail formation from temperature, relative humidity, pressure
+///and overall propulsion efficiency of the aircraft. Returns `true` when contrails form,
+///that is when `temperature` is lower than [`threshold_temperature1`].
+///
+///Derived by U. Schumann (1996) [(doi:10.1127/metz/5/1996/4)](https://doi.org/10.1127/metz/5/1996/4).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 173K - 273K\
+///Valid `relative_humidity` range: 0.0 - 1.0\
+///Valid `pressure` range: 5000Pa - 110000Pa\
+///Valid `propulsion_efficiency` range: 0.0 - 0.6
+#[cfg_attr(feature = "debug", logerr)]
+pub fn schmidt_appleman1(
+    temperature: Float,
+    relative_humidity: Float,
+    pressure: Float,
+    propulsion_efficiency: Float,
+) -> Result<bool, InputError> {
+    if !(173.0..=273.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let threshold = threshold_temperature1(relative_humidity, pressure, propulsion_efficiency)?;
+
+    Ok(temperature < threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        contrail,
+        errors::InputError,
+        tests_framework::{self, Argument},
+        vapour_pressure, Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn mixing_line_slope1() {
+        assert!(tests_framework::test_with_2args(
+            &contrail::mixing_line_slope1,
+            Argument {
+                name: "pressure",
+                def_val: 25000.0,
+                range: [5000.0, 110_000.0]
+            },
+            Argument {
+                name: "propulsion_efficiency",
+                def_val: 0.3,
+                range: [0.0, 0.6]
+            },
+            1.669_290_5
+        ));
+    }
+
+    #[test]
+    fn threshold_temperature1() {
+        assert!(tests_framework::test_with_3args(
+            &contrail::threshold_temperature1,
+            Argument {
+                name: "relative_humidity",
+                def_val: 0.5,
+                range: [0.0, 1.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 25000.0,
+                range: [5000.0, 110_000.0]
+            },
+            Argument {
+                name: "propulsion_efficiency",
+                def_val: 0.3,
+                range: [0.0, 0.6]
+            },
+            224.028_29
+        ));
+
+        //mixing line is tangent to saturation curve at threshold temperature of saturated air
+        let threshold = contrail::threshold_temperature1(1.0, 25000.0, 0.3).unwrap();
+        let tangent = (vapour_pressure::sonntag1(threshold + 0.01).unwrap()
+            - vapour_pressure::sonntag1(threshold - 0.01).unwrap())
+            / 0.02;
+        let slope = contrail::mixing_line_slope1(25000.0, 0.3).unwrap();
+        assert_approx_eq!(Float, tangent / slope, 1.0, epsilon = 0.01);
+
+        //threshold temperature increases with relative humidity
+        let mut previous = 0.0;
+
+        for relative_humidity in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let result = contrail::threshold_temperature1(relative_humidity, 25000.0, 0.3).unwrap();
+            assert!(result > previous);
+            previous = result;
+        }
+    }
+
+    #[test]
+    fn schmidt_appleman1() {
+        assert!(contrail::schmidt_appleman1(218.15, 0.5, 25000.0, 0.3).unwrap());
+        assert!(!contrail::schmidt_appleman1(238.15, 0.5, 25000.0, 0.3).unwrap());
+
+        assert_eq!(
+            contrail::schmidt_appleman1(300.0, 0.5, 25000.0, 0.3).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+    }
+}
@@ -0,0 +1,518 @@
+//!Functions to calculate convective available potential energy (CAPE) and convective inhibition (CIN) in J*kg^-1.
+//!
+//!CAPE is the work done by buoyancy on air parcel lifted from its level of free convection (LFC)
+//!to its equilibrium level (EL). CIN is the (negative) work done by buoyancy on the parcel
+//!lifted from its initial level to the LFC.
+//!
+//!Parcel is lifted through the sounding with [`parcel::profile1`].
+//!
+//!Downdraft CAPE (DCAPE) is the work done by negative buoyancy on saturated air parcel
+//!lowered from its source level to the surface.
+
+use crate::Float;
+use crate::{
+    constants::R_D,
+    equivalent_potential_temperature,
+    errors::InputError,
+    moist_adiabat,
+    parcel::{self, ParcelKind},
+    sounding::Sounding,
+    wet_bulb_temperature,
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Convective energy of air parcel lifted through the sounding.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ConvectiveEnergy {
+    ///Convective available potential energy in `J*kg^-1`
+    pub cape: Float,
+    ///Convective inhibition in `J*kg^-1` (zero or negative)
+    pub cin: Float,
+    ///Pressure of level of free convection in `Pa`,
+    ///`None` when parcel never becomes positively buoyant above its LCL
+    pub level_of_free_convection: Option<Float>,
+    ///Pressure of equilibrium level in `Pa`,
+    ///`None` when there is no LFC or parcel is positively buoyant at the top of the sounding
+    pub equilibrium_level: Option<Float>,
+}
+
+///Formula for computing CAPE and CIN of air parcel lifted through the sounding
+///from difference between parcel and environmental temperature.
+///
+///Levels of the sounding skipped by [`Sounding::thermodynamic_profile`] are not used.
+///Parcel is lifted as described in [`parcel::profile1`] and CAPE and CIN are integrated over logarithm
+///of pressure, with LFC and EL found by linear interpolation of temperature difference.
+///When the parcel has no LFC both CAPE and CIN are 0.
+///
+///This formula neglects the virtual temperature correction, see [`doswell_rasmussen1`].
+///
+///# Errors
+///
+///Returns the errors of [`parcel::profile1`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn general1(
+    sounding: &Sounding,
+    parcel_kind: ParcelKind,
+    freezing_range: Option<[Float; 2]>,
+) -> Result<ConvectiveEnergy, InputError> {
+    convective_energy(sounding, parcel_kind, freezing_range, false)
+}
+
+///Formula for computing CAPE and CIN of air parcel lifted through the sounding
+///from difference between parcel and environmental virtual temperature.
+///
+///The virtual temperature correction has been recommended by C. A. Doswell III & E. N. Rasmussen (1994)
+///[(doi:10.1175/1520-0434(1994)009<0625:TEONTV>2.0.CO;2)](https://doi.org/10.1175/1520-0434(1994)009%3C0625:TEONTV%3E2.0.CO;2)
+///as neglecting it can result in significant errors, especially for small CAPE.
+///
+///Levels of the sounding skipped by [`Sounding::thermodynamic_profile`] are not used.
+///Parcel is lifted as described in [`parcel::profile1`] and CAPE and CIN are integrated over logarithm
+///of pressure, with LFC and EL found by linear interpolation of virtual temperature difference.
+///When the parcel has no LFC both CAPE and CIN are 0.
+///
+///# Errors
+///
+///Returns the errors of [`parcel::profile1`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn doswell_rasmussen1(
+    sounding: &Sounding,
+    parcel_kind: ParcelKind,
+    freezing_range: Option<[Float; 2]>,
+) -> Result<ConvectiveEnergy, InputError> {
+    convective_energy(sounding, parcel_kind, freezing_range, true)
+}
+
+///Formula for computing downdraft CAPE (DCAPE) of air parcel lowered from its source level to the surface.
+///
+///The source level is the level of minimum equivalent potential temperature ([`equivalent_potential_temperature::bolton1`])
+///within `source_layer` (`[bottom, top]` pressure in Pa), levels outside of the valid range of that formula
+///and levels skipped by [`Sounding::thermodynamic_profile`] are not used. The parcel is cooled to its wet bulb temperature
+///([`wet_bulb_temperature::normand1`]) and lowered along saturated pseudo-adiabat
+///([`moist_adiabat::saturated_descent1`]), staying saturated by evaporation of precipitation. DCAPE is integrated over logarithm of pressure
+///from difference between environmental and parcel temperature, so it is positive for negatively buoyant parcel.
+///
+///Commonly the source layer is the lowest 400hPa of the sounding, as proposed by
+///D. M. Gilmore & L. J. Wicker (1998) [(doi:10.1175/1520-0493(1998)126<0943:TIOMEO>2.0.CO;2)](https://doi.org/10.1175/1520-0493(1998)126%3C0943:TIOMEO%3E2.0.CO;2)
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when `source_layer` is out of range.\
+///Valid `source_layer` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `source_layer` top is not lower than its bottom
+///or no valid level of the sounding lies within it.
+///
+///Returns the errors of [`parcel::profile1`], [`wet_bulb_temperature::normand1`] and [`moist_adiabat::saturated_descent1`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn downdraft1(sounding: &Sounding, source_layer: [Float; 2]) -> Result<Float, InputError> {
+    let [bottom, top] = source_layer;
+
+    if !(100.0..=150_000.0).contains(&bottom) || !(100.0..=150_000.0).contains(&top) {
+        return Err(InputError::OutOfRange(String::from("source_layer")));
+    }
+
+    if top >= bottom {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "top of source_layer must be lower than its bottom",
+        )));
+    }
+
+    let [pressure, temperature, dewpoint] = sounding.thermodynamic_profile();
+    parcel::validate_profile(&pressure, &temperature, &dewpoint)?;
+
+    let mut source_index = None;
+    let mut min_theta_e = Float::INFINITY;
+
+    for i in 0..pressure.len() {
+        if !(top..=bottom).contains(&pressure[i]) {
+            continue;
+        }
+
+        if let Ok(theta_e) =
+            equivalent_potential_temperature::bolton1(pressure[i], temperature[i], dewpoint[i])
+        {
+            if theta_e < min_theta_e {
+                source_index = Some(i);
+                min_theta_e = theta_e;
+            }
+        }
+    }
+
+    let source_index = match source_index {
+        Some(index) => index,
+        None => {
+            return Err(InputError::IncorrectArgumentSet(String::from(
+                "no valid level of the sounding within source_layer",
+            )))
+        }
+    };
+
+    let source_pressure = pressure[source_index];
+    let wet_bulb = wet_bulb_temperature::normand1(
+        temperature[source_index],
+        dewpoint[source_index],
+        source_pressure,
+    )?;
+
+    let descent_levels: Vec<Float> = pressure[..source_index].iter().rev().copied().collect();
+    let states =
+        moist_adiabat::saturated_descent1(wet_bulb, source_pressure, &descent_levels, None)?;
+
+    let mut differences = vec![(source_pressure.ln(), temperature[source_index] - wet_bulb)];
+
+    for (state, i) in states.iter().zip((0..source_index).rev()) {
+        differences.push((pressure[i].ln(), temperature[i] - state.temperature));
+    }
+
+    let result = differences
+        .windows(2)
+        .map(|pair| R_D * 0.5 * (pair[0].1 + pair[1].1) * (pair[1].0 - pair[0].0))
+        .sum();
+
+    Ok(result)
+}
+
+fn convective_energy(
+    sounding: &Sounding,
+    parcel_kind: ParcelKind,
+    freezing_range: Option<[Float; 2]>,
+    virtual_correction: bool,
+) -> Result<ConvectiveEnergy, InputError> {
+    let [pressure, temperature, dewpoint] = sounding.thermodynamic_profile();
+
+    let parcel_profile = parcel::profile1(
+        &pressure,
+        &temperature,
+        &dewpoint,
+        parcel_kind,
+        freezing_range,
+    )?;
+
+    let environment_temperature = &temperature[parcel_profile.start_index..];
+    let [lcl_pressure, _] = parcel_profile.lifting_condensation_level;
+
+    let differences: Vec<(Float, Float)> = parcel_profile
+        .levels
+        .iter()
+        .zip(environment_temperature)
+        .map(|(parcel_level, environment_temperature)| {
+            let difference = if virtual_correction {
+                parcel_level.virtual_temperature - parcel_level.environment_virtual_temperature
+            } else {
+                parcel_level.temperature - environment_temperature
+            };
+
+            (parcel_level.pressure.ln(), difference)
+        })
+        .collect();
+
+    Ok(integrate_areas(&differences, lcl_pressure.ln()))
+}
+
+//integrates positive and negative areas of the profile of temperature differences
+//given as (log pressure, difference) pairs ordered from the bottom up
+fn integrate_areas(differences: &[(Float, Float)], lcl_log_pressure: Float) -> ConvectiveEnergy {
+    let mut points: Vec<(Float, Float)> = vec![differences[0]];
+
+    for pair in differences.windows(2) {
+        let [(x_a, d_a), (x_b, d_b)] = [pair[0], pair[1]];
+
+        //LCL is inserted to find LFC of parcels that are buoyant at their LCL
+        if x_a > lcl_log_pressure && lcl_log_pressure > x_b {
+            let d_lcl = d_a + (d_b - d_a) * (lcl_log_pressure - x_a) / (x_b - x_a);
+            insert_with_crossing(&mut points, (lcl_log_pressure, d_lcl));
+        }
+
+        insert_with_crossing(&mut points, (x_b, d_b));
+    }
+
+    let lfc_index = (0..points.len() - 1)
+        .find(|&i| points[i].0 <= lcl_log_pressure && points[i].1 >= 0.0 && points[i + 1].1 > 0.0);
+
+    let lfc_index = match lfc_index {
+        Some(index) => index,
+        None => return ConvectiveEnergy::default(),
+    };
+
+    let el_index =
+        (lfc_index + 1..points.len()).rfind(|&i| points[i - 1].1 > 0.0 && points[i].1 <= 0.0);
+
+    let top_index = el_index.unwrap_or(points.len() - 1);
+
+    let area =
+        |i: usize| R_D * 0.5 * (points[i].1 + points[i + 1].1) * (points[i].0 - points[i + 1].0);
+
+    let cape = (lfc_index..top_index).map(area).filter(|a| *a > 0.0).sum();
+    let cin = (0..lfc_index).map(area).filter(|a| *a < 0.0).sum();
+
+    ConvectiveEnergy {
+        cape,
+        cin,
+        level_of_free_convection: Some(points[lfc_index].0.exp()),
+        equilibrium_level: el_index.map(|i| points[i].0.exp()),
+    }
+}
+
+//pushes point to the profile, inserting zero crossing before it if sign changes
+fn insert_with_crossing(points: &mut Vec<(Float, Float)>, point: (Float, Float)) {
+    let (x_a, d_a) = points[points.len() - 1];
+    let (x_b, d_b) = point;
+
+    if d_a * d_b < 0.0 {
+        let x_zero = x_a + (x_b - x_a) * d_a / (d_a - d_b);
+        points.push((x_zero, 0.0));
+    }
+
+    points.push(point);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constants::R_D,
+        convective_available_potential_energy,
+        errors::InputError,
+        moist_adiabat,
+        parcel::{self, ParcelKind},
+        sounding::Sounding,
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    const PRESSURE: [Float; 12] = [
+        100_000.0, 95000.0, 90000.0, 85000.0, 80000.0, 70000.0, 60000.0, 50000.0, 40000.0, 30000.0,
+        25000.0, 20000.0,
+    ];
+
+    #[test]
+    fn general1_analytic() {
+        //environment is 2K colder than surface parcel above 850hPa
+        //and 2K warmer below it, so CAPE can be computed analytically
+        let dewpoint = [
+            292.0, 289.0, 286.0, 283.0, 279.0, 270.0, 262.0, 250.0, 238.0, 225.0, 215.0, 205.0,
+        ];
+
+        let states =
+            parcel::ascent1(300.0, 100_000.0, 0.0145, &PRESSURE, Some([273.15, 250.15])).unwrap();
+
+        let mut temperature: Vec<Float> = states.iter().map(|s| s.temperature).collect();
+        for (i, t) in temperature.iter_mut().enumerate() {
+            *t += if PRESSURE[i] > 85000.0 { 2.0 } else { -2.0 };
+        }
+        temperature[0] = 300.0;
+        temperature[3] = states[3].temperature;
+
+        let mut dewpoint = dewpoint.to_vec();
+        dewpoint[0] = parcel_dewpoint();
+
+        let sounding = Sounding::from_si(&PRESSURE, &temperature, &dewpoint).unwrap();
+
+        let result = convective_available_potential_energy::general1(
+            &sounding,
+            ParcelKind::SurfaceBased,
+            Some([273.15, 250.15]),
+        )
+        .unwrap();
+
+        //difference increases linearly from 0 to 2K between 850hPa and 800hPa
+        let expected_cape = R_D * 2.0 * (80000.0 as Float / 20000.0).ln()
+            + R_D * 1.0 * (85000.0 as Float / 80000.0).ln();
+
+        assert_approx_eq!(Float, result.cape, expected_cape, epsilon = 1.0);
+        assert!(result.cin < 0.0);
+        assert_approx_eq!(
+            Float,
+            result.level_of_free_convection.unwrap(),
+            85000.0,
+            epsilon = 1.0
+        );
+        assert_eq!(result.equilibrium_level, None);
+    }
+
+    #[test]
+    fn general1_metpy() {
+        //sounding from MetPy test suite (test_cape_cin in metpy/calc/tests/test_thermo.py),
+        //where CAPE is about 75.7 J/kg and CIN about -89.8 J/kg without virtual temperature correction;
+        //MetPy lifts the parcel with constant latent heat and a different LCL computation,
+        //so results are compared within 10%
+        let sounding = Sounding::from_hpa_celsius(
+            &[959.0, 779.2, 751.3, 724.3, 700.0, 269.0],
+            &[22.2, 14.6, 12.0, 9.4, 7.0, -38.0],
+            &[19.0, -11.2, -10.8, -10.4, -10.0, -53.2],
+        )
+        .unwrap();
+
+        let result = convective_available_potential_energy::general1(
+            &sounding,
+            ParcelKind::SurfaceBased,
+            None,
+        )
+        .unwrap();
+
+        assert_approx_eq!(Float, result.cape / 75.7, 1.0, epsilon = 0.1);
+        assert_approx_eq!(Float, result.cin / -89.8, 1.0, epsilon = 0.1);
+
+        //LFC lies in the layer between 751.3hPa and 700hPa and EL below the top level
+        let lfc = result.level_of_free_convection.unwrap();
+        let el = result.equilibrium_level.unwrap();
+        assert!((70000.0..=75130.0).contains(&lfc));
+        assert!((26900.0..lfc).contains(&el));
+    }
+
+    //dewpoint at which mixing ratio at 1000hPa is 0.0145
+    fn parcel_dewpoint() -> Float {
+        let mut lower = 280.0;
+        let mut upper = 300.0;
+
+        for _ in 0..40 {
+            let middle = 0.5 * (lower + upper);
+            let vapour_pressure = parcel::environment_vapour_pressure(middle, 100_000.0).unwrap();
+            let mixing_ratio = crate::mixing_ratio::general1(100_000.0, vapour_pressure).unwrap();
+
+            if mixing_ratio > 0.0145 {
+                upper = middle;
+            } else {
+                lower = middle;
+            }
+        }
+
+        0.5 * (lower + upper)
+    }
+
+    fn convective_sounding() -> Sounding {
+        Sounding::from_hpa_celsius(
+            &[
+                1000.0, 950.0, 900.0, 850.0, 800.0, 700.0, 600.0, 500.0, 400.0, 300.0, 250.0,
+                200.0, 150.0,
+            ],
+            &[
+                30.0, 25.5, 21.5, 18.0, 15.0, 8.0, 0.0, -8.5, -19.0, -33.0, -42.0, -52.0, -58.0,
+            ],
+            &[
+                22.0, 20.0, 17.5, 14.0, 10.0, 1.0, -8.0, -20.0, -32.0, -45.0, -53.0, -62.0, -70.0,
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn doswell_rasmussen1() {
+        let sounding = convective_sounding();
+        let freezing_range = Some([273.15, 250.15]);
+
+        let corrected = convective_available_potential_energy::doswell_rasmussen1(
+            &sounding,
+            ParcelKind::SurfaceBased,
+            freezing_range,
+        )
+        .unwrap();
+        let plain = convective_available_potential_energy::general1(
+            &sounding,
+            ParcelKind::SurfaceBased,
+            freezing_range,
+        )
+        .unwrap();
+
+        assert!(corrected.cape > 1000.0);
+        assert!(corrected.cape > plain.cape);
+        assert!(corrected.cin <= 0.0);
+
+        let lfc = corrected.level_of_free_convection.unwrap();
+        let el = corrected.equilibrium_level.unwrap();
+        assert!(lfc < 100_000.0 && lfc > 70000.0);
+        assert!(el < 25000.0 && el > 15000.0);
+
+        let mixed_layer = convective_available_potential_energy::doswell_rasmussen1(
+            &sounding,
+            ParcelKind::MixedLayer(10000.0),
+            freezing_range,
+        )
+        .unwrap();
+        let most_unstable = convective_available_potential_energy::doswell_rasmussen1(
+            &sounding,
+            ParcelKind::MostUnstable(30000.0),
+            freezing_range,
+        )
+        .unwrap();
+
+        assert!(mixed_layer.cape < corrected.cape);
+        assert!(most_unstable.cape >= corrected.cape);
+    }
+
+    #[test]
+    fn downdraft1_analytic() {
+        //environment is 2K warmer than saturated parcel lowered from 700hPa
+        let levels = [100_000.0, 90000.0, 80000.0];
+        let states = moist_adiabat::saturated_descent1(280.0, 70000.0, &levels, None).unwrap();
+
+        let mut temperature: Vec<Float> = states.iter().map(|s| s.temperature + 2.0).collect();
+        temperature.extend([280.0, 265.0]);
+        let mut dewpoint: Vec<Float> = temperature.iter().map(|t| t - 10.0).collect();
+        dewpoint[3] = 280.0;
+
+        let sounding = Sounding::from_si(
+            &[100_000.0, 90000.0, 80000.0, 70000.0, 50000.0],
+            &temperature,
+            &dewpoint,
+        )
+        .unwrap();
+
+        let result =
+            convective_available_potential_energy::downdraft1(&sounding, [70000.0, 75000.0]);
+        assert!(matches!(result, Err(InputError::IncorrectArgumentSet(_))));
+
+        let result =
+            convective_available_potential_energy::downdraft1(&sounding, [69000.0, 60000.0]);
+        assert!(matches!(result, Err(InputError::IncorrectArgumentSet(_))));
+
+        let result =
+            convective_available_potential_energy::downdraft1(&sounding, [75000.0, 65000.0])
+                .unwrap();
+
+        //difference increases linearly from 0 to 2K between 700hPa and 800hPa
+        let expected = R_D * 2.0 * (100_000.0 as Float / 80000.0).ln()
+            + R_D * 1.0 * (80000.0 as Float / 70000.0).ln();
+
+        assert_approx_eq!(Float, result, expected, epsilon = 1.0);
+    }
+
+    #[test]
+    fn downdraft1() {
+        let sounding = convective_sounding();
+
+        let result =
+            convective_available_potential_energy::downdraft1(&sounding, [100_000.0, 60000.0])
+                .unwrap();
+        assert!(result > 200.0 && result < 1500.0);
+
+        assert_eq!(
+            convective_available_potential_energy::downdraft1(&sounding, [200_000.0, 60000.0])
+                .unwrap_err(),
+            InputError::OutOfRange(String::from("source_layer"))
+        );
+    }
+
+    #[test]
+    fn stable() {
+        let sounding = Sounding::from_hpa_celsius(
+            &[1000.0, 850.0, 700.0, 500.0, 300.0],
+            &[10.0, 12.0, 4.0, -10.0, -30.0],
+            &[0.0, -10.0, -20.0, -35.0, -50.0],
+        )
+        .unwrap();
+
+        let result = convective_available_potential_energy::doswell_rasmussen1(
+            &sounding,
+            ParcelKind::SurfaceBased,
+            Some([273.15, 250.15]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result,
+            convective_available_potential_energy::ConvectiveEnergy::default()
+        );
+    }
+}
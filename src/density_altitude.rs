@@ -0,0 +1,109 @@
+//!Functions to calculate density altitude in m.
+//!
+//!Density altitude is the altitude in the standard atmosphere at which air density is equal
+//!to the given air density [Wikipedia](https://en.wikipedia.org/wiki/Density_altitude).
+//!It determines aircraft performance, so it is widely used in aviation.
+
+use crate::Float;
+use crate::{
+    constants::R_D,
+    errors::InputError,
+    standard_atmosphere::{self, Model},
+    virtual_temperature,
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing density altitude from pressure, temperature and vapour pressure.
+///
+///Density of moist air is computed from virtual temperature ([`virtual_temperature::general2`])
+///and the altitude at which [`standard_atmosphere::density1`] of the International Standard Atmosphere
+///is equal to it is found with bisection.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `temperature` range: 173K - 354K\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when density is outside of the range of
+///the International Standard Atmosphere.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn general1(
+    pressure: Float,
+    temperature: Float,
+    vapour_pressure: Float,
+) -> Result<Float, InputError> {
+    if !(100.0..=150_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    if !(173.0..=354.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+        return Err(InputError::OutOfRange(String::from("vapour_pressure")));
+    }
+
+    let virtual_temperature =
+        virtual_temperature::general2(temperature, pressure, vapour_pressure)?;
+    let density = pressure / (R_D * virtual_temperature);
+
+    let mut lower: Float = -5000.0;
+    let mut upper: Float = 80_000.0;
+
+    if density > standard_atmosphere::density1(lower, Model::Isa)?
+        || density < standard_atmosphere::density1(upper, Model::Isa)?
+    {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "density is outside of the range of the standard atmosphere",
+        )));
+    }
+
+    //density of the standard atmosphere decreases monotonically with altitude
+    for _ in 0..50 {
+        let middle = 0.5 * (lower + upper);
+
+        if standard_atmosphere::density1(middle, Model::Isa)? > density {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+
+    Ok(0.5 * (lower + upper))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        density_altitude,
+        tests_framework::{self, Argument},
+    };
+
+    #[test]
+    fn general1() {
+        assert!(tests_framework::test_with_3args(
+            &density_altitude::general1,
+            Argument {
+                name: "pressure",
+                def_val: 85000.0,
+                range: [100.0, 150_000.0]
+            },
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [173.0, 354.0]
+            },
+            Argument {
+                name: "vapour_pressure",
+                def_val: 2000.0,
+                range: [0.0, 10_000.0]
+            },
+            2282.04
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+//!Functions to calculate dewpoint temperature in K.
+//!
+//!Dewpoint is computed with respect to liquid water, also below 0°C.
+
+use crate::Float;
+use crate::{
+    errors::InputError,
+    vapour_pressure::{self, Psychrometer},
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing dewpoint from vapour pressure and pressure
+///by inverting [`vapour_pressure::buck1`] with bisection.
+///
+///Derived by A. L. Buck (1981) [(doi: 10.1175/1520-0450(1981)020<1527:nefcvp>2.0.co;2)](https://doi.org/10.1175/1520-0450(1981)020%3C1527:NEFCVP%3E2.0.CO;2).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when dewpoint is outside of 232K - 324K range.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck1(vapour_pressure: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+        return Err(InputError::OutOfRange(String::from("vapour_pressure")));
+    }
+
+    if !(100.0..=150_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    let mut lower = 232.0;
+    let mut upper = 324.0;
+
+    if vapour_pressure < vapour_pressure::buck1(lower, pressure)?
+        || vapour_pressure > vapour_pressure::buck1(upper, pressure)?
+    {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "dewpoint is outside of 232K - 324K range",
+        )));
+    }
+
+    //vapour pressure increases monotonically with dewpoint
+    for _ in 0..40 {
+        let middle = 0.5 * (lower + upper);
+
+        if vapour_pressure::buck1(middle, pressure)? < vapour_pressure {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+
+    Ok(0.5 * (lower + upper))
+}
+
+///Formula for computing dewpoint from dry bulb and wet bulb temperatures and pressure
+///measured with psychrometer.
+///
+///Vapour pressure is computed with [`vapour_pressure::psychrometric1`] and converted to dewpoint with [`buck1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K\
+///Valid `wet_bulb_temperature` range: 232K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `wet_bulb_temperature` is higher than `temperature`,
+///when the readings give negative vapour pressure or when dewpoint is below 232K.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn psychrometric1(
+    temperature: Float,
+    wet_bulb_temperature: Float,
+    pressure: Float,
+    psychrometer: Psychrometer,
+) -> Result<Float, InputError> {
+    let vapour_pressure =
+        vapour_pressure::psychrometric1(temperature, wet_bulb_temperature, pressure, psychrometer)?;
+
+    buck1(vapour_pressure, pressure)
+}
+
+///Formula for computing dewpoint from dry bulb and ice bulb temperatures and pressure
+///measured with psychrometer which wet bulb is covered with ice.
+///
+///Vapour pressure is computed with [`vapour_pressure::psychrometric2`] and converted to dewpoint with [`buck1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 193K - 324K\
+///Valid `ice_bulb_temperature` range: 193K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `ice_bulb_temperature` is higher than `temperature`,
+///when the readings give negative vapour pressure or when dewpoint is below 232K.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn psychrometric2(
+    temperature: Float,
+    ice_bulb_temperature: Float,
+    pressure: Float,
+    psychrometer: Psychrometer,
+) -> Result<Float, InputError> {
+    let vapour_pressure =
+        vapour_pressure::psychrometric2(temperature, ice_bulb_temperature, pressure, psychrometer)?;
+
+    buck1(vapour_pressure, pressure)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dewpoint,
+        errors::InputError,
+        vapour_pressure::{self, Psychrometer},
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn buck1() {
+        for (dewpoint, pressure) in [(290.0, 90000.0), (240.0, 50000.0), (315.0, 101_325.0)] {
+            let vapour_pressure = vapour_pressure::buck1(dewpoint, pressure).unwrap();
+            let result = dewpoint::buck1(vapour_pressure, pressure).unwrap();
+            assert_approx_eq!(Float, result, dewpoint, epsilon = 0.001);
+        }
+
+        assert_eq!(
+            dewpoint::buck1(-1.0, 90000.0).unwrap_err(),
+            InputError::OutOfRange(String::from("vapour_pressure"))
+        );
+        assert!(matches!(
+            dewpoint::buck1(1.0, 90000.0),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+
+    #[test]
+    fn psychrometric1() {
+        let result =
+            dewpoint::psychrometric1(293.15, 288.15, 100_000.0, Psychrometer::Aspirated).unwrap();
+        assert_approx_eq!(Float, result, 284.856, epsilon = 0.01);
+
+        let result =
+            dewpoint::psychrometric1(293.15, 288.15, 100_000.0, Psychrometer::NonAspirated)
+                .unwrap();
+        assert_approx_eq!(Float, result, 284.090, epsilon = 0.01);
+    }
+
+    #[test]
+    fn psychrometric2() {
+        let result =
+            dewpoint::psychrometric2(268.15, 266.15, 100_000.0, Psychrometer::Aspirated).unwrap();
+        assert_approx_eq!(Float, result, 260.055, epsilon = 0.01);
+    }
+}
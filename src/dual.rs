@@ -0,0 +1,635 @@
+//!Dual numbers for forward-mode automatic differentiation.
+//!
+//!Formulas generic over [`Number`] trait can be computed with [`Dual`] numbers instead of floating-point numbers,
+//!which gives exact derivatives (tangent-linear sensitivities) of the result with respect to
+//!every input, also for composite formulas like [`equivalent_potential_temperature::bolton1`](crate::equivalent_potential_temperature::bolton1).
+//!
+//!Each input is created with [`Dual::variable`] and a distinct index, the gradient of the result
+//!is then one row of the Jacobian. Input ranges are checked on the values of dual numbers.
+//!
+//!```
+//!use floccus::{dual::Dual, equivalent_potential_temperature};
+//!
+//!let pressure = Dual::<3>::variable(101325.0, 0);
+//!let temperature = Dual::<3>::variable(300.0, 1);
+//!let dewpoint = Dual::<3>::variable(280.0, 2);
+//!
+//!let theta_e = equivalent_potential_temperature::bolton1(pressure, temperature, dewpoint).unwrap();
+//!
+//!println!("{}", theta_e.value); // equivalent potential temperature in K
+//!println!("{:?}", theta_e.gradient); // derivatives in K*Pa^-1, K*K^-1, K*K^-1
+//!```
+//!
+//!Generic formulas are all functions in [`vapour_pressure`](crate::vapour_pressure), [`mixing_ratio`](crate::mixing_ratio),
+//![`relative_humidity`](crate::relative_humidity), [`specific_humidity`](crate::specific_humidity),
+//![`virtual_temperature`](crate::virtual_temperature), [`vapour_pressure_deficit`](crate::vapour_pressure_deficit),
+//![`potential_temperature`](crate::potential_temperature), [`equivalent_potential_temperature`](crate::equivalent_potential_temperature),
+//![`wet_bulb_potential_temperature`](crate::wet_bulb_potential_temperature) and [`latent_heat`](crate::latent_heat) modules,
+//!as well as closed-form [`wet_bulb_temperature::stull1`](crate::wet_bulb_temperature::stull1).
+//!
+//!Other functions take only floating-point numbers. These are formulas solved iteratively
+//!(eg. in [`dewpoint`](crate::dewpoint), [`wet_bulb_temperature`](crate::wet_bulb_temperature) or [`moist_adiabat`](crate::moist_adiabat))
+//!and profile computations built on them (eg. [`parcel`](crate::parcel)), for which dual numbers would differentiate
+//!the iterations instead of the solution. Analytic derivatives of saturation vapour pressure are provided
+//!in [`vapour_pressure_slope`](crate::vapour_pressure_slope).
+
+use crate::Float;
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+///Numeric type over which generic formulas can be computed.
+///
+///Implemented for floating-point numbers and [`Dual`]. Mixed arithmetic with the floating-point number
+///on the left-hand side is also implemented for [`Dual`], generic formulas using it
+///require bounds like `Float: Mul<N, Output = N>`.
+pub trait Number:
+    Copy
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Add<Float, Output = Self>
+    + Sub<Float, Output = Self>
+    + Mul<Float, Output = Self>
+    + Div<Float, Output = Self>
+{
+    ///Creates a number from a constant.
+    fn from_float(value: Float) -> Self;
+
+    ///Returns the value of the number, used for input checking.
+    fn value(self) -> Float;
+
+    ///Returns the multiplicative inverse of the number.
+    fn recip(self) -> Self;
+
+    ///Returns `e` raised to the power of the number.
+    fn exp(self) -> Self;
+
+    ///Returns the natural logarithm of the number.
+    fn ln(self) -> Self;
+
+    ///Returns the base 10 logarithm of the number.
+    fn log10(self) -> Self;
+
+    ///Returns the square root of the number.
+    fn sqrt(self) -> Self;
+
+    ///Returns the hyperbolic tangent of the number.
+    fn tanh(self) -> Self;
+
+    ///Returns the arctangent of the number.
+    fn atan(self) -> Self;
+
+    ///Raises the number to an integer power.
+    fn powi(self, exponent: i32) -> Self;
+
+    ///Raises the number to a constant power.
+    fn powf(self, exponent: Float) -> Self;
+
+    ///Raises the number to a power which is also a number.
+    fn pow(self, exponent: Self) -> Self;
+}
+
+impl Number for Float {
+    fn from_float(value: Float) -> Self {
+        value
+    }
+
+    fn value(self) -> Float {
+        self
+    }
+
+    fn recip(self) -> Self {
+        Float::recip(self)
+    }
+
+    fn exp(self) -> Self {
+        Float::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        Float::ln(self)
+    }
+
+    fn log10(self) -> Self {
+        Float::log10(self)
+    }
+
+    fn sqrt(self) -> Self {
+        Float::sqrt(self)
+    }
+
+    fn tanh(self) -> Self {
+        Float::tanh(self)
+    }
+
+    fn atan(self) -> Self {
+        Float::atan(self)
+    }
+
+    fn powi(self, exponent: i32) -> Self {
+        Float::powi(self, exponent)
+    }
+
+    fn powf(self, exponent: Float) -> Self {
+        Float::powf(self, exponent)
+    }
+
+    fn pow(self, exponent: Self) -> Self {
+        Float::powf(self, exponent)
+    }
+}
+
+///Dual number holding a value and its gradient with respect to `N` input variables.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Dual<const N: usize> {
+    ///Value of the number.
+    pub value: Float,
+    ///Derivatives of the value with respect to each input variable.
+    pub gradient: [Float; N],
+}
+
+impl<const N: usize> Dual<N> {
+    ///Creates a constant, which gradient is zero.
+    pub fn constant(value: Float) -> Self {
+        Dual {
+            value,
+            gradient: [0.0; N],
+        }
+    }
+
+    ///Creates an input variable with derivative equal to one at position `index` of the gradient.
+    ///
+    ///# Panics
+    ///
+    ///Panics when `index` is not lower than `N`.
+    pub fn variable(value: Float, index: usize) -> Self {
+        let mut gradient = [0.0; N];
+        gradient[index] = 1.0;
+
+        Dual { value, gradient }
+    }
+
+    //applies chain rule for a function with given value and derivative
+    fn chain(self, value: Float, derivative: Float) -> Self {
+        Dual {
+            value,
+            gradient: self.gradient.map(|g| g * derivative),
+        }
+    }
+
+    fn combine(self, other: Self, value: Float, self_scale: Float, other_scale: Float) -> Self {
+        let mut gradient = [0.0; N];
+
+        for (i, g) in gradient.iter_mut().enumerate() {
+            *g = self.gradient[i] * self_scale + other.gradient[i] * other_scale;
+        }
+
+        Dual { value, gradient }
+    }
+}
+
+impl<const N: usize> Number for Dual<N> {
+    fn from_float(value: Float) -> Self {
+        Dual::constant(value)
+    }
+
+    fn value(self) -> Float {
+        self.value
+    }
+
+    fn recip(self) -> Self {
+        self.chain(1.0 / self.value, -1.0 / (self.value * self.value))
+    }
+
+    fn exp(self) -> Self {
+        let value = self.value.exp();
+        self.chain(value, value)
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.value.ln(), 1.0 / self.value)
+    }
+
+    fn log10(self) -> Self {
+        self.chain(self.value.log10(), 1.0 / (self.value * Float::ln(10.0)))
+    }
+
+    fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        self.chain(value, 0.5 / value)
+    }
+
+    fn tanh(self) -> Self {
+        let value = self.value.tanh();
+        self.chain(value, 1.0 - value * value)
+    }
+
+    fn atan(self) -> Self {
+        self.chain(self.value.atan(), 1.0 / (1.0 + self.value * self.value))
+    }
+
+    fn powi(self, exponent: i32) -> Self {
+        self.chain(
+            self.value.powi(exponent),
+            exponent as Float * self.value.powi(exponent - 1),
+        )
+    }
+
+    fn powf(self, exponent: Float) -> Self {
+        self.chain(
+            self.value.powf(exponent),
+            exponent * self.value.powf(exponent - 1.0),
+        )
+    }
+
+    fn pow(self, exponent: Self) -> Self {
+        let value = self.value.powf(exponent.value);
+
+        //for zero base ln(0) is infinite, but the result does not depend on the exponent
+        let exponent_scale = if self.value == 0.0 {
+            0.0
+        } else {
+            value * self.value.ln()
+        };
+
+        self.combine(
+            exponent,
+            value,
+            exponent.value * self.value.powf(exponent.value - 1.0),
+            exponent_scale,
+        )
+    }
+}
+
+impl<const N: usize> Add for Dual<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.combine(rhs, self.value + rhs.value, 1.0, 1.0)
+    }
+}
+
+impl<const N: usize> Sub for Dual<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.combine(rhs, self.value - rhs.value, 1.0, -1.0)
+    }
+}
+
+impl<const N: usize> Mul for Dual<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.combine(rhs, self.value * rhs.value, rhs.value, self.value)
+    }
+}
+
+impl<const N: usize> Div for Dual<N> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.combine(
+            rhs,
+            self.value / rhs.value,
+            1.0 / rhs.value,
+            -self.value / (rhs.value * rhs.value),
+        )
+    }
+}
+
+impl<const N: usize> Neg for Dual<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.chain(-self.value, -1.0)
+    }
+}
+
+impl<const N: usize> Add<Float> for Dual<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Float) -> Self {
+        self.chain(self.value + rhs, 1.0)
+    }
+}
+
+impl<const N: usize> Sub<Float> for Dual<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Float) -> Self {
+        self.chain(self.value - rhs, 1.0)
+    }
+}
+
+impl<const N: usize> Mul<Float> for Dual<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Float) -> Self {
+        self.chain(self.value * rhs, rhs)
+    }
+}
+
+impl<const N: usize> Div<Float> for Dual<N> {
+    type Output = Self;
+
+    fn div(self, rhs: Float) -> Self {
+        self.chain(self.value / rhs, 1.0 / rhs)
+    }
+}
+
+impl<const N: usize> Add<Dual<N>> for Float {
+    type Output = Dual<N>;
+
+    fn add(self, rhs: Dual<N>) -> Dual<N> {
+        rhs + self
+    }
+}
+
+impl<const N: usize> Sub<Dual<N>> for Float {
+    type Output = Dual<N>;
+
+    fn sub(self, rhs: Dual<N>) -> Dual<N> {
+        -rhs + self
+    }
+}
+
+impl<const N: usize> Mul<Dual<N>> for Float {
+    type Output = Dual<N>;
+
+    fn mul(self, rhs: Dual<N>) -> Dual<N> {
+        rhs * self
+    }
+}
+
+impl<const N: usize> Div<Dual<N>> for Float {
+    type Output = Dual<N>;
+
+    fn div(self, rhs: Dual<N>) -> Dual<N> {
+        Dual::constant(self) / rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dual::{Dual, Number},
+        equivalent_potential_temperature, mixing_ratio, relative_humidity,
+        vapour_pressure::{self, PhaseBlending},
+        vapour_pressure_slope, wet_bulb_potential_temperature, wet_bulb_temperature, Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn elementary_functions() {
+        let x = Dual::<2>::variable(2.0, 0);
+        let y = Dual::<2>::variable(3.0, 1);
+
+        let result = x * y + x / y - y * 2.0;
+        assert_approx_eq!(
+            Float,
+            result.value,
+            6.0 + 2.0 / 3.0 - 6.0,
+            epsilon = 0.00001
+        );
+        assert_approx_eq!(
+            Float,
+            result.gradient[0],
+            3.0 + 1.0 / 3.0,
+            epsilon = 0.00001
+        );
+        assert_approx_eq!(
+            Float,
+            result.gradient[1],
+            2.0 - 2.0 / 9.0 - 2.0,
+            epsilon = 0.00001
+        );
+
+        let result = x.pow(y);
+        assert_approx_eq!(Float, result.value, 8.0, epsilon = 0.00001);
+        assert_approx_eq!(Float, result.gradient[0], 12.0, epsilon = 0.00001);
+        assert_approx_eq!(
+            Float,
+            result.gradient[1],
+            8.0 * Float::ln(2.0),
+            epsilon = 0.00001
+        );
+
+        let result = x.exp().ln() + x.sqrt().powi(2) + x.log10() * Float::ln(10.0);
+        assert_approx_eq!(Float, result.value, 4.0 + Float::ln(2.0), epsilon = 0.00001);
+        assert_approx_eq!(Float, result.gradient[0], 2.5, epsilon = 0.00001);
+        assert_approx_eq!(Float, result.gradient[1], 0.0, epsilon = 0.00001);
+
+        let result = 1.0 - 4.0 / x.powf(2.0);
+        assert_approx_eq!(Float, result.value, 0.0, epsilon = 0.00001);
+        assert_approx_eq!(Float, result.gradient[0], 1.0, epsilon = 0.00001);
+
+        let result = (x - 2.0).tanh();
+        assert_approx_eq!(Float, result.value, 0.0, epsilon = 0.00001);
+        assert_approx_eq!(Float, result.gradient[0], 1.0, epsilon = 0.00001);
+
+        let result = (x - 1.0).atan();
+        assert_approx_eq!(
+            Float,
+            result.value,
+            std::f64::consts::FRAC_PI_4 as Float,
+            epsilon = 0.00001
+        );
+        assert_approx_eq!(Float, result.gradient[0], 0.5, epsilon = 0.00001);
+    }
+
+    #[test]
+    fn pow_zero_base() {
+        let x = Dual::<2>::variable(0.0, 0);
+        let y = Dual::<2>::variable(2.0, 1);
+
+        let result = x.pow(y);
+        assert_approx_eq!(Float, result.value, 0.0, epsilon = 0.00001);
+        assert_approx_eq!(Float, result.gradient[0], 0.0, epsilon = 0.00001);
+        assert_approx_eq!(Float, result.gradient[1], 0.0, epsilon = 0.00001);
+
+        let result = x.pow(Dual::constant(2.0));
+        assert!(result.gradient.iter().all(|g| g.is_finite()));
+    }
+
+    #[test]
+    fn saturation_vapour_pressure_slopes() {
+        //derivatives of generic formulas match analytic slopes
+        for (formula, slope, temperature) in [
+            (
+                vapour_pressure::wexler1 as fn(_) -> _,
+                vapour_pressure_slope::wexler1 as fn(_) -> _,
+                300.0,
+            ),
+            (
+                vapour_pressure::wexler2,
+                vapour_pressure_slope::wexler2,
+                250.0,
+            ),
+            (
+                vapour_pressure::goff_gratch1,
+                vapour_pressure_slope::goff_gratch1,
+                300.0,
+            ),
+            (
+                vapour_pressure::goff_gratch2,
+                vapour_pressure_slope::goff_gratch2,
+                250.0,
+            ),
+            (
+                vapour_pressure::hyland_wexler1,
+                vapour_pressure_slope::hyland_wexler1,
+                300.0,
+            ),
+            (
+                vapour_pressure::hyland_wexler2,
+                vapour_pressure_slope::hyland_wexler2,
+                250.0,
+            ),
+            (
+                vapour_pressure::sonntag1,
+                vapour_pressure_slope::sonntag1,
+                300.0,
+            ),
+            (
+                vapour_pressure::sonntag2,
+                vapour_pressure_slope::sonntag2,
+                250.0,
+            ),
+            (
+                vapour_pressure::murphy_koop1,
+                vapour_pressure_slope::murphy_koop1,
+                240.0,
+            ),
+            (
+                vapour_pressure::murphy_koop2,
+                vapour_pressure_slope::murphy_koop2,
+                250.0,
+            ),
+            (
+                vapour_pressure::huang1,
+                vapour_pressure_slope::huang1,
+                300.0,
+            ),
+            (
+                vapour_pressure::huang2,
+                vapour_pressure_slope::huang2,
+                250.0,
+            ),
+            (
+                vapour_pressure::iapws1,
+                vapour_pressure_slope::iapws1,
+                300.0,
+            ),
+            (
+                vapour_pressure::iapws2,
+                vapour_pressure_slope::iapws2,
+                250.0,
+            ),
+        ] {
+            let result: Dual<1> = formula(Dual::variable(temperature, 0)).unwrap();
+            let expected = slope(temperature).unwrap();
+            assert_approx_eq!(Float, result.gradient[0] / expected, 1.0, epsilon = 0.0001);
+        }
+
+        let temperature = Dual::<1>::variable(260.0, 0);
+        let result = vapour_pressure::mixed_phase1(temperature, PhaseBlending::Ifs).unwrap();
+        let expected = vapour_pressure_slope::mixed_phase1(260.0, PhaseBlending::Ifs).unwrap();
+        assert_approx_eq!(Float, result.gradient[0] / expected, 1.0, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn bolton1_jacobian() {
+        let inputs: [Float; 3] = [101325.0, 300.0, 280.0];
+        let steps: [Float; 3] = [10.0, 0.05, 0.05];
+
+        let dual_inputs: [Dual<3>; 3] = [0, 1, 2].map(|i| Dual::variable(inputs[i], i));
+        let result = equivalent_potential_temperature::bolton1(
+            dual_inputs[0],
+            dual_inputs[1],
+            dual_inputs[2],
+        )
+        .unwrap();
+
+        let expected =
+            equivalent_potential_temperature::bolton1(inputs[0], inputs[1], inputs[2]).unwrap();
+        assert_approx_eq!(Float, result.value, expected, epsilon = 0.0001);
+
+        for i in 0..3 {
+            let mut upper = inputs;
+            let mut lower = inputs;
+            upper[i] += steps[i];
+            lower[i] -= steps[i];
+
+            let expected =
+                (equivalent_potential_temperature::bolton1(upper[0], upper[1], upper[2]).unwrap()
+                    - equivalent_potential_temperature::bolton1(lower[0], lower[1], lower[2])
+                        .unwrap())
+                    / (2.0 * steps[i]);
+
+            assert_approx_eq!(Float, result.gradient[i] / expected, 1.0, epsilon = 0.01);
+        }
+    }
+
+    #[test]
+    fn moisture_jacobians() {
+        let dewpoint = Dual::<2>::variable(290.0, 0);
+        let pressure = Dual::<2>::variable(101325.0, 1);
+
+        let result = mixing_ratio::accuracy1(dewpoint, pressure).unwrap();
+        let expected = (mixing_ratio::accuracy1(290.05, 101325.0).unwrap()
+            - mixing_ratio::accuracy1(289.95, 101325.0).unwrap())
+            / 0.1;
+        assert_approx_eq!(Float, result.gradient[0] / expected, 1.0, epsilon = 0.01);
+
+        let temperature = Dual::<2>::variable(300.0, 0);
+        let dewpoint = Dual::<2>::variable(290.0, 1);
+
+        let result = relative_humidity::general3(temperature, dewpoint).unwrap();
+        let expected = (relative_humidity::general3(300.05, 290.0).unwrap()
+            - relative_humidity::general3(299.95, 290.0).unwrap())
+            / 0.1;
+        assert_approx_eq!(Float, result.gradient[0] / expected, 1.0, epsilon = 0.01);
+
+        //relative humidity decreases with temperature and increases with dewpoint
+        assert!(result.gradient[0] < 0.0);
+        assert!(result.gradient[1] > 0.0);
+    }
+
+    #[test]
+    fn wet_bulb_jacobians() {
+        let temperature = Dual::<2>::variable(300.0, 0);
+        let relative_humidity = Dual::<2>::variable(0.5, 1);
+
+        let result = wet_bulb_temperature::stull1(temperature, relative_humidity).unwrap();
+        let expected = (wet_bulb_temperature::stull1(300.05, 0.5).unwrap()
+            - wet_bulb_temperature::stull1(299.95, 0.5).unwrap())
+            / 0.1;
+        assert_approx_eq!(Float, result.gradient[0] / expected, 1.0, epsilon = 0.01);
+
+        let expected = (wet_bulb_temperature::stull1(300.0, 0.501).unwrap()
+            - wet_bulb_temperature::stull1(300.0, 0.499).unwrap())
+            / 0.002;
+        assert_approx_eq!(Float, result.gradient[1] / expected, 1.0, epsilon = 0.01);
+
+        //wet bulb potential temperature chained with equivalent potential temperature
+        let pressure = Dual::<3>::variable(101325.0, 0);
+        let temperature = Dual::<3>::variable(300.0, 1);
+        let dewpoint = Dual::<3>::variable(280.0, 2);
+
+        let theta_e =
+            equivalent_potential_temperature::bolton1(pressure, temperature, dewpoint).unwrap();
+        let result = wet_bulb_potential_temperature::davies_jones1(theta_e).unwrap();
+
+        let theta_w = |temperature: Float| {
+            let theta_e =
+                equivalent_potential_temperature::bolton1(101325.0, temperature, 280.0).unwrap();
+            wet_bulb_potential_temperature::davies_jones1(theta_e).unwrap()
+        };
+        let expected = (theta_w(300.05) - theta_w(299.95)) / 0.1;
+        assert_approx_eq!(Float, result.gradient[1] / expected, 1.0, epsilon = 0.01);
+    }
+}
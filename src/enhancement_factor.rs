@@ -0,0 +1,445 @@
+//!Functions to calculate enhancement factor of water vapour in moist air (dimensionless).
+//!
+//!Saturation vapour pressure in moist air is slightly higher than over pure water or ice,
+//!because of the presence of other gases and the pressure they exert.
+//!The enhancement factor is the ratio of those two pressures, so saturation vapour pressure in moist air at given
+//!total pressure can be computed by multiplying result of any pure-phase formula from [`vapour_pressure`]
+//!module (eg. [`vapour_pressure::wexler1`] or [`vapour_pressure::tetens1`]) by the enhancement factor.
+//!
+//!Over water (`1` functions) the factor should be used with formulas for water,
+//!over ice (`2` functions) with formulas for ice.
+
+use crate::Float;
+use crate::{constants::ZERO_CELSIUS, errors::InputError, vapour_pressure};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+//coefficients of Greenspan (1976) formula for the ITS-90 temperature scale by Hardy (1998),
+//for water at and above 0C, supercooled water and ice
+const WATER_A: [Float; 4] = [3.536_24e-4, 2.932_28e-5, 2.614_74e-7, 8.575_38e-9];
+const WATER_B: [Float; 4] = [-1.075_88e1, 6.325_29e-2, -2.535_91e-4, 6.337_84e-7];
+const SUPERCOOLED_A: [Float; 4] = [3.621_83e-4, 2.605_53e-5, 3.865_01e-7, 3.824_49e-9];
+const SUPERCOOLED_B: [Float; 4] = [-1.076_04e1, 6.397_25e-2, -2.634_16e-4, 1.672_54e-6];
+const ICE_A: [Float; 4] = [3.644_49e-4, 2.936_31e-5, 4.886_35e-7, 4.365_43e-9];
+const ICE_B: [Float; 4] = [-1.072_71e1, 7.619_89e-2, -1.747_71e-4, 2.467_21e-6];
+
+///Formula for computing enhancement factor over water from temperature and pressure.
+///Used in [`vapour_pressure::buck1`], should be used when accuracy is desired.
+///
+///Derived by A. L. Buck (1981) [(doi: 10.1175/1520-0450(1981)020<1527:nefcvp>2.0.co;2)](https://doi.org/10.1175/1520-0450(1981)020%3C1527:NEFCVP%3E2.0.CO;2).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck1(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(232.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    validate_pressure(pressure)?;
+
+    Ok(buck(temperature, pressure, 0.000_72, 0.000_003_2, 5.9e-10))
+}
+
+///Formula for computing enhancement factor over ice from temperature and pressure.
+///Used in [`vapour_pressure::buck2`], should be used when accuracy is desired.
+///
+///Derived by A. L. Buck (1981) [(doi: 10.1175/1520-0450(1981)020<1527:nefcvp>2.0.co;2)](https://doi.org/10.1175/1520-0450(1981)020%3C1527:NEFCVP%3E2.0.CO;2).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 193K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck2(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(193.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    validate_pressure(pressure)?;
+
+    Ok(buck(temperature, pressure, 0.000_22, 0.000_003_83, 6.4e-10))
+}
+
+///Formula for computing enhancement factor over water from pressure.
+///Used in [`vapour_pressure::buck3`], should be used for general use.
+///
+///Derived by A. L. Buck (1981) [(doi: 10.1175/1520-0450(1981)020<1527:nefcvp>2.0.co;2)](https://doi.org/10.1175/1520-0450(1981)020%3C1527:NEFCVP%3E2.0.CO;2).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck3(pressure: Float) -> Result<Float, InputError> {
+    validate_pressure(pressure)?;
+
+    Ok(buck(ZERO_CELSIUS, pressure, 0.000_7, 0.000_003_46, 0.0))
+}
+
+///Formula for computing enhancement factor over ice from pressure.
+///Used in [`vapour_pressure::buck4`], should be used for general use.
+///
+///Derived by A. L. Buck (1981) [(doi: 10.1175/1520-0450(1981)020<1527:nefcvp>2.0.co;2)](https://doi.org/10.1175/1520-0450(1981)020%3C1527:NEFCVP%3E2.0.CO;2).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck4(pressure: Float) -> Result<Float, InputError> {
+    validate_pressure(pressure)?;
+
+    Ok(buck(ZERO_CELSIUS, pressure, 0.000_3, 0.000_004_18, 0.0))
+}
+
+///Formula for computing enhancement factor over water (including supercooled water) from temperature and pressure.
+///Should be used when the highest accuracy is required.
+///
+///Functional form derived by A. Greenspan (1976) [(doi:10.6028/jres.080A.007)](https://doi.org/10.6028/jres.080A.007),
+///with coefficients for the ITS-90 temperature scale by B. Hardy (1998). ITS-90 formulations for vapor pressure,
+///frostpoint temperature, dewpoint temperature, and enhancement factors in the range -100 to +100 C.
+///Proceedings of the Third International Symposium on Humidity & Moisture, 1-8.
+///Saturation vapour pressure in the formula is computed with [`vapour_pressure::sonntag1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 223K - 373K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when saturation vapour pressure is not lower than `pressure`.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn hardy1(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(223.0..=373.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    validate_pressure(pressure)?;
+
+    let saturation_vapour_pressure = vapour_pressure::sonntag1(temperature)?;
+
+    let (a, b) = if temperature >= ZERO_CELSIUS {
+        (WATER_A, WATER_B)
+    } else {
+        (SUPERCOOLED_A, SUPERCOOLED_B)
+    };
+
+    greenspan(temperature, pressure, saturation_vapour_pressure, a, b)
+}
+
+///Formula for computing enhancement factor over ice from temperature and pressure.
+///Should be used when the highest accuracy is required.
+///
+///Functional form derived by A. Greenspan (1976) [(doi:10.6028/jres.080A.007)](https://doi.org/10.6028/jres.080A.007),
+///with coefficients for the ITS-90 temperature scale by B. Hardy (1998). ITS-90 formulations for vapor pressure,
+///frostpoint temperature, dewpoint temperature, and enhancement factors in the range -100 to +100 C.
+///Proceedings of the Third International Symposium on Humidity & Moisture, 1-8.
+///Saturation vapour pressure in the formula is computed with [`vapour_pressure::sonntag2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 173K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when saturation vapour pressure is not lower than `pressure`.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn hardy2(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(173.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    validate_pressure(pressure)?;
+
+    let saturation_vapour_pressure = vapour_pressure::sonntag2(temperature)?;
+
+    greenspan(
+        temperature,
+        pressure,
+        saturation_vapour_pressure,
+        ICE_A,
+        ICE_B,
+    )
+}
+
+///Formula for computing enhancement factor over water from temperature and pressure.
+///Uses the same coefficients as [`hardy1`] (above 0C), the only difference is that saturation
+///vapour pressure in the formula is computed with [`vapour_pressure::wexler1`], as in the original paper.
+///
+///Derived by A. Greenspan (1976) [(doi:10.6028/jres.080A.007)](https://doi.org/10.6028/jres.080A.007).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 273K - 373K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when saturation vapour pressure is not lower than `pressure`.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn greenspan1(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(273.0..=373.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    validate_pressure(pressure)?;
+
+    let saturation_vapour_pressure = vapour_pressure::wexler1(temperature)?;
+
+    greenspan(
+        temperature,
+        pressure,
+        saturation_vapour_pressure,
+        WATER_A,
+        WATER_B,
+    )
+}
+
+///Formula for computing enhancement factor over ice from temperature and pressure.
+///Uses the same coefficients as [`hardy2`], the only difference is that saturation
+///vapour pressure in the formula is computed with [`vapour_pressure::wexler2`], as in the original paper.
+///
+///Derived by A. Greenspan (1976) [(doi:10.6028/jres.080A.007)](https://doi.org/10.6028/jres.080A.007).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 173K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when saturation vapour pressure is not lower than `pressure`.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn greenspan2(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(173.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    validate_pressure(pressure)?;
+
+    let saturation_vapour_pressure = vapour_pressure::wexler2(temperature)?;
+
+    greenspan(
+        temperature,
+        pressure,
+        saturation_vapour_pressure,
+        ICE_A,
+        ICE_B,
+    )
+}
+
+fn validate_pressure(pressure: Float) -> Result<(), InputError> {
+    if !(100.0..=150_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    Ok(())
+}
+
+//formula of Buck (1981) with pressure in hPa and temperature in C
+fn buck(temperature: Float, pressure: Float, a: Float, b: Float, c: Float) -> Float {
+    let temperature = temperature - ZERO_CELSIUS;
+    let pressure = pressure / 100.0;
+
+    1.0 + a + pressure * (b + c * temperature * temperature)
+}
+
+//formula of Greenspan (1976) with temperature in C
+fn greenspan(
+    temperature: Float,
+    pressure: Float,
+    saturation_vapour_pressure: Float,
+    a: [Float; 4],
+    b: [Float; 4],
+) -> Result<Float, InputError> {
+    if saturation_vapour_pressure >= pressure {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "saturation vapour pressure must be lower than pressure",
+        )));
+    }
+
+    let temperature = temperature - ZERO_CELSIUS;
+
+    let alpha = a[0] + temperature * (a[1] + temperature * (a[2] + temperature * a[3]));
+    let beta = (b[0] + temperature * (b[1] + temperature * (b[2] + temperature * b[3]))).exp();
+
+    let result = (alpha * (1.0 - saturation_vapour_pressure / pressure)
+        + beta * (pressure / saturation_vapour_pressure - 1.0))
+        .exp();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        enhancement_factor,
+        errors::InputError,
+        tests_framework::{self, Argument},
+        vapour_pressure, Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn buck1() {
+        assert!(tests_framework::test_with_2args(
+            &enhancement_factor::buck1,
+            Argument {
+                name: "temperature",
+                def_val: 293.15,
+                range: [232.0, 324.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 100_000.0,
+                range: [100.0, 150_000.0]
+            },
+            1.004156
+        ));
+    }
+
+    #[test]
+    fn buck2() {
+        assert!(tests_framework::test_with_2args(
+            &enhancement_factor::buck2,
+            Argument {
+                name: "temperature",
+                def_val: 253.15,
+                range: [193.0, 274.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 100_000.0,
+                range: [100.0, 150_000.0]
+            },
+            1.004306
+        ));
+    }
+
+    #[test]
+    fn buck3() {
+        assert!(tests_framework::test_with_1arg(
+            &enhancement_factor::buck3,
+            Argument {
+                name: "pressure",
+                def_val: 100_000.0,
+                range: [100.0, 150_000.0]
+            },
+            1.00416
+        ));
+
+        //enhancement factor is included in vapour_pressure::buck3
+        let result = vapour_pressure::buck3_simplified(290.0).unwrap()
+            * enhancement_factor::buck3(90000.0).unwrap();
+        let expected = vapour_pressure::buck3(290.0, 90000.0).unwrap();
+        assert_approx_eq!(Float, result, expected, epsilon = 0.01);
+    }
+
+    #[test]
+    fn buck4() {
+        assert!(tests_framework::test_with_1arg(
+            &enhancement_factor::buck4,
+            Argument {
+                name: "pressure",
+                def_val: 100_000.0,
+                range: [100.0, 150_000.0]
+            },
+            1.00448
+        ));
+
+        //enhancement factor is included in vapour_pressure::buck4
+        let result = vapour_pressure::buck4_simplified(250.0).unwrap()
+            * enhancement_factor::buck4(90000.0).unwrap();
+        let expected = vapour_pressure::buck4(250.0, 90000.0).unwrap();
+        assert_approx_eq!(Float, result, expected, epsilon = 0.01);
+    }
+
+    #[test]
+    fn hardy1() {
+        let result = enhancement_factor::hardy1(293.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, 1.003_951, epsilon = 0.000_01);
+
+        let result = enhancement_factor::hardy1(263.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, 1.003_932, epsilon = 0.000_01);
+
+        //coefficients for water and supercooled water agree at 0C
+        let water = enhancement_factor::hardy1(273.15, 100_000.0).unwrap();
+        let supercooled = enhancement_factor::hardy1(273.149, 100_000.0).unwrap();
+        assert_approx_eq!(Float, water, supercooled, epsilon = 0.000_01);
+
+        //Buck formula agrees with Hardy formula
+        let hardy = enhancement_factor::hardy1(293.15, 100_000.0).unwrap();
+        let buck = enhancement_factor::buck1(293.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, hardy, buck, epsilon = 0.001);
+
+        let hardy = enhancement_factor::hardy1(263.15, 100_000.0).unwrap();
+        let buck = enhancement_factor::buck1(263.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, hardy, buck, epsilon = 0.001);
+
+        assert!(matches!(
+            enhancement_factor::hardy1(353.15, 40000.0),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert_eq!(
+            enhancement_factor::hardy1(200.0, 100_000.0).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+    }
+
+    #[test]
+    fn hardy2() {
+        let result = enhancement_factor::hardy2(253.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, 1.004_175, epsilon = 0.000_01);
+
+        //Buck formula agrees with Hardy formula
+        let buck = enhancement_factor::buck2(253.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, buck, epsilon = 0.001);
+
+        assert_eq!(
+            enhancement_factor::hardy2(280.0, 100_000.0).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+    }
+
+    #[test]
+    fn greenspan1() {
+        let result = enhancement_factor::greenspan1(293.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, 1.003_952, epsilon = 0.000_01);
+
+        //Buck formula agrees with Greenspan formula
+        let buck = enhancement_factor::buck1(293.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, buck, epsilon = 0.001);
+
+        assert!(matches!(
+            enhancement_factor::greenspan1(353.15, 40000.0),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert_eq!(
+            enhancement_factor::greenspan1(263.15, 100_000.0).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+        assert_eq!(
+            enhancement_factor::greenspan1(293.15, 50.0).unwrap_err(),
+            InputError::OutOfRange(String::from("pressure"))
+        );
+    }
+
+    #[test]
+    fn greenspan2() {
+        let result = enhancement_factor::greenspan2(253.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, 1.004_174, epsilon = 0.000_01);
+
+        //Buck formula agrees with Greenspan formula
+        let buck = enhancement_factor::buck2(253.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, buck, epsilon = 0.001);
+
+        assert_eq!(
+            enhancement_factor::greenspan2(280.0, 100_000.0).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+    }
+}
@@ -2,10 +2,12 @@
 use crate::constants::{C_L, R_V};
 use crate::Float;
 use crate::{
-    constants::{C_P, EPSILON, L_V, R_D},
+    constants::{C_P, EPSILON, KAPPA, L_V, R_D},
+    dual::Number,
     errors::InputError,
-    mixing_ratio, potential_temperature, relative_humidity, vapour_pressure,
+    latent_heat, mixing_ratio, potential_temperature, relative_humidity, vapour_pressure,
 };
+use std::ops::{Add, Div, Mul, Sub};
 
 #[cfg(feature = "debug")]
 use floccus_proc::logerr;
@@ -14,6 +16,7 @@ use floccus_proc::logerr;
 ///temperature, pressure and vapour pressure.
 ///
 ///Implementation of this formula assumes no liquid or solid water in the air parcel.
+///Latent heat of vaporisation is computed with [`latent_heat::vaporisation1`].
 ///
 ///Provided in Emmanuel, Kerry (1994). Atmospheric Convection. Oxford University Press.
 ///
@@ -24,20 +27,19 @@ use floccus_proc::logerr;
 ///Valid `pressure` range: 100Pa - 150000Pa\
 ///Valid `vapour_pressure` range: 0Pa - 10000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general1(
-    temperature: Float,
-    pressure: Float,
-    vapour_pressure: Float,
-) -> Result<Float, InputError> {
-    if !(253.0..=324.0).contains(&temperature) {
+pub fn general1<N: Number>(temperature: N, pressure: N, vapour_pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(253.0..=324.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(20000.0..=150_000.0).contains(&pressure) {
+    if !(20000.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
-    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+    if !(0.0..=10_000.0).contains(&vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from("vapour_pressure")));
     }
 
@@ -49,10 +51,13 @@ pub fn general1(
     let relative_humidity =
         relative_humidity::general2(vapour_pressure, saturation_vapour_pressure)?;
 
+    let heat_capacity = C_P + mixing_ratio * C_L;
+    let latent_heat = latent_heat::vaporisation1(temperature)?;
+
     let result = temperature
-        * (p0 / pressure).powf(R_D / (C_P + mixing_ratio * C_L))
-        * relative_humidity.powf((-mixing_ratio * R_V) / (C_P + mixing_ratio * C_L))
-        * ((L_V * mixing_ratio) / (temperature * (C_P + mixing_ratio * C_L))).exp();
+        * (p0 / pressure).pow(R_D / heat_capacity)
+        * relative_humidity.pow((-mixing_ratio * R_V) / heat_capacity)
+        * ((latent_heat * mixing_ratio) / (temperature * heat_capacity)).exp();
 
     Ok(result)
 }
@@ -69,25 +74,22 @@ pub fn general1(
 ///Valid `pressure` range: 100Pa - 150000Pa\
 ///Valid `vapour_pressure` range: 0Pa - 10000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn bryan1(
-    temperature: Float,
-    pressure: Float,
-    vapour_pressure: Float,
-) -> Result<Float, InputError> {
-    if !(253.0..=324.0).contains(&temperature) {
+pub fn bryan1<N: Number>(temperature: N, pressure: N, vapour_pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(253.0..=324.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(20000.0..=150_000.0).contains(&pressure) {
+    if !(20000.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
-    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+    if !(0.0..=10_000.0).contains(&vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from("vapour_pressure")));
     }
 
-    let kappa = R_D / C_P;
-
     let potential_temperature =
         potential_temperature::davies_jones1(temperature, pressure, vapour_pressure)?;
 
@@ -98,7 +100,7 @@ pub fn bryan1(
     let mixing_ratio = mixing_ratio::general1(pressure, vapour_pressure)?;
 
     let result = potential_temperature
-        * relative_humidity.powf((-kappa) * (mixing_ratio / EPSILON))
+        * relative_humidity.pow((-KAPPA) * (mixing_ratio / EPSILON))
         * ((L_V * mixing_ratio) / (C_P * temperature)).exp();
 
     Ok(result)
@@ -117,21 +119,22 @@ pub fn bryan1(
 ///Valid `temperature` range: 253K - 324K\
 ///Valid `dewpoint` range: 253K - 324K
 #[cfg_attr(feature = "debug", logerr)]
-pub fn bolton1(pressure: Float, temperature: Float, dewpoint: Float) -> Result<Float, InputError> {
-    if !(20000.0..=150_000.0).contains(&pressure) {
+pub fn bolton1<N: Number>(pressure: N, temperature: N, dewpoint: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(20000.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
-    if !(253.0..=324.0).contains(&temperature) {
+    if !(253.0..=324.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(253.0..=324.0).contains(&dewpoint) {
+    if !(253.0..=324.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
-    let kappa = R_D / C_P;
-
     let vapour_pressure = vapour_pressure::buck3(dewpoint, pressure)?;
     let mixing_ratio = mixing_ratio::general1(pressure, vapour_pressure)?;
 
@@ -139,8 +142,8 @@ pub fn bolton1(pressure: Float, temperature: Float, dewpoint: Float) -> Result<F
         (1.0 / ((1.0 / (dewpoint - 56.0)) + ((temperature / dewpoint).ln() / 800.0))) + 56.0;
 
     let theta_dl = temperature
-        * (100000.0 / (pressure - vapour_pressure)).powf(kappa)
-        * (temperature / lcl_temp).powf(0.28 * mixing_ratio);
+        * (100000.0 / (pressure - vapour_pressure)).powf(KAPPA)
+        * (temperature / lcl_temp).pow(0.28 * mixing_ratio);
 
     let result = theta_dl
         * (((3036.0 / lcl_temp) - 1.78) * mixing_ratio * (1.0 + 0.448 * mixing_ratio)).exp();
@@ -149,6 +152,7 @@ pub fn bolton1(pressure: Float, temperature: Float, dewpoint: Float) -> Result<F
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
         equivalent_potential_temperature,
@@ -174,7 +178,7 @@ mod tests {
                 def_val: 991.189131,
                 range: [0.0, 10_000.0]
             },
-            315.23724970376776
+            314.83853097179093
         ));
     }
 
@@ -0,0 +1,487 @@
+//!Functions to calculate heat index in K.
+//!
+//!Heat index (or apparent temperature in hot conditions) is the temperature perceived by humans
+//!when relative humidity is combined with the air temperature [Wikipedia](https://en.wikipedia.org/wiki/Heat_index).
+//!It is based on the thermoregulatory model of the human body by R. G. Steadman (1979)
+//![(doi:10.1175/1520-0450(1979)018<0861:TAOSPI>2.0.CO;2)](https://doi.org/10.1175/1520-0450(1979)018%3C0861:TAOSPI%3E2.0.CO;2).
+
+use crate::Float;
+use crate::{constants::ZERO_CELSIUS, errors::InputError};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing heat index from temperature and relative humidity
+///with the regression used by the U.S. National Weather Service.
+///
+///The regression is applied with adjustments for low (below 13%) and high (above 85%)
+///relative humidity. It is intended for heat index of 80°F (300K) and above,
+///for lower values see [`steadman1`].
+///
+///Derived by L. P. Rothfusz (1990). The heat index equation. NWS Southern Region Technical Attachment, SR 90-23.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 300K - 322K\
+///Valid `relative_humidity` range: 0.0 - 1.0
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn rothfusz1(temperature: Float, relative_humidity: Float) -> Result<Float, InputError> {
+    if !(300.0..=322.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(0.0..=1.0).contains(&relative_humidity) {
+        return Err(InputError::OutOfRange(String::from("relative_humidity")));
+    }
+
+    //convert units
+    let temperature = kelvin_to_fahrenheit(temperature);
+    let relative_humidity = relative_humidity * 100.0;
+
+    let mut result = -42.379 + 2.049_015_23 * temperature + 10.143_331_27 * relative_humidity
+        - 0.224_755_41 * temperature * relative_humidity
+        - 0.006_837_83 * temperature.powi(2)
+        - 0.054_817_17 * relative_humidity.powi(2)
+        + 0.001_228_74 * temperature.powi(2) * relative_humidity
+        + 0.000_852_82 * temperature * relative_humidity.powi(2)
+        - 0.000_001_99 * temperature.powi(2) * relative_humidity.powi(2);
+
+    if relative_humidity < 13.0 && (80.0..=112.0).contains(&temperature) {
+        result -= ((13.0 - relative_humidity) / 4.0)
+            * ((17.0 - (temperature - 95.0).abs()) / 17.0).sqrt();
+    } else if relative_humidity > 85.0 && (80.0..=87.0).contains(&temperature) {
+        result += ((relative_humidity - 85.0) / 10.0) * ((87.0 - temperature) / 5.0);
+    }
+
+    Ok(fahrenheit_to_kelvin(result))
+}
+
+///Formula for computing heat index from temperature and relative humidity
+///with the simple formula used by the U.S. National Weather Service for heat index below 80°F (300K).
+///
+///The formula is consistent with the original table of R. G. Steadman (1979).
+///
+///Provided in NWS Weather Prediction Center, The Heat Index Equation.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 273K - 322K\
+///Valid `relative_humidity` range: 0.0 - 1.0
+#[cfg_attr(feature = "debug", logerr)]
+pub fn steadman1(temperature: Float, relative_humidity: Float) -> Result<Float, InputError> {
+    if !(273.0..=322.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(0.0..=1.0).contains(&relative_humidity) {
+        return Err(InputError::OutOfRange(String::from("relative_humidity")));
+    }
+
+    //convert units
+    let temperature = kelvin_to_fahrenheit(temperature);
+    let relative_humidity = relative_humidity * 100.0;
+
+    let result =
+        0.5 * (temperature + 61.0 + ((temperature - 68.0) * 1.2) + (relative_humidity * 0.094));
+
+    Ok(fahrenheit_to_kelvin(result))
+}
+
+///Formula for computing extended heat index from temperature and relative humidity.
+///
+///The extended heat index solves the thermoregulatory model of Steadman (1979) extended
+///to all combinations of temperature and humidity, so it agrees with Steadman's table
+///within its range and remains physical outside of it. The model uses its own thermodynamic
+///and physiological constants, which are retained here.
+///Roots of the model equations are found with bisection.
+///
+///Derived by Y.-C. Lu & D. M. Romps (2022) [(doi:10.1175/JAMC-D-22-0021.1)](https://doi.org/10.1175/JAMC-D-22-0021.1)
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 223K - 333K\
+///Valid `relative_humidity` range: 0.0 - 1.0
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the model equations cannot be solved.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn lu_romps1(temperature: Float, relative_humidity: Float) -> Result<Float, InputError> {
+    if !(223.0..=333.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(0.0..=1.0).contains(&relative_humidity) {
+        return Err(InputError::OutOfRange(String::from("relative_humidity")));
+    }
+
+    let state = lu_romps::equivalent_state(temperature, relative_humidity)?;
+    lu_romps::heat_index(state)
+}
+
+fn kelvin_to_fahrenheit(temperature: Float) -> Float {
+    (temperature - ZERO_CELSIUS) * 1.8 + 32.0
+}
+
+fn fahrenheit_to_kelvin(temperature: Float) -> Float {
+    (temperature - 32.0) / 1.8 + ZERO_CELSIUS
+}
+
+//thermoregulatory model of Lu & Romps (2022), names follow the paper
+mod lu_romps {
+    use crate::errors::InputError;
+    use crate::Float;
+
+    //thermodynamic parameters
+    const T_TRIP: Float = 273.16;
+    const P_TRIP: Float = 611.65;
+    const E0_V: Float = 2.3740e6;
+    const E0_S: Float = 0.3337e6;
+    const R_A: Float = 287.04;
+    const R_V: Float = 461.0;
+    const C_VA: Float = 719.0;
+    const C_VV: Float = 1418.0;
+    const C_VL: Float = 4119.0;
+    const C_VS: Float = 1861.0;
+    const C_PA: Float = C_VA + R_A;
+    const C_PV: Float = C_VV + R_V;
+
+    //thermoregulatory parameters
+    const SIGMA: Float = 5.67e-8;
+    const EMISSIVITY: Float = 0.97;
+    const MASS: Float = 83.6;
+    const HEIGHT: Float = 1.69;
+    const C_PC: Float = 3492.0;
+    const R_ZF: Float = 124.0;
+    const Q: Float = 180.0;
+    const PHI_SALT: Float = 0.9;
+    const T_C: Float = 310.0;
+    const P: Float = 1.013e5;
+    const ETA: Float = 1.43e-6;
+    const P_A0: Float = 1.6e3;
+    const R_S0: Float = 0.0387;
+    const PHI0: Float = 0.84;
+
+    //mass transfer resistances through air of exposed skin, clothed skin and naked body
+    const Z_A: Float = 60.6 / 17.4;
+    const Z_A_BAR: Float = 60.6 / 11.6;
+    const Z_A_UN: Float = 60.6 / 12.3;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub(super) enum Region {
+        //covering fraction is reduced
+        Phi,
+        //clothing resistance is reduced
+        Rf,
+        //skin resistance is reduced, skin is not or is fully wetted
+        Rs,
+        RsStar,
+        //core temperature rises
+        DTcDt,
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    pub(super) struct EquivalentState {
+        region: Region,
+        phi: Float,
+        rf: Float,
+        rs: Float,
+        dtc_dt: Float,
+    }
+
+    fn saturation_vapour_pressure(t: Float) -> Float {
+        if t < T_TRIP {
+            P_TRIP
+                * (t / T_TRIP).powf((C_PV - C_VS) / R_V)
+                * ((E0_V + E0_S - (C_VV - C_VS) * T_TRIP) / R_V * (1.0 / T_TRIP - 1.0 / t)).exp()
+        } else {
+            P_TRIP
+                * (t / T_TRIP).powf((C_PV - C_VL) / R_V)
+                * ((E0_V - (C_VV - C_VL) * T_TRIP) / R_V * (1.0 / T_TRIP - 1.0 / t)).exp()
+        }
+    }
+
+    fn latent_heat(t: Float) -> Float {
+        E0_V + (C_VV - C_VL) * (t - T_TRIP) + (C_PV - C_VV) * t
+    }
+
+    fn core_vapour_pressure() -> Float {
+        PHI_SALT * saturation_vapour_pressure(T_C)
+    }
+
+    //heat capacity of the core per unit skin area
+    fn core_heat_capacity() -> Float {
+        let area = 0.202 * MASS.powf(0.425) * HEIGHT.powf(0.725);
+        MASS * C_PC / area
+    }
+
+    //respiratory heat loss
+    fn q_v(t_a: Float, p_a: Float) -> Float {
+        ETA * Q
+            * (C_PA * (T_C - t_a)
+                + latent_heat(T_C) * R_A / (P * R_V) * (core_vapour_pressure() - p_a))
+    }
+
+    //mass transfer resistance through the skin
+    fn z_s(r_s: Float) -> Float {
+        if r_s == R_S0 {
+            52.1
+        } else {
+            6.0e8 * r_s.powi(5)
+        }
+    }
+
+    //heat transfer resistance through the air
+    fn r_a(t_s: Float, t_a: Float, h_c: Float, phi_rad: Float) -> Float {
+        let h_r = EMISSIVITY * phi_rad * SIGMA * (t_s.powi(2) + t_a.powi(2)) * (t_s + t_a);
+        1.0 / (h_c + h_r)
+    }
+
+    fn r_a_exposed(t_s: Float, t_a: Float) -> Float {
+        r_a(t_s, t_a, 17.4, 0.85)
+    }
+
+    fn r_a_bar(t_f: Float, t_a: Float) -> Float {
+        r_a(t_f, t_a, 11.6, 0.79)
+    }
+
+    fn r_a_un(t_s: Float, t_a: Float) -> Float {
+        r_a(t_s, t_a, 12.3, 0.80)
+    }
+
+    pub(super) fn equivalent_state(t_a: Float, rh: Float) -> Result<EquivalentState, InputError> {
+        let p_c = core_vapour_pressure();
+        let p_a = rh * saturation_vapour_pressure(t_a);
+        let r_s = R_S0;
+        let phi = PHI0;
+
+        let m = (p_c - p_a) / (z_s(r_s) + Z_A);
+        let m_bar = (p_c - p_a) / (z_s(r_s) + Z_A_BAR);
+
+        let t_s = solve(
+            |t_s| Ok((t_s - t_a) / r_a_exposed(t_s, t_a) + m - (T_C - t_s) / r_s),
+            (T_C.min(t_a) - r_s * m.abs()).max(0.0),
+            T_C.max(t_a) + r_s * m.abs(),
+        )?;
+        let t_f = solve(
+            |t_f| Ok((t_f - t_a) / r_a_bar(t_f, t_a) + m_bar - (T_C - t_f) / r_s),
+            (T_C.min(t_a) - r_s * m_bar.abs()).max(0.0),
+            T_C.max(t_a) + r_s * m_bar.abs(),
+        )?;
+
+        let heat_loss = Q - q_v(t_a, p_a);
+        let flux1 = heat_loss - (1.0 - phi) * (T_C - t_s) / r_s;
+        let flux2 = flux1 - phi * (T_C - t_f) / r_s;
+
+        let mut state = EquivalentState {
+            region: Region::Phi,
+            phi,
+            rf: Float::INFINITY,
+            rs: r_s,
+            dtc_dt: 0.0,
+        };
+
+        if flux1 <= 0.0 {
+            state.phi = 1.0 - heat_loss * r_s / (T_C - t_s);
+        } else if flux2 <= 0.0 {
+            let t_s_bar = T_C - heat_loss * r_s / phi + (1.0 / phi - 1.0) * (T_C - t_s);
+            let t_f = solve(
+                |t_f| {
+                    Ok((t_f - t_a) / r_a_bar(t_f, t_a)
+                        + (p_c - p_a) * (t_f - t_a)
+                            / ((z_s(r_s) + Z_A_BAR) * (t_f - t_a)
+                                + R_ZF * r_a_bar(t_f, t_a) * (t_s_bar - t_f))
+                        - (T_C - t_s_bar) / r_s)
+                },
+                t_a,
+                t_s_bar,
+            )?;
+
+            state.region = Region::Rf;
+            state.rf = r_a_bar(t_f, t_a) * (t_s_bar - t_f) / (t_f - t_a);
+        } else {
+            state.rf = 0.0;
+
+            let flux3 = heat_loss - (T_C - t_a) / r_a_un(T_C, t_a) - (p_c - p_a) / Z_A_UN;
+
+            if flux3 < 0.0 {
+                let t_s = solve(
+                    |t_s| {
+                        Ok((t_s - t_a) / r_a_un(t_s, t_a)
+                            + (p_c - p_a) / (z_s((T_C - t_s) / heat_loss) + Z_A_UN)
+                            - heat_loss)
+                    },
+                    0.0,
+                    T_C,
+                )?;
+
+                state.region = Region::Rs;
+                state.rs = (T_C - t_s) / heat_loss;
+
+                let p_s = p_c - (p_c - p_a) * z_s(state.rs) / (z_s(state.rs) + Z_A_UN);
+
+                if p_s > PHI_SALT * saturation_vapour_pressure(t_s) {
+                    let t_s = solve(
+                        |t_s| {
+                            Ok((t_s - t_a) / r_a_un(t_s, t_a)
+                                + (PHI_SALT * saturation_vapour_pressure(t_s) - p_a) / Z_A_UN
+                                - heat_loss)
+                        },
+                        0.0,
+                        T_C,
+                    )?;
+
+                    state.region = Region::RsStar;
+                    state.rs = (T_C - t_s) / heat_loss;
+                }
+            } else {
+                state.region = Region::DTcDt;
+                state.rs = 0.0;
+                state.dtc_dt = flux3 / core_heat_capacity();
+            }
+        }
+
+        Ok(state)
+    }
+
+    //finds temperature at which air of reference humidity has the same equivalent variable
+    pub(super) fn heat_index(state: EquivalentState) -> Result<Float, InputError> {
+        let reference_humidity = |t: Float| P_A0 / saturation_vapour_pressure(t);
+
+        match state.region {
+            Region::Phi => solve(
+                |t| Ok(equivalent_state(t, 1.0)?.phi - state.phi),
+                0.0,
+                240.0,
+            ),
+            Region::Rf => solve(
+                |t| Ok(equivalent_state(t, reference_humidity(t).min(1.0))?.rf - state.rf),
+                230.0,
+                300.0,
+            ),
+            Region::Rs | Region::RsStar => solve(
+                |t| Ok(equivalent_state(t, reference_humidity(t))?.rs - state.rs),
+                295.0,
+                350.0,
+            ),
+            Region::DTcDt => solve(
+                |t| Ok(equivalent_state(t, reference_humidity(t))?.dtc_dt - state.dtc_dt),
+                340.0,
+                1000.0,
+            ),
+        }
+    }
+
+    //finds root of function changing its sign between lower and upper with bisection
+    fn solve<F>(function: F, mut lower: Float, mut upper: Float) -> Result<Float, InputError>
+    where
+        F: Fn(Float) -> Result<Float, InputError>,
+    {
+        let lower_sign = function(lower)?.signum();
+
+        if lower_sign == function(upper)?.signum() {
+            return Err(InputError::IncorrectArgumentSet(String::from(
+                "heat index model equations cannot be solved for given inputs",
+            )));
+        }
+
+        for _ in 0..60 {
+            let middle = 0.5 * (lower + upper);
+
+            if function(middle)?.signum() == lower_sign {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+
+        Ok(0.5 * (lower + upper))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        heat_index,
+        tests_framework::{self, Argument},
+        vapour_pressure, Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn rothfusz1() {
+        assert!(tests_framework::test_with_2args(
+            &heat_index::rothfusz1,
+            Argument {
+                name: "temperature",
+                def_val: 305.372,
+                range: [300.0, 322.0]
+            },
+            Argument {
+                name: "relative_humidity",
+                def_val: 0.5,
+                range: [0.0, 1.0]
+            },
+            307.926
+        ));
+    }
+
+    #[test]
+    fn steadman1() {
+        assert!(tests_framework::test_with_2args(
+            &heat_index::steadman1,
+            Argument {
+                name: "temperature",
+                def_val: 294.261,
+                range: [273.0, 322.0]
+            },
+            Argument {
+                name: "relative_humidity",
+                def_val: 0.5,
+                range: [0.0, 1.0]
+            },
+            293.733
+        ));
+    }
+
+    #[test]
+    fn lu_romps1() {
+        //by definition (Lu & Romps, 2022) the heat index of air at the reference vapour pressure
+        //of 1.6kPa, or saturated when that is not possible, equals its temperature
+        for temperature in [300.0, 310.0, 320.0] {
+            let relative_humidity = 1600.0 / vapour_pressure::murphy_koop1(temperature).unwrap();
+            let result = heat_index::lu_romps1(temperature, relative_humidity).unwrap();
+            assert_approx_eq!(Float, result, temperature, epsilon = 0.05);
+        }
+
+        for temperature in [230.0, 250.0, 260.0] {
+            let result = heat_index::lu_romps1(temperature, 1.0).unwrap();
+            assert_approx_eq!(Float, result, temperature, epsilon = 0.05);
+        }
+
+        //reproduces Steadman's values tabulated in the NWS heat index chart
+        //(90F, 50%) -> 95F, (96F, 50%) -> 108F, (100F, 40%) -> 109F
+        for [temperature, relative_humidity, expected] in [
+            [305.372, 0.5, 308.15],
+            [308.706, 0.5, 315.372],
+            [310.928, 0.4, 315.928],
+        ] {
+            let result = heat_index::lu_romps1(temperature, relative_humidity).unwrap();
+            assert_approx_eq!(Float, result, expected, epsilon = 0.5);
+        }
+
+        //heat index increases with humidity and temperature in all regions of the model
+        for temperature in [223.0, 250.0, 280.0, 300.0, 320.0, 333.0] {
+            let mut previous = 0.0;
+
+            for relative_humidity in [0.0, 0.25, 0.5, 0.75, 1.0] {
+                let result = heat_index::lu_romps1(temperature, relative_humidity).unwrap();
+                assert!(result.is_finite());
+                assert!(result >= previous - 0.01);
+                previous = result;
+            }
+        }
+    }
+}
@@ -0,0 +1,313 @@
+//!Functions to calculate geopotential thickness and height of atmospheric layers in m.
+//!
+//!The hypsometric equation relates the thickness of the layer between two pressure levels
+//!to the mean virtual temperature of that layer [Wikipedia](https://en.wikipedia.org/wiki/Hypsometric_equation).
+//!All heights are geopotential heights (in geopotential metres).
+//!
+//!Functions operating on [`Sounding`] use virtual temperature of each level
+//!([`Level::virtual_temperature`]). At levels with missing dewpoint air is treated as dry
+//!and temperature is used instead. Virtual temperature is assumed to vary linearly
+//!with logarithm of pressure between levels.
+
+use crate::Float;
+use crate::{
+    constants::{G, R_D},
+    errors::InputError,
+    sounding::{Level, Sounding},
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing geopotential thickness of the layer between two pressure levels
+///from its mean virtual temperature.
+///
+///Mean virtual temperature can be computed with functions from [`virtual_temperature`](crate::virtual_temperature) module.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `mean_virtual_temperature` range: 173K - 354K\
+///Valid `bottom_pressure` range: 100Pa - 150000Pa\
+///Valid `top_pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `top_pressure` is higher than `bottom_pressure`.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn thickness1(
+    mean_virtual_temperature: Float,
+    bottom_pressure: Float,
+    top_pressure: Float,
+) -> Result<Float, InputError> {
+    if !(173.0..=354.0).contains(&mean_virtual_temperature) {
+        return Err(InputError::OutOfRange(String::from(
+            "mean_virtual_temperature",
+        )));
+    }
+
+    if !(100.0..=150_000.0).contains(&bottom_pressure) {
+        return Err(InputError::OutOfRange(String::from("bottom_pressure")));
+    }
+
+    if !(100.0..=150_000.0).contains(&top_pressure) {
+        return Err(InputError::OutOfRange(String::from("top_pressure")));
+    }
+
+    if top_pressure > bottom_pressure {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "top_pressure cannot be higher than bottom_pressure",
+        )));
+    }
+
+    let result = (R_D * mean_virtual_temperature / G) * (bottom_pressure / top_pressure).ln();
+
+    Ok(result)
+}
+
+///Formula for computing geopotential height of every level of the sounding
+///by integrating the hypsometric equation upwards from the lowest level at `surface_height`.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when `surface_height` is out of range.\
+///Valid `surface_height` range: -500m - 90000m
+///
+///Returns the errors of [`Level::virtual_temperature`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn heights1(sounding: &Sounding, surface_height: Float) -> Result<Vec<Float>, InputError> {
+    validate_surface_height(surface_height)?;
+
+    let virtual_temperatures = virtual_temperatures(sounding)?;
+
+    let mut result = Vec::with_capacity(virtual_temperatures.len());
+    let mut height = surface_height;
+    result.push(height);
+
+    for pair in virtual_temperatures.windows(2) {
+        let [(p_a, t_a), (p_b, t_b)] = [pair[0], pair[1]];
+        height += layer_thickness(p_a, t_a, p_b, t_b);
+        result.push(height);
+    }
+
+    Ok(result)
+}
+
+///Formula for computing geopotential height at given `pressure` within the sounding,
+///with the lowest level of the sounding at `surface_height`.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when `surface_height` is out of range.\
+///Valid `surface_height` range: -500m - 90000m
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `pressure` is outside of the sounding.\
+///Returns the errors of [`Level::virtual_temperature`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn height_at_pressure1(
+    sounding: &Sounding,
+    surface_height: Float,
+    pressure: Float,
+) -> Result<Float, InputError> {
+    validate_surface_height(surface_height)?;
+
+    let virtual_temperatures = virtual_temperatures(sounding)?;
+    let mut height = surface_height;
+
+    for pair in virtual_temperatures.windows(2) {
+        let [(p_a, t_a), (p_b, t_b)] = [pair[0], pair[1]];
+
+        if (p_b..=p_a).contains(&pressure) {
+            let t = t_a + (t_b - t_a) * (pressure / p_a).ln() / (p_b / p_a).ln();
+            return Ok(height + layer_thickness(p_a, t_a, pressure, t));
+        }
+
+        height += layer_thickness(p_a, t_a, p_b, t_b);
+    }
+
+    Err(InputError::IncorrectArgumentSet(String::from(
+        "pressure is outside of the sounding",
+    )))
+}
+
+///Formula for computing pressure at given geopotential `height` within the sounding,
+///with the lowest level of the sounding at `surface_height`.
+///
+///Within the layer containing `height` the hypsometric equation is solved for pressure with bisection.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when `surface_height` is out of range.\
+///Valid `surface_height` range: -500m - 90000m
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `height` is outside of the sounding.\
+///Returns the errors of [`Level::virtual_temperature`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn pressure_at_height1(
+    sounding: &Sounding,
+    surface_height: Float,
+    height: Float,
+) -> Result<Float, InputError> {
+    validate_surface_height(surface_height)?;
+
+    let virtual_temperatures = virtual_temperatures(sounding)?;
+    let mut bottom_height = surface_height;
+
+    for pair in virtual_temperatures.windows(2) {
+        let [(p_a, t_a), (p_b, t_b)] = [pair[0], pair[1]];
+        let top_height = bottom_height + layer_thickness(p_a, t_a, p_b, t_b);
+
+        if (bottom_height..=top_height).contains(&height) {
+            //height increases monotonically with decreasing pressure so bisection always converges
+            let mut lower = p_b;
+            let mut upper = p_a;
+
+            for _ in 0..40 {
+                let middle = 0.5 * (lower + upper);
+                let t = t_a + (t_b - t_a) * (middle / p_a).ln() / (p_b / p_a).ln();
+
+                if bottom_height + layer_thickness(p_a, t_a, middle, t) > height {
+                    lower = middle;
+                } else {
+                    upper = middle;
+                }
+            }
+
+            return Ok(0.5 * (lower + upper));
+        }
+
+        bottom_height = top_height;
+    }
+
+    Err(InputError::IncorrectArgumentSet(String::from(
+        "height is outside of the sounding",
+    )))
+}
+
+fn validate_surface_height(surface_height: Float) -> Result<(), InputError> {
+    if !(-500.0..=90_000.0).contains(&surface_height) {
+        return Err(InputError::OutOfRange(String::from("surface_height")));
+    }
+
+    Ok(())
+}
+
+//collects (pressure, virtual temperature) pairs at all levels of the sounding
+fn virtual_temperatures(sounding: &Sounding) -> Result<Vec<(Float, Float)>, InputError> {
+    sounding
+        .iter()
+        .map(|level: &Level| match level.dewpoint {
+            Some(_) => Ok((level.pressure, level.virtual_temperature()?)),
+            None => Ok((level.pressure, level.temperature)),
+        })
+        .collect()
+}
+
+//thickness of layer in which virtual temperature varies linearly with logarithm of pressure
+fn layer_thickness(p_a: Float, t_a: Float, p_b: Float, t_b: Float) -> Float {
+    (R_D * 0.5 * (t_a + t_b) / G) * (p_a / p_b).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constants::{G, R_D},
+        errors::InputError,
+        hypsometric,
+        sounding::Sounding,
+        tests_framework::{self, Argument},
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    fn sounding() -> Sounding {
+        Sounding::from_hpa_celsius(
+            &[1000.0, 925.0, 850.0, 700.0, 500.0, 300.0],
+            &[15.0, 10.5, 6.0, -3.0, -21.0, -45.0],
+            &[10.0, 6.0, 1.0, -10.0, -30.0, -55.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn thickness1() {
+        assert!(tests_framework::test_with_3args(
+            &hypsometric::thickness1,
+            Argument {
+                name: "mean_virtual_temperature",
+                def_val: 273.15,
+                range: [173.0, 354.0]
+            },
+            Argument {
+                name: "bottom_pressure",
+                def_val: 100_000.0,
+                range: [100.0, 150_000.0]
+            },
+            Argument {
+                name: "top_pressure",
+                def_val: 50000.0,
+                range: [100.0, 150_000.0]
+            },
+            5542.1157
+        ));
+    }
+
+    #[test]
+    fn heights1() {
+        let sounding = sounding();
+        let heights = hypsometric::heights1(&sounding, 110.0).unwrap();
+
+        assert_approx_eq!(Float, heights[0], 110.0);
+        assert!(heights.windows(2).all(|h| h[1] > h[0]));
+
+        //standard-like atmosphere has 500hPa at about 5.5km
+        assert!(heights[4] > 5400.0 && heights[4] < 5800.0);
+
+        //isothermal dry layer has exact thickness
+        let isothermal =
+            Sounding::from_si(&[100_000.0, 50000.0], &[250.0, 250.0], &[200.0, 200.0]).unwrap();
+        let heights = hypsometric::heights1(&isothermal, 0.0).unwrap();
+        let virtual_temperature = isothermal.levels()[0].virtual_temperature().unwrap();
+        let expected = R_D * virtual_temperature / G * (2.0 as Float).ln();
+        assert_approx_eq!(Float, heights[1], expected, epsilon = 0.01);
+
+        assert_eq!(
+            hypsometric::heights1(&sounding, -1000.0).unwrap_err(),
+            InputError::OutOfRange(String::from("surface_height"))
+        );
+    }
+
+    #[test]
+    fn height_at_pressure1() {
+        let sounding = sounding();
+        let heights = hypsometric::heights1(&sounding, 110.0).unwrap();
+
+        for (level, height) in sounding.iter().zip(&heights) {
+            let result =
+                hypsometric::height_at_pressure1(&sounding, 110.0, level.pressure).unwrap();
+            assert_approx_eq!(Float, result, *height, epsilon = 0.01);
+        }
+
+        let result = hypsometric::height_at_pressure1(&sounding, 110.0, 60000.0).unwrap();
+        assert!(result > heights[3] && result < heights[4]);
+
+        assert!(matches!(
+            hypsometric::height_at_pressure1(&sounding, 110.0, 20000.0),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+
+    #[test]
+    fn pressure_at_height1() {
+        let sounding = sounding();
+
+        for pressure in [100_000.0, 95000.0, 70000.0, 60000.0, 30000.0] {
+            let height = hypsometric::height_at_pressure1(&sounding, 110.0, pressure).unwrap();
+            let result = hypsometric::pressure_at_height1(&sounding, 110.0, height).unwrap();
+            assert_approx_eq!(Float, result, pressure, epsilon = 1.0);
+        }
+
+        assert!(matches!(
+            hypsometric::pressure_at_height1(&sounding, 110.0, 0.0),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+}
@@ -0,0 +1,303 @@
+//!Functions to calculate latent heats of phase changes of water in J*kg^-1.
+//!
+//!Functions with `1` suffix use Kirchhoff's relation with heat capacities from [`constants`](crate::constants),
+//!so they are consistent with formulas using those heat capacities (eg. [`moist_adiabat`](crate::moist_adiabat)).
+//!Functions with `2` suffix are more accurate empirical fits.
+//!
+//!For latent heats at the reference temperature (0°C) see [`L_V`], [`L_F`] and [`L_S`].
+
+use crate::{
+    constants::{C_L, C_PV, C_S, L_F, L_S, L_V, M_V, ZERO_CELSIUS},
+    dual::Number,
+    errors::InputError,
+    Float,
+};
+use std::ops::{Add, Mul, Sub};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing latent heat of vaporisation of water from temperature.
+///
+///Derived from Kirchhoff's relation assuming constant heat capacities of water vapour ([`C_PV`])
+///and liquid water ([`C_L`]), with [`L_V`] at 0°C.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 173K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn vaporisation1<N: Number>(temperature: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(173.0..=374.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let result = L_V + (C_PV - C_L) * (temperature - ZERO_CELSIUS);
+
+    Ok(result)
+}
+
+///Formula for computing latent heat of vaporisation of supercooled water from temperature.
+///
+///Derived by D. M. Murphy and T. Koop (2005) [(doi:10.1256/qj.04.94)](https://doi.org/10.1256/qj.04.94).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 236K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn vaporisation2<N: Number>(temperature: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(236.0..=274.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let result = murphy_koop_vaporisation(temperature);
+
+    Ok(result)
+}
+
+///Formula for computing latent heat of fusion of water from temperature.
+///
+///Derived from Kirchhoff's relation assuming constant heat capacities of liquid water ([`C_L`])
+///and ice ([`C_S`]), with [`L_F`] at 0°C.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 173K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn fusion1<N: Number>(temperature: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(173.0..=374.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let result = L_F + (C_L - C_S) * (temperature - ZERO_CELSIUS);
+
+    Ok(result)
+}
+
+///Formula for computing latent heat of fusion of supercooled water from temperature.
+///Difference of [`sublimation2`] and [`vaporisation2`].
+///
+///Derived by D. M. Murphy and T. Koop (2005) [(doi:10.1256/qj.04.94)](https://doi.org/10.1256/qj.04.94).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 236K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn fusion2<N: Number>(temperature: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(236.0..=274.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let result = murphy_koop_sublimation(temperature) - murphy_koop_vaporisation(temperature);
+
+    Ok(result)
+}
+
+///Formula for computing latent heat of sublimation of ice from temperature.
+///
+///Derived from Kirchhoff's relation assuming constant heat capacities of water vapour ([`C_PV`])
+///and ice ([`C_S`]), with [`L_S`] at 0°C.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 173K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn sublimation1<N: Number>(temperature: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(173.0..=374.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let result = L_S + (C_PV - C_S) * (temperature - ZERO_CELSIUS);
+
+    Ok(result)
+}
+
+///Formula for computing latent heat of sublimation of ice from temperature.
+///
+///Derived by D. M. Murphy and T. Koop (2005) [(doi:10.1256/qj.04.94)](https://doi.org/10.1256/qj.04.94).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 30K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn sublimation2<N: Number>(temperature: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(30.0..=274.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let result = murphy_koop_sublimation(temperature);
+
+    Ok(result)
+}
+
+//fits from the paper are in J*mol^-1
+fn murphy_koop_vaporisation<N: Number>(temperature: N) -> N
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    (56579.0 - 42.212 * temperature + (0.1149 * (281.6 - temperature)).exp()) / M_V
+}
+
+fn murphy_koop_sublimation<N: Number>(temperature: N) -> N
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
+    (46782.5 + 35.8925 * temperature - 0.07414 * temperature.powi(2)
+        + 541.5 * (-(temperature / 123.75).powi(2)).exp())
+        / M_V
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use crate::{
+        constants::{L_F, L_S, L_V, ZERO_CELSIUS},
+        latent_heat,
+        tests_framework::{self, Argument},
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn vaporisation1() {
+        assert!(tests_framework::test_with_1arg(
+            &latent_heat::vaporisation1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [173.0, 374.0]
+            },
+            2_437_114.485
+        ));
+
+        assert_approx_eq!(
+            Float,
+            latent_heat::vaporisation1(ZERO_CELSIUS).unwrap(),
+            L_V,
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn vaporisation2() {
+        assert!(tests_framework::test_with_1arg(
+            &latent_heat::vaporisation2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [236.0, 274.0]
+            },
+            2_556_925.9
+        ));
+    }
+
+    #[test]
+    fn fusion1() {
+        assert!(tests_framework::test_with_1arg(
+            &latent_heat::fusion1,
+            Argument {
+                name: "temperature",
+                def_val: 263.15,
+                range: [173.0, 374.0]
+            },
+            312_580.0
+        ));
+
+        assert_approx_eq!(
+            Float,
+            latent_heat::fusion1(ZERO_CELSIUS).unwrap(),
+            L_F,
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn fusion2() {
+        assert!(tests_framework::test_with_1arg(
+            &latent_heat::fusion2,
+            Argument {
+                name: "temperature",
+                def_val: 254.0,
+                range: [236.0, 274.0]
+            },
+            291_031.01
+        ));
+    }
+
+    #[test]
+    fn sublimation1() {
+        assert!(tests_framework::test_with_1arg(
+            &latent_heat::sublimation1,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [173.0, 374.0]
+            },
+            2_840_516.685
+        ));
+
+        assert_approx_eq!(
+            Float,
+            latent_heat::sublimation1(ZERO_CELSIUS).unwrap(),
+            L_S,
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn sublimation2() {
+        assert!(tests_framework::test_with_1arg(
+            &latent_heat::sublimation2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [30.0, 274.0]
+            },
+            2_838_202.34
+        ));
+    }
+
+    #[test]
+    fn reference_values() {
+        //fits agree with Kirchhoff's relation close to 0C
+        for (fit, kirchhoff) in [
+            (
+                latent_heat::vaporisation2 as fn(_) -> _,
+                latent_heat::vaporisation1 as fn(_) -> _,
+            ),
+            (latent_heat::fusion2, latent_heat::fusion1),
+            (latent_heat::sublimation2, latent_heat::sublimation1),
+        ] {
+            let expected = kirchhoff(ZERO_CELSIUS).unwrap();
+            assert_approx_eq!(
+                Float,
+                fit(ZERO_CELSIUS).unwrap() / expected,
+                1.0,
+                epsilon = 0.01
+            );
+        }
+    }
+}
@@ -42,6 +42,12 @@
 //! If increased accuracy is needed (at the cost of performance) `double_precision` feature can be enabled
 //! to use double-precision (64-bit) floating point.
 //!
+//! # Automatic differentiation
+//!
+//! Formulae of the moist thermodynamics chain (eg. [`vapour_pressure::buck1`] or [`equivalent_potential_temperature::bolton1`])
+//! are generic over the [`Number`](dual::Number) trait. They can be called with [`Dual`](dual::Dual) numbers
+//! to compute partial derivatives of the result alongside its value. See the [`dual`] module for the list of generic formulas.
+//!
 //! # Input checking
 //!
 //! To prevent any unexpected behaviour, all functions check whether provided inputs are within a reasonable range.
@@ -62,6 +68,13 @@
 //! - Density: kilograms per meter cubed (kg*m^3)
 //! - Mixing ratio: kilograms per kilogram (kg*kg^-1)
 //! - Specific humidity: kilograms per kilogram (kg*kg^-1)
+//! - Wind speed: meters per second (m*s^-1)
+//! - Wind direction: degrees (°) clockwise from north, direction from which the wind blows
+//! - Radiation flux: watts per meter squared (W*m^-2)
+//!
+//! Surface wind speed used by human comfort indices (eg. [`wind_chill`] or [`apparent_temperature`])
+//! is the wind speed measured at the standard height of 10m. Wind speed is checked against 0m/s - 150m/s
+//! range, unless the formula is valid only in a narrower range.
 //!
 //! If the formula uses numbers of very different scales there can be an exception from that rule described in the function documentation.
 //!
@@ -71,19 +84,42 @@
 //! With that feature when returning the error function will also print the error message to `log` with additional
 //! information about the error. This feature potentially is not zero-cost so it is optional.
 
+pub mod altimeter_setting;
+pub mod apparent_temperature;
+pub mod comfort_index;
 pub mod constants;
+pub mod contrail;
+pub mod convective_available_potential_energy;
+pub mod density_altitude;
+pub mod dewpoint;
+pub mod dual;
+pub mod enhancement_factor;
 pub mod equivalent_potential_temperature;
 pub mod errors;
+pub mod heat_index;
+pub mod hypsometric;
+pub mod latent_heat;
 pub mod mixing_ratio;
+pub mod moist_adiabat;
+pub mod mslp;
+pub mod parcel;
 pub mod potential_temperature;
+pub mod precipitable_water;
+pub mod pressure_altitude;
 pub mod relative_humidity;
+pub mod sounding;
 pub mod specific_humidity;
+pub mod stability_indices;
+pub mod standard_atmosphere;
 mod tests_framework;
 pub mod vapour_pressure;
 pub mod vapour_pressure_deficit;
+pub mod vapour_pressure_slope;
 pub mod virtual_temperature;
 pub mod wet_bulb_potential_temperature;
+pub mod wet_bulb_globe_temperature;
 pub mod wet_bulb_temperature;
+pub mod wind_chill;
 
 #[cfg(not(feature = "double_precision"))]
 type Float = f32;
@@ -3,9 +3,15 @@
 //!To calculate saturation mixing ratio input dry-bulb temperature in place of dewpoint
 //!or saturation vapour pressure in place of vapour pressure.
 
-use crate::{constants::EPSILON, errors::InputError, vapour_pressure};
+use crate::{
+    constants::EPSILON,
+    dual::Number,
+    errors::InputError,
+    vapour_pressure::{self, PhaseBlending},
+};
 use float_cmp::approx_eq;
 use crate::Float;
+use std::ops::{Add, Div, Mul, Sub};
 
 #[cfg(feature="debug")]
 use floccus_proc::logerr;
@@ -21,17 +27,20 @@ use floccus_proc::logerr;
 ///Returns [`InputError::IncorrectArgumentSet`] when inputs are equal, in which
 ///case division by 0 occurs.
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general1(pressure: Float, vapour_pressure: Float) -> Result<Float, InputError> {
+pub fn general1<N: Number>(pressure: N, vapour_pressure: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N>,
+{
     //validate inputs
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
-    if !(0.0..=50_000.0).contains(&vapour_pressure) {
+    if !(0.0..=50_000.0).contains(&vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from("vapour_pressure")));
     }
 
-    if approx_eq!(Float, pressure, vapour_pressure, ulps = 2) {
+    if approx_eq!(Float, pressure.value(), vapour_pressure.value(), ulps = 2) {
         return Err(InputError::IncorrectArgumentSet(String::from(
             "pressure and vapour_pressure cannot be equal",
         )));
@@ -50,13 +59,16 @@ pub fn general1(pressure: Float, vapour_pressure: Float) -> Result<Float, InputE
 ///Valid `dewpoint` range: 273K - 353K\
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn performance1(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
+pub fn performance1<N: Number>(dewpoint: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N>,
+{
     //validate inputs
-    if !(273.0..=353.0).contains(&dewpoint) {
+    if !(273.0..=353.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -74,13 +86,16 @@ pub fn performance1(dewpoint: Float, pressure: Float) -> Result<Float, InputErro
 ///Valid `dewpoint` range: 232K - 324K\
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn accuracy1(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
+pub fn accuracy1<N: Number>(dewpoint: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
     //validate inputs
-    if !(232.0..=324.0).contains(&dewpoint) {
+    if !(232.0..=324.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -89,12 +104,58 @@ pub fn accuracy1(dewpoint: Float, pressure: Float) -> Result<Float, InputError>
     Ok(result)
 }
 
+///Formula for computing mixing ratio of unsaturated air from dewpoint temperature and pressure
+///with vapour pressure over water and ice blended as in [`vapour_pressure::mixed_phase1`].
+///Input dry-bulb temperature to get mixed-phase saturation mixing ratio.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `dewpoint` range: 110K - 332K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when vapour pressure is not lower than `pressure`.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn mixed_phase1<N: Number>(
+    dewpoint: N,
+    pressure: N,
+    blending: PhaseBlending,
+) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    //validate inputs
+    if !(110.0..=332.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    let vapour_pressure = vapour_pressure::mixed_phase1(dewpoint, blending)?;
+
+    if vapour_pressure.value() >= pressure.value() {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "vapour pressure must be lower than pressure",
+        )));
+    }
+
+    let result = general1(pressure, vapour_pressure)?;
+    Ok(result)
+}
+
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
+        errors::InputError,
         mixing_ratio,
         tests_framework::{self, Argument},
+        vapour_pressure::PhaseBlending,
+        Float,
     };
+    use float_cmp::assert_approx_eq;
 
     #[test]
     fn general1() {
@@ -149,4 +210,23 @@ mod tests {
             0.022587116896465847
         ));
     }
+
+    #[test]
+    fn mixed_phase1() {
+        let result = mixing_ratio::mixed_phase1(260.0, 80000.0, PhaseBlending::Ifs).unwrap();
+        assert_approx_eq!(Float, result / 0.001_564_5, 1.0, epsilon = 0.001);
+
+        let result = mixing_ratio::mixed_phase1(300.0, 101325.0, PhaseBlending::Linear).unwrap();
+        let expected = mixing_ratio::general1(101325.0, 3536.7644).unwrap();
+        assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.0001);
+
+        assert!(matches!(
+            mixing_ratio::mixed_phase1(330.0, 10000.0, PhaseBlending::Ifs),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert_eq!(
+            mixing_ratio::mixed_phase1(260.0, 50.0, PhaseBlending::Ifs).unwrap_err(),
+            InputError::OutOfRange(String::from("pressure"))
+        );
+    }
 }
@@ -0,0 +1,664 @@
+//!Functions to calculate temperature and water content of air parcel following moist adiabat.
+//!
+//!Functions in this module integrate the first law of thermodynamics for a parcel of moist air
+//!changing its pressure without heat exchange with the environment. The parcel is kept in
+//!thermodynamic equilibrium, so water vapour above saturation is condensed (or deposited)
+//!and condensate is evaporated when the parcel becomes subsaturated.
+//!Latent heats change with temperature as given by [`latent_heat::vaporisation1`] and [`latent_heat::fusion1`],
+//!consistently with heat capacities of water vapour, liquid water and ice.
+//!
+//!All mixing ratios are given per unit mass of dry air.
+
+use crate::Float;
+use crate::{
+    constants::{C_L, C_P, C_PV, C_S, R_D, R_V},
+    errors::InputError,
+    latent_heat, mixing_ratio, vapour_pressure,
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+//upper limit of valid mixing ratio range
+const MAX_MIXING_RATIO: Float = 0.1;
+
+//thermodynamic process followed by the parcel
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Process {
+    Reversible,
+    Pseudo,
+    SaturatedDescent,
+}
+
+///Thermodynamic state of an air parcel at a single pressure level.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ParcelState {
+    ///Pressure in `Pa`
+    pub pressure: Float,
+    ///Temperature in `K`
+    pub temperature: Float,
+    ///Water vapour mixing ratio in `kg*kg^-1`
+    pub vapour_mixing_ratio: Float,
+    ///Liquid water mixing ratio in `kg*kg^-1`
+    pub liquid_mixing_ratio: Float,
+    ///Ice mixing ratio in `kg*kg^-1`
+    pub ice_mixing_ratio: Float,
+}
+
+///Formula for computing state of air parcel lifted (or lowered) along reversible moist adiabat
+///from its initial temperature, pressure and total water mixing ratio.
+///
+///In the reversible process all condensate stays in the parcel and contributes to its heat capacity
+///([`C_L`](crate::constants::C_L) for liquid water and [`C_S`](crate::constants::C_S) for ice),
+///so lowering the parcel back to its initial pressure restores its initial state.
+///
+///When `freezing_range` (`[start, end]` in K) is provided, the fraction of condensate in the form of ice
+///increases linearly from 0 at `start` to 1 at `end` and the latent heat of fusion is released.
+///Saturation vapour pressure is then weighted with the same fraction between
+///[`buck1`](vapour_pressure::buck1) over water and [`buck2`](vapour_pressure::buck2) over ice.
+///Without `freezing_range` all condensate remains liquid.
+///
+///Parcel state is returned for each of `pressure_levels` in the provided order.
+///The first law of thermodynamics is integrated with implicit trapezoidal scheme
+///in steps not larger than 1% of pressure.
+///
+///Provided in Emanuel, Kerry (1994). Atmospheric Convection. Oxford University Press.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K (193K - 324K with `freezing_range`)\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `total_mixing_ratio` range: 0.0 - 0.1\
+///Valid `pressure_levels` range: 100Pa - 150000Pa\
+///Valid `freezing_range` range: 232K - 274K
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the start of `freezing_range` is not higher than its end,
+///or when parcel temperature leaves the valid `temperature` range during integration.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn reversible1(
+    temperature: Float,
+    pressure: Float,
+    total_mixing_ratio: Float,
+    pressure_levels: &[Float],
+    freezing_range: Option<[Float; 2]>,
+) -> Result<Vec<ParcelState>, InputError> {
+    validate_inputs(temperature, pressure, pressure_levels, freezing_range)?;
+
+    if !(0.0..=MAX_MIXING_RATIO).contains(&total_mixing_ratio) {
+        return Err(InputError::OutOfRange(String::from("total_mixing_ratio")));
+    }
+
+    integrate(
+        temperature,
+        pressure,
+        total_mixing_ratio,
+        pressure_levels,
+        freezing_range,
+        Process::Reversible,
+    )
+}
+
+///Formula for computing state of air parcel lifted (or lowered) along pseudo-adiabat
+///from its initial temperature, pressure and water vapour mixing ratio.
+///
+///In the pseudo-adiabatic process all condensate falls out of the parcel immediately after forming,
+///so the parcel carries no liquid water or ice and its heat capacity includes only dry air and water vapour.
+///Returned liquid and ice mixing ratios are therefore always 0.
+///Parcel lowered along pseudo-adiabat has no condensate to evaporate, so it warms dry-adiabatically,
+///see [`saturated_descent1`] for parcel kept saturated by evaporation of precipitation.
+///
+///When `freezing_range` (`[start, end]` in K) is provided, the fraction of condensate deposited as ice
+///increases linearly from 0 at `start` to 1 at `end` and the latent heat of fusion is released.
+///Saturation vapour pressure is then weighted with the same fraction between
+///[`buck1`](vapour_pressure::buck1) over water and [`buck2`](vapour_pressure::buck2) over ice.
+///Without `freezing_range` all condensate is liquid.
+///
+///Parcel state is returned for each of `pressure_levels` in the provided order.
+///The first law of thermodynamics is integrated with implicit trapezoidal scheme
+///in steps not larger than 1% of pressure.
+///
+///Provided in Emanuel, Kerry (1994). Atmospheric Convection. Oxford University Press.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K (193K - 324K with `freezing_range`)\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `mixing_ratio` range: 0.0 - 0.1\
+///Valid `pressure_levels` range: 100Pa - 150000Pa\
+///Valid `freezing_range` range: 232K - 274K
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the start of `freezing_range` is not higher than its end,
+///or when parcel temperature leaves the valid `temperature` range during integration.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn pseudo1(
+    temperature: Float,
+    pressure: Float,
+    mixing_ratio: Float,
+    pressure_levels: &[Float],
+    freezing_range: Option<[Float; 2]>,
+) -> Result<Vec<ParcelState>, InputError> {
+    validate_inputs(temperature, pressure, pressure_levels, freezing_range)?;
+
+    if !(0.0..=MAX_MIXING_RATIO).contains(&mixing_ratio) {
+        return Err(InputError::OutOfRange(String::from("mixing_ratio")));
+    }
+
+    integrate(
+        temperature,
+        pressure,
+        mixing_ratio,
+        pressure_levels,
+        freezing_range,
+        Process::Pseudo,
+    )
+}
+
+///Formula for computing state of saturated air parcel lowered along pseudo-adiabat
+///from its initial temperature and pressure.
+///
+///The parcel evaporates falling condensate (as in precipitation-driven downdrafts),
+///so it remains saturated while warming and its water vapour mixing ratio is equal to saturation mixing ratio.
+///As in [`pseudo1`] the parcel carries no liquid water or ice, so its heat capacity includes
+///only dry air and water vapour and returned liquid and ice mixing ratios are always 0.
+///
+///When `freezing_range` (`[start, end]` in K) is provided, the fraction of evaporated condensate in the form of ice
+///increases linearly from 0 at `start` to 1 at `end` and the latent heat of fusion is absorbed.
+///Saturation vapour pressure is then weighted with the same fraction between
+///[`buck1`](vapour_pressure::buck1) over water and [`buck2`](vapour_pressure::buck2) over ice.
+///
+///Parcel state is returned for each of `pressure_levels` in the provided order.
+///The first law of thermodynamics is integrated with implicit trapezoidal scheme
+///in steps not larger than 1% of pressure.
+///
+///Provided in Emanuel, Kerry (1994). Atmospheric Convection. Oxford University Press.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K (193K - 324K with `freezing_range`)\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `pressure_levels` range: 100Pa - 150000Pa\
+///Valid `freezing_range` range: 232K - 274K
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `pressure_levels` are lower than `pressure`,
+///when saturation mixing ratio of the parcel is higher than 0.1,
+///when the start of `freezing_range` is not higher than its end,
+///or when parcel temperature leaves the valid `temperature` range during integration.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn saturated_descent1(
+    temperature: Float,
+    pressure: Float,
+    pressure_levels: &[Float],
+    freezing_range: Option<[Float; 2]>,
+) -> Result<Vec<ParcelState>, InputError> {
+    validate_inputs(temperature, pressure, pressure_levels, freezing_range)?;
+
+    if pressure_levels.iter().any(|p| *p < pressure) {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "pressure_levels cannot be lower than pressure",
+        )));
+    }
+
+    let saturation_mixing_ratio = saturation_mixing_ratio(temperature, pressure, freezing_range)?;
+
+    if saturation_mixing_ratio > MAX_MIXING_RATIO {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "saturation mixing ratio of the parcel cannot be higher than 0.1",
+        )));
+    }
+
+    integrate(
+        temperature,
+        pressure,
+        saturation_mixing_ratio,
+        pressure_levels,
+        freezing_range,
+        Process::SaturatedDescent,
+    )
+}
+
+fn validate_inputs(
+    temperature: Float,
+    pressure: Float,
+    pressure_levels: &[Float],
+    freezing_range: Option<[Float; 2]>,
+) -> Result<(), InputError> {
+    if !(min_temperature(freezing_range)..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(100.0..=150_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    if pressure_levels
+        .iter()
+        .any(|p| !(100.0..=150_000.0).contains(p))
+    {
+        return Err(InputError::OutOfRange(String::from("pressure_levels")));
+    }
+
+    if let Some([start, end]) = freezing_range {
+        if !(232.0..=274.0).contains(&start) || !(232.0..=274.0).contains(&end) {
+            return Err(InputError::OutOfRange(String::from("freezing_range")));
+        }
+
+        if start <= end {
+            return Err(InputError::IncorrectArgumentSet(String::from(
+                "start of freezing_range must be higher than its end",
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn min_temperature(freezing_range: Option<[Float; 2]>) -> Float {
+    match freezing_range {
+        Some(_) => 193.0,
+        None => 232.0,
+    }
+}
+
+fn ice_fraction(temperature: Float, freezing_range: Option<[Float; 2]>) -> Float {
+    match freezing_range {
+        Some([start, end]) => ((start - temperature) / (start - end)).clamp(0.0, 1.0),
+        None => 0.0,
+    }
+}
+
+//splits total water into vapour, liquid and ice in thermodynamic equilibrium
+fn equilibrium(
+    temperature: Float,
+    pressure: Float,
+    total_mixing_ratio: Float,
+    freezing_range: Option<[Float; 2]>,
+) -> Result<[Float; 3], InputError> {
+    let saturation_mixing_ratio = saturation_mixing_ratio(temperature, pressure, freezing_range)?;
+
+    if total_mixing_ratio <= saturation_mixing_ratio {
+        return Ok([total_mixing_ratio, 0.0, 0.0]);
+    }
+
+    let ice_fraction = ice_fraction(temperature, freezing_range);
+    let condensate = total_mixing_ratio - saturation_mixing_ratio;
+
+    Ok([
+        saturation_mixing_ratio,
+        (1.0 - ice_fraction) * condensate,
+        ice_fraction * condensate,
+    ])
+}
+
+//saturation mixing ratio over mixture of water and ice given by freezing_range,
+//infinite when saturation vapour pressure exceeds pressure
+fn saturation_mixing_ratio(
+    temperature: Float,
+    pressure: Float,
+    freezing_range: Option<[Float; 2]>,
+) -> Result<Float, InputError> {
+    let ice_fraction = ice_fraction(temperature, freezing_range);
+
+    let mut saturation_vapour_pressure = 0.0;
+
+    if ice_fraction < 1.0 {
+        saturation_vapour_pressure +=
+            (1.0 - ice_fraction) * vapour_pressure::buck1(temperature, pressure)?;
+    }
+
+    if ice_fraction > 0.0 {
+        saturation_vapour_pressure += ice_fraction * vapour_pressure::buck2(temperature, pressure)?;
+    }
+
+    //air cannot be saturated when saturation vapour pressure exceeds pressure
+    if saturation_vapour_pressure >= pressure {
+        return Ok(Float::INFINITY);
+    }
+
+    mixing_ratio::general1(pressure, saturation_vapour_pressure)
+}
+
+//shared integrator of moist adiabats, unless the process is reversible
+//condensate is removed from the parcel after each step
+fn integrate(
+    temperature: Float,
+    pressure: Float,
+    total_mixing_ratio: Float,
+    pressure_levels: &[Float],
+    freezing_range: Option<[Float; 2]>,
+    process: Process,
+) -> Result<Vec<ParcelState>, InputError> {
+    let retain_condensate = process == Process::Reversible;
+
+    let [vapour, liquid, ice] =
+        equilibrium(temperature, pressure, total_mixing_ratio, freezing_range)?;
+
+    let mut state = ParcelState {
+        pressure,
+        temperature,
+        vapour_mixing_ratio: vapour,
+        liquid_mixing_ratio: if retain_condensate { liquid } else { 0.0 },
+        ice_mixing_ratio: if retain_condensate { ice } else { 0.0 },
+    };
+
+    let mut result = Vec::with_capacity(pressure_levels.len());
+
+    for &level in pressure_levels {
+        let steps = ((level / state.pressure).ln().abs() / 0.01).ceil().max(1.0) as usize;
+        let step_ratio = (level / state.pressure).powf(1.0 / steps as Float);
+        let start_pressure = state.pressure;
+
+        for i in 1..=steps {
+            let next_pressure = if i == steps {
+                level
+            } else {
+                start_pressure * step_ratio.powi(i as i32)
+            };
+
+            state = step(
+                state,
+                next_pressure,
+                freezing_range,
+                process == Process::SaturatedDescent,
+            )?;
+
+            if !retain_condensate {
+                state.liquid_mixing_ratio = 0.0;
+                state.ice_mixing_ratio = 0.0;
+            }
+        }
+
+        result.push(state);
+    }
+
+    Ok(result)
+}
+
+fn step(
+    state: ParcelState,
+    next_pressure: Float,
+    freezing_range: Option<[Float; 2]>,
+    evaporate: bool,
+) -> Result<ParcelState, InputError> {
+    let total_mixing_ratio =
+        state.vapour_mixing_ratio + state.liquid_mixing_ratio + state.ice_mixing_ratio;
+    let log_pressure_change = (next_pressure / state.pressure).ln();
+
+    let residual = |next_temperature: Float| -> Result<(Float, [Float; 3]), InputError> {
+        let water = if evaporate {
+            //evaporated condensate is accounted as negative condensate formed during the step,
+            //vapour is capped at the valid mixing ratio range to keep the residual finite
+            //when bisection tries temperatures at which saturation vapour pressure exceeds pressure
+            let vapour = saturation_mixing_ratio(next_temperature, next_pressure, freezing_range)?
+                .min(MAX_MIXING_RATIO);
+            let evaporated = vapour - state.vapour_mixing_ratio;
+            let ice_fraction = ice_fraction(next_temperature, freezing_range);
+
+            [
+                vapour,
+                -(1.0 - ice_fraction) * evaporated,
+                -ice_fraction * evaporated,
+            ]
+        } else {
+            equilibrium(
+                next_temperature,
+                next_pressure,
+                total_mixing_ratio,
+                freezing_range,
+            )?
+        };
+
+        let heat_capacity = C_P
+            + 0.5
+                * ((state.vapour_mixing_ratio + water[0]) * C_PV
+                    + (state.liquid_mixing_ratio + water[1]) * C_L
+                    + (state.ice_mixing_ratio + water[2]) * C_S);
+        let gas_constant = R_D + 0.5 * (state.vapour_mixing_ratio + water[0]) * R_V;
+        let mean_temperature = 0.5 * (state.temperature + next_temperature);
+
+        let result = heat_capacity * (next_temperature - state.temperature)
+            - gas_constant * mean_temperature * log_pressure_change
+            + latent_heat::vaporisation1(mean_temperature)?
+                * (water[0] - state.vapour_mixing_ratio)
+            - latent_heat::fusion1(mean_temperature)? * (water[2] - state.ice_mixing_ratio);
+
+        Ok((result, water))
+    };
+
+    //residual increases monotonically with temperature so bisection always converges
+    let mut lower = (state.temperature - 10.0).max(min_temperature(freezing_range));
+    let mut upper = (state.temperature + 10.0).min(324.0);
+
+    if residual(lower)?.0 > 0.0 || residual(upper)?.0 < 0.0 {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "parcel temperature left the valid range during integration",
+        )));
+    }
+
+    for _ in 0..30 {
+        let middle = 0.5 * (lower + upper);
+
+        if residual(middle)?.0 > 0.0 {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+
+    let temperature = 0.5 * (lower + upper);
+    let [vapour, liquid, ice] = residual(temperature)?.1;
+
+    Ok(ParcelState {
+        pressure: next_pressure,
+        temperature,
+        vapour_mixing_ratio: vapour,
+        liquid_mixing_ratio: liquid,
+        ice_mixing_ratio: ice,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constants::{C_P, C_PV, R_D, R_V},
+        equivalent_potential_temperature,
+        errors::InputError,
+        moist_adiabat, Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn reversible1_dry() {
+        let total_mixing_ratio = 0.001;
+        let levels = [90000.0, 80000.0];
+
+        let result =
+            moist_adiabat::reversible1(300.0, 100_000.0, total_mixing_ratio, &levels, None)
+                .unwrap();
+
+        let exponent = (R_D + total_mixing_ratio * R_V) / (C_P + total_mixing_ratio * C_PV);
+
+        for (state, level) in result.iter().zip(levels) {
+            let expected = 300.0 * (level / 100_000.0 as Float).powf(exponent);
+            assert_approx_eq!(Float, state.temperature, expected, epsilon = 0.01);
+            assert_approx_eq!(Float, state.vapour_mixing_ratio, total_mixing_ratio);
+            assert_approx_eq!(Float, state.liquid_mixing_ratio, 0.0);
+        }
+    }
+
+    #[test]
+    fn reversible1_saturated() {
+        let levels = [90000.0, 70000.0, 50000.0, 30000.0];
+
+        let result =
+            moist_adiabat::reversible1(300.0, 100_000.0, 0.025, &levels, Some([273.15, 263.15]))
+                .unwrap();
+
+        let mut previous_temperature = 300.0;
+
+        for state in &result {
+            let total =
+                state.vapour_mixing_ratio + state.liquid_mixing_ratio + state.ice_mixing_ratio;
+            assert_approx_eq!(Float, total, 0.025, epsilon = 0.000_01);
+            assert!(state.temperature < previous_temperature);
+            assert!(state.liquid_mixing_ratio > 0.0 || state.ice_mixing_ratio > 0.0);
+            previous_temperature = state.temperature;
+        }
+
+        assert_approx_eq!(Float, result[0].ice_mixing_ratio, 0.0);
+        assert_approx_eq!(Float, result[3].liquid_mixing_ratio, 0.0);
+        assert_approx_eq!(Float, result[2].temperature, 275.98, epsilon = 0.05);
+    }
+
+    #[test]
+    fn reversible1_reversibility() {
+        let result = moist_adiabat::reversible1(
+            295.0,
+            100_000.0,
+            0.02,
+            &[100_000.0, 40000.0, 100_000.0],
+            Some([268.0, 238.0]),
+        )
+        .unwrap();
+
+        assert!(result[1].ice_mixing_ratio > 0.0);
+        assert_approx_eq!(
+            Float,
+            result[2].temperature,
+            result[0].temperature,
+            epsilon = 0.01
+        );
+        assert_approx_eq!(
+            Float,
+            result[2].liquid_mixing_ratio,
+            result[0].liquid_mixing_ratio,
+            epsilon = 0.000_01
+        );
+        assert_approx_eq!(Float, result[2].ice_mixing_ratio, 0.0);
+    }
+
+    #[test]
+    fn pseudo1() {
+        let levels = [100_000.0, 85000.0, 70000.0, 50000.0, 30000.0];
+
+        let pseudo = moist_adiabat::pseudo1(300.0, 100_000.0, 0.05, &levels, None).unwrap();
+        let reversible = moist_adiabat::reversible1(
+            300.0,
+            100_000.0,
+            pseudo[0].vapour_mixing_ratio,
+            &levels,
+            None,
+        )
+        .unwrap();
+
+        for state in &pseudo {
+            assert_approx_eq!(Float, state.liquid_mixing_ratio, 0.0);
+            assert_approx_eq!(Float, state.ice_mixing_ratio, 0.0);
+        }
+
+        //condensate carried by reversible parcel warms it in upper troposphere
+        assert!(pseudo[4].temperature < reversible[4].temperature);
+
+        //equivalent potential temperature is conserved along pseudo-adiabat
+        let start = equivalent_potential_temperature::bolton1(100_000.0, 300.0, 300.0).unwrap();
+        let end = pseudo[3];
+        let end = equivalent_potential_temperature::bolton1(
+            end.pressure,
+            end.temperature,
+            end.temperature,
+        )
+        .unwrap();
+
+        assert_approx_eq!(Float, start, end, epsilon = 1.0);
+    }
+
+    #[test]
+    fn pseudo1_descent() {
+        let ascent = moist_adiabat::pseudo1(300.0, 100_000.0, 0.05, &[50000.0], None).unwrap()[0];
+        let descent = moist_adiabat::pseudo1(
+            ascent.temperature,
+            ascent.pressure,
+            ascent.vapour_mixing_ratio,
+            &[70000.0],
+            None,
+        )
+        .unwrap()[0];
+
+        //parcel without condensate warms dry-adiabatically and conserves its mixing ratio
+        assert_approx_eq!(
+            Float,
+            descent.vapour_mixing_ratio,
+            ascent.vapour_mixing_ratio,
+            epsilon = 0.000_001
+        );
+        assert!(descent.temperature > ascent.temperature + 25.0);
+    }
+
+    #[test]
+    fn saturated_descent1() {
+        let ascent = moist_adiabat::pseudo1(300.0, 100_000.0, 0.05, &[50000.0], None).unwrap()[0];
+        let descent = moist_adiabat::saturated_descent1(
+            ascent.temperature,
+            ascent.pressure,
+            &[70000.0, 100_000.0],
+            None,
+        )
+        .unwrap();
+
+        //saturated parcel returns along the same pseudo-adiabat
+        assert_approx_eq!(Float, descent[1].temperature, 300.0, epsilon = 0.05);
+
+        //and remains saturated during descent
+        let saturated =
+            moist_adiabat::reversible1(descent[0].temperature, 70000.0, 0.1, &[70000.0], None)
+                .unwrap()[0];
+        assert_approx_eq!(
+            Float,
+            descent[0].vapour_mixing_ratio,
+            saturated.vapour_mixing_ratio,
+            epsilon = 0.000_01
+        );
+
+        assert!(matches!(
+            moist_adiabat::saturated_descent1(280.0, 70000.0, &[50000.0], None),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+
+    #[test]
+    fn reversible1_errors() {
+        let levels = [50000.0];
+
+        assert_eq!(
+            moist_adiabat::reversible1(220.0, 100_000.0, 0.01, &levels, None).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+        assert_eq!(
+            moist_adiabat::reversible1(300.0, 50.0, 0.01, &levels, None).unwrap_err(),
+            InputError::OutOfRange(String::from("pressure"))
+        );
+        assert_eq!(
+            moist_adiabat::reversible1(300.0, 100_000.0, 0.2, &levels, None).unwrap_err(),
+            InputError::OutOfRange(String::from("total_mixing_ratio"))
+        );
+        assert_eq!(
+            moist_adiabat::reversible1(300.0, 100_000.0, 0.01, &[50.0], None).unwrap_err(),
+            InputError::OutOfRange(String::from("pressure_levels"))
+        );
+        assert_eq!(
+            moist_adiabat::reversible1(300.0, 100_000.0, 0.01, &levels, Some([280.0, 250.0]))
+                .unwrap_err(),
+            InputError::OutOfRange(String::from("freezing_range"))
+        );
+        assert!(matches!(
+            moist_adiabat::reversible1(300.0, 100_000.0, 0.01, &levels, Some([250.0, 270.0])),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert!(matches!(
+            moist_adiabat::reversible1(300.0, 100_000.0, 0.025, &[10000.0], None),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+}
@@ -0,0 +1,355 @@
+//!Functions to calculate mean sea level pressure (MSLP) in Pa by reduction of station pressure.
+//!
+//!Reduction methods differ in the assumed temperature of the fictitious air column
+//!between the station and mean sea level. Methods taking vapour pressure
+//!account for humidity with virtual temperature ([`virtual_temperature::general2`]).
+//!
+//!None of the methods is reliable for stations at high elevations, where the result
+//!depends strongly on the assumed column temperature.
+
+use crate::Float;
+use crate::{
+    constants::{G, R_D},
+    errors::InputError,
+    virtual_temperature,
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+//lapse rate of the standard atmosphere
+const LAPSE_RATE: Float = 0.0065;
+
+///Formula for computing mean sea level pressure from station pressure, elevation, temperature
+///and vapour pressure recommended by the WMO for low-level stations.
+///
+///Humidity is accounted for with empirical correction of 0.12K*hPa^-1 of vapour pressure.
+///
+///Provided in WMO (2018). Guide to Instruments and Methods of Observation, WMO-No. 8, Volume I, Chapter 3.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `station_pressure` range: 50000Pa - 110000Pa\
+///Valid `elevation` range: -500m - 5000m\
+///Valid `temperature` range: 213K - 333K\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn wmo1(
+    station_pressure: Float,
+    elevation: Float,
+    temperature: Float,
+    vapour_pressure: Float,
+) -> Result<Float, InputError> {
+    validate_inputs(station_pressure, elevation, temperature, vapour_pressure)?;
+
+    let column_temperature =
+        temperature + 0.5 * LAPSE_RATE * elevation + 0.12 * (vapour_pressure / 100.0);
+
+    let result = station_pressure * (G * elevation / (R_D * column_temperature)).exp();
+
+    Ok(result)
+}
+
+///Formula for computing mean sea level pressure from station pressure, elevation, current temperature,
+///temperature 12 hours earlier and vapour pressure with the hypsometric equation.
+///
+///Column temperature is the mean of the two temperatures, increased by the standard lapse rate
+///to the middle of the column and converted to virtual temperature.
+///Averaging over 12 hours reduces the influence of the diurnal cycle.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `station_pressure` range: 50000Pa - 110000Pa\
+///Valid `elevation` range: -500m - 5000m\
+///Valid `temperature` range: 213K - 333K\
+///Valid `temperature_12h` range: 213K - 333K\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn hypsometric1(
+    station_pressure: Float,
+    elevation: Float,
+    temperature: Float,
+    temperature_12h: Float,
+    vapour_pressure: Float,
+) -> Result<Float, InputError> {
+    validate_inputs(station_pressure, elevation, temperature, vapour_pressure)?;
+
+    if !(213.0..=333.0).contains(&temperature_12h) {
+        return Err(InputError::OutOfRange(String::from("temperature_12h")));
+    }
+
+    let column_temperature = 0.5 * (temperature + temperature_12h) + 0.5 * LAPSE_RATE * elevation;
+    let column_temperature =
+        virtual_temperature::general2(column_temperature, station_pressure, vapour_pressure)?;
+
+    let result = station_pressure * (G * elevation / (R_D * column_temperature)).exp();
+
+    Ok(result)
+}
+
+///Formula for computing mean sea level pressure from station pressure, elevation, temperature
+///and vapour pressure with the Shuell method.
+///
+///Sea level temperature is extrapolated from station virtual temperature with the standard lapse rate,
+///but is limited to avoid excessive pressure reduction at warm, high stations.
+///
+///Described in National Weather Service (1994). Mean sea level pressure reduction, NWS Technical Procedures Bulletin.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `station_pressure` range: 50000Pa - 110000Pa\
+///Valid `elevation` range: -500m - 5000m\
+///Valid `temperature` range: 213K - 333K\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn shuell1(
+    station_pressure: Float,
+    elevation: Float,
+    temperature: Float,
+    vapour_pressure: Float,
+) -> Result<Float, InputError> {
+    validate_inputs(station_pressure, elevation, temperature, vapour_pressure)?;
+
+    let station_temperature =
+        virtual_temperature::general2(temperature, station_pressure, vapour_pressure)?;
+
+    let mut sea_level_temperature = station_temperature + LAPSE_RATE * elevation;
+
+    if station_temperature > 290.66 {
+        sea_level_temperature = 290.66 - 0.005 * (station_temperature - 290.66).powi(2);
+    } else if sea_level_temperature > 290.66 {
+        sea_level_temperature = 290.66;
+    }
+
+    let column_temperature = 0.5 * (station_temperature + sea_level_temperature);
+
+    let result = station_pressure * (G * elevation / (R_D * column_temperature)).exp();
+
+    Ok(result)
+}
+
+///Formula for computing mean sea level pressure from station pressure, elevation, temperature
+///and vapour pressure with the method used in the ECMWF Integrated Forecasting System.
+///
+///Surface temperature is extrapolated with the standard lapse rate, which is reduced
+///when sea level temperature would exceed 290.5K, and very cold surface temperatures are raised
+///towards 255K. The original method uses temperature, here virtual temperature is used instead
+///to account for humidity.
+///
+///Derived by K. E. Trenberth et al. (1993) [(doi:10.5065/D6HX19NH)](https://doi.org/10.5065/D6HX19NH)
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `station_pressure` range: 50000Pa - 110000Pa\
+///Valid `elevation` range: -500m - 5000m\
+///Valid `temperature` range: 213K - 333K\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn ifs1(
+    station_pressure: Float,
+    elevation: Float,
+    temperature: Float,
+    vapour_pressure: Float,
+) -> Result<Float, InputError> {
+    validate_inputs(station_pressure, elevation, temperature, vapour_pressure)?;
+
+    let mut surface_temperature =
+        virtual_temperature::general2(temperature, station_pressure, vapour_pressure)?;
+
+    if surface_temperature < 255.0 {
+        surface_temperature = 0.5 * (surface_temperature + 255.0);
+    }
+
+    let sea_level_temperature = surface_temperature + LAPSE_RATE * elevation;
+    let mut lapse_rate = LAPSE_RATE;
+
+    if sea_level_temperature > 290.5 {
+        if surface_temperature <= 290.5 {
+            lapse_rate = (290.5 - surface_temperature) / elevation;
+        } else {
+            lapse_rate = 0.0;
+            surface_temperature = 0.5 * (290.5 + surface_temperature);
+        }
+    }
+
+    let x = lapse_rate * elevation / surface_temperature;
+
+    let result = station_pressure
+        * ((G * elevation / (R_D * surface_temperature)) * (1.0 - x / 2.0 + x.powi(2) / 3.0)).exp();
+
+    Ok(result)
+}
+
+fn validate_inputs(
+    station_pressure: Float,
+    elevation: Float,
+    temperature: Float,
+    vapour_pressure: Float,
+) -> Result<(), InputError> {
+    if !(50_000.0..=110_000.0).contains(&station_pressure) {
+        return Err(InputError::OutOfRange(String::from("station_pressure")));
+    }
+
+    if !(-500.0..=5000.0).contains(&elevation) {
+        return Err(InputError::OutOfRange(String::from("elevation")));
+    }
+
+    if !(213.0..=333.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+        return Err(InputError::OutOfRange(String::from("vapour_pressure")));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use crate::{
+        mslp,
+        tests_framework::{self, Argument},
+    };
+
+    const STATION_PRESSURE: Argument = Argument {
+        name: "station_pressure",
+        def_val: 95000.0,
+        range: [50000.0, 110_000.0],
+    };
+
+    const ELEVATION: Argument = Argument {
+        name: "elevation",
+        def_val: 500.0,
+        range: [-500.0, 5000.0],
+    };
+
+    const TEMPERATURE: Argument = Argument {
+        name: "temperature",
+        def_val: 288.15,
+        range: [213.0, 333.0],
+    };
+
+    #[test]
+    fn wmo1() {
+        assert!(tests_framework::test_with_3args(
+            &|station_pressure, elevation, temperature| mslp::wmo1(
+                station_pressure,
+                elevation,
+                temperature,
+                1000.0
+            ),
+            STATION_PRESSURE,
+            ELEVATION,
+            TEMPERATURE,
+            100_743.791
+        ));
+
+        assert!(tests_framework::test_with_1arg(
+            &|vapour_pressure| mslp::wmo1(95000.0, 500.0, 288.15, vapour_pressure),
+            Argument {
+                name: "vapour_pressure",
+                def_val: 1000.0,
+                range: [0.0, 10000.0]
+            },
+            100_743.791
+        ));
+    }
+
+    #[test]
+    fn hypsometric1() {
+        assert!(tests_framework::test_with_3args(
+            &|station_pressure, elevation, temperature| mslp::hypsometric1(
+                station_pressure,
+                elevation,
+                temperature,
+                286.15,
+                1000.0
+            ),
+            STATION_PRESSURE,
+            ELEVATION,
+            TEMPERATURE,
+            100_765.135
+        ));
+
+        assert!(tests_framework::test_with_2args(
+            &|temperature_12h, vapour_pressure| mslp::hypsometric1(
+                95000.0,
+                500.0,
+                288.15,
+                temperature_12h,
+                vapour_pressure
+            ),
+            Argument {
+                name: "temperature_12h",
+                def_val: 286.15,
+                range: [213.0, 333.0]
+            },
+            Argument {
+                name: "vapour_pressure",
+                def_val: 1000.0,
+                range: [0.0, 10000.0]
+            },
+            100_765.135
+        ));
+    }
+
+    #[test]
+    fn shuell1() {
+        assert!(tests_framework::test_with_3args(
+            &|station_pressure, elevation, temperature| mslp::shuell1(
+                station_pressure,
+                elevation,
+                temperature,
+                1000.0
+            ),
+            STATION_PRESSURE,
+            ELEVATION,
+            TEMPERATURE,
+            100_764.074
+        ));
+
+        //sea level temperature is limited for warm stations
+        let warm = mslp::shuell1(85000.0, 1500.0, 310.0, 1000.0).unwrap();
+        let wmo = mslp::wmo1(85000.0, 1500.0, 310.0, 1000.0).unwrap();
+        assert!(warm > wmo);
+    }
+
+    #[test]
+    fn ifs1() {
+        assert!(tests_framework::test_with_3args(
+            &|station_pressure, elevation, temperature| mslp::ifs1(
+                station_pressure,
+                elevation,
+                temperature,
+                1000.0
+            ),
+            STATION_PRESSURE,
+            ELEVATION,
+            TEMPERATURE,
+            100_765.725
+        ));
+
+        assert!(tests_framework::test_with_1arg(
+            &|vapour_pressure| mslp::ifs1(95000.0, 500.0, 288.15, vapour_pressure),
+            Argument {
+                name: "vapour_pressure",
+                def_val: 1000.0,
+                range: [0.0, 10000.0]
+            },
+            100_765.725
+        ));
+    }
+
+    #[test]
+    fn sea_level_station() {
+        for method in [mslp::wmo1 as fn(_, _, _, _) -> _, mslp::shuell1, mslp::ifs1] {
+            assert_eq!(method(101_000.0, 0.0, 288.15, 1000.0), Ok(101_000.0));
+        }
+    }
+}
@@ -0,0 +1,614 @@
+//!Functions to calculate properties of air parcel lifted through a vertical profile.
+//!
+//!The parcel is lifted dry-adiabatically up to its lifting condensation level (LCL)
+//!and pseudo-adiabatically (see [`moist_adiabat::pseudo1`]) above it.
+//!
+//!Profiles are provided as slices of values at consecutive levels, ordered from the lowest level up
+//!(with strictly decreasing pressure).
+
+use crate::Float;
+use crate::{
+    constants::{C_P, EPSILON, G, R_D},
+    equivalent_potential_temperature,
+    errors::InputError,
+    mixing_ratio,
+    moist_adiabat::{self, ParcelState},
+    potential_temperature, vapour_pressure, virtual_temperature,
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Method of choosing initial properties of lifted parcel.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ParcelKind {
+    ///Parcel with properties of the lowest level of the profile.
+    SurfaceBased,
+    ///Parcel starting at the lowest level of the profile with mean potential temperature
+    ///and mixing ratio of the layer of given depth (in Pa) above that level.
+    MixedLayer(Float),
+    ///Parcel starting at the level with the highest equivalent potential temperature
+    ///within the layer of given depth (in Pa) above the lowest level of the profile.
+    MostUnstable(Float),
+}
+
+///Properties of lifted parcel at a single level of the profile.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ParcelLevel {
+    ///Pressure in `Pa`
+    pub pressure: Float,
+    ///Parcel temperature in `K`
+    pub temperature: Float,
+    ///Parcel virtual temperature in `K`
+    pub virtual_temperature: Float,
+    ///Environmental virtual temperature in `K`
+    pub environment_virtual_temperature: Float,
+    ///Parcel buoyancy relative to the environment in `m*s^-2`
+    pub buoyancy: Float,
+}
+
+///Air parcel lifted through the profile with [`profile1`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParcelProfile {
+    ///Index of the profile level from which the parcel is lifted
+    pub start_index: usize,
+    ///Pressure in `Pa` and temperature in `K` of the parcel's LCL
+    pub lifting_condensation_level: [Float; 2],
+    ///Parcel properties at levels of the profile from `start_index` up
+    pub levels: Vec<ParcelLevel>,
+}
+
+///Formula for computing pressure and temperature of lifting condensation level
+///from temperature, pressure and mixing ratio of air parcel.
+///Returns `[pressure, temperature]` of the LCL.
+///
+///Temperature of the LCL is computed with formula derived by D. Bolton (1980)
+///[(doi:10.1175/1520-0493(1980)108<1046:TCOEPT>2.0.CO;2)](https://doi.org/10.1175/1520-0493(1980)108%3C1046:TCOEPT%3E2.0.CO;2)
+///and its pressure from conservation of potential temperature ([`potential_temperature::davies_jones1`]).
+///For saturated parcel the LCL is at its initial pressure.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 253K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `mixing_ratio` range: 0.000001 - 0.1
+#[cfg_attr(feature = "debug", logerr)]
+pub fn lifting_condensation_level1(
+    temperature: Float,
+    pressure: Float,
+    mixing_ratio: Float,
+) -> Result<[Float; 2], InputError> {
+    if !(253.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(100.0..=150_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    if !(0.000_001..=0.1).contains(&mixing_ratio) {
+        return Err(InputError::OutOfRange(String::from("mixing_ratio")));
+    }
+
+    let kappa = R_D / C_P;
+
+    let vapour_pressure = vapour_pressure::general2(mixing_ratio, pressure)?;
+    let potential_temperature =
+        potential_temperature::davies_jones1(temperature, pressure, vapour_pressure)?;
+
+    let lcl_temperature =
+        2840.0 / (3.5 * temperature.ln() - (vapour_pressure / 100.0).ln() - 4.805) + 55.0;
+    let lcl_temperature = lcl_temperature.min(temperature);
+
+    let lcl_pressure = 100_000.0
+        * (lcl_temperature / potential_temperature).powf(1.0 / kappa)
+        * ((EPSILON + mixing_ratio) / EPSILON);
+
+    Ok([lcl_pressure.min(pressure), lcl_temperature])
+}
+
+///Formula for computing state of air parcel lifted from its initial temperature,
+///pressure and mixing ratio to each of `pressure_levels`.
+///
+///Below the LCL ([`lifting_condensation_level1`]) parcel conserves its potential temperature
+///([`potential_temperature::davies_jones1`]) and mixing ratio. Above the LCL parcel follows
+///pseudo-adiabat ([`moist_adiabat::pseudo1`]) with provided `freezing_range`.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 253K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `mixing_ratio` range: 0.000001 - 0.1\
+///Valid `pressure_levels` range: 100Pa - 150000Pa\
+///Valid `freezing_range` range: 232K - 274K
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `pressure_levels` are higher than `pressure`,
+///when the start of `freezing_range` is not higher than its end,
+///or when parcel temperature leaves the valid range of [`moist_adiabat::pseudo1`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn ascent1(
+    temperature: Float,
+    pressure: Float,
+    mixing_ratio: Float,
+    pressure_levels: &[Float],
+    freezing_range: Option<[Float; 2]>,
+) -> Result<Vec<ParcelState>, InputError> {
+    let [lcl_pressure, lcl_temperature] =
+        lifting_condensation_level1(temperature, pressure, mixing_ratio)?;
+
+    if pressure_levels
+        .iter()
+        .any(|p| !(100.0..=150_000.0).contains(p))
+    {
+        return Err(InputError::OutOfRange(String::from("pressure_levels")));
+    }
+
+    if pressure_levels.iter().any(|p| *p > pressure) {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "pressure_levels cannot be higher than pressure",
+        )));
+    }
+
+    let kappa = R_D / C_P;
+
+    let vapour_pressure = vapour_pressure::general2(mixing_ratio, pressure)?;
+    let potential_temperature =
+        potential_temperature::davies_jones1(temperature, pressure, vapour_pressure)?;
+
+    let moist_levels: Vec<Float> = pressure_levels
+        .iter()
+        .copied()
+        .filter(|p| *p < lcl_pressure)
+        .collect();
+
+    let mut moist_states = moist_adiabat::pseudo1(
+        lcl_temperature,
+        lcl_pressure,
+        mixing_ratio,
+        &moist_levels,
+        freezing_range,
+    )?
+    .into_iter();
+
+    pressure_levels
+        .iter()
+        .map(|p| {
+            if *p >= lcl_pressure {
+                Ok(ParcelState {
+                    pressure: *p,
+                    temperature: potential_temperature
+                        * ((p * EPSILON / (EPSILON + mixing_ratio)) / 100_000.0).powf(kappa),
+                    vapour_mixing_ratio: mixing_ratio,
+                    liquid_mixing_ratio: 0.0,
+                    ice_mixing_ratio: 0.0,
+                })
+            } else {
+                //moist states are returned in the order of pressure_levels
+                moist_states.next().ok_or_else(|| {
+                    InputError::IncorrectArgumentSet(String::from(
+                        "parcel state above the LCL is missing",
+                    ))
+                })
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+///Formula for computing temperature, virtual temperature and buoyancy of air parcel
+///lifted through the profile with [`ascent1`].
+///
+///Initial parcel is chosen according to `parcel_kind`. Parcel properties are returned
+///at all levels of the profile from the parcel's initial level up, together with index
+///of that level and the parcel's LCL ([`lifting_condensation_level1`]).
+///Buoyancy is computed from parcel and environmental virtual temperature
+///([`virtual_temperature::general1`] and [`virtual_temperature::general2`]).
+///
+///Environmental vapour pressure is computed from dewpoint with [`vapour_pressure::buck1`],
+///below its valid range dewpoint is treated as frost point and [`vapour_pressure::buck2`] is used.
+///For [`ParcelKind::MostUnstable`] levels outside of the valid range of
+///[`equivalent_potential_temperature::bolton1`] are not considered as parcel origin.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `temperature` range: 193K - 324K\
+///Valid `dewpoint` range: 193K - 324K\
+///Valid `parcel_kind` depth range: 0Pa - 50000Pa\
+///Valid `freezing_range` range: 232K - 274K\
+///Initial parcel must also be within the valid range of [`ascent1`].
+///
+///Returns [`InputError::IncorrectArgumentSet`] when profiles have different lengths or less than two levels,
+///when `pressure` is not strictly decreasing, when `dewpoint` is higher than `temperature`,
+///when no level of [`ParcelKind::MostUnstable`] layer is within the valid range of
+///[`equivalent_potential_temperature::bolton1`] or when [`ascent1`] fails to lift the parcel.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn profile1(
+    pressure: &[Float],
+    temperature: &[Float],
+    dewpoint: &[Float],
+    parcel_kind: ParcelKind,
+    freezing_range: Option<[Float; 2]>,
+) -> Result<ParcelProfile, InputError> {
+    validate_profile(pressure, temperature, dewpoint)?;
+
+    let (start_index, start_temperature, start_mixing_ratio) =
+        initial_parcel(pressure, temperature, dewpoint, parcel_kind)?;
+
+    let lifting_condensation_level =
+        lifting_condensation_level1(start_temperature, pressure[start_index], start_mixing_ratio)?;
+
+    let levels = &pressure[start_index..];
+    let states = ascent1(
+        start_temperature,
+        levels[0],
+        start_mixing_ratio,
+        levels,
+        freezing_range,
+    )?;
+
+    let levels = states
+        .iter()
+        .enumerate()
+        .map(|(i, state)| {
+            let i = i + start_index;
+
+            let parcel_virtual_temperature =
+                virtual_temperature::general1(state.temperature, state.vapour_mixing_ratio)?;
+
+            let environment_vapour_pressure =
+                environment_vapour_pressure(dewpoint[i], pressure[i])?;
+            let environment_virtual_temperature = virtual_temperature::general2(
+                temperature[i],
+                pressure[i],
+                environment_vapour_pressure,
+            )?;
+
+            Ok(ParcelLevel {
+                pressure: state.pressure,
+                temperature: state.temperature,
+                virtual_temperature: parcel_virtual_temperature,
+                environment_virtual_temperature,
+                buoyancy: G * (parcel_virtual_temperature - environment_virtual_temperature)
+                    / environment_virtual_temperature,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ParcelProfile {
+        start_index,
+        lifting_condensation_level,
+        levels,
+    })
+}
+
+pub(crate) fn validate_profile(
+    pressure: &[Float],
+    temperature: &[Float],
+    dewpoint: &[Float],
+) -> Result<(), InputError> {
+    if pressure.len() != temperature.len() || pressure.len() != dewpoint.len() {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "pressure, temperature and dewpoint must have the same length",
+        )));
+    }
+
+    if pressure.len() < 2 {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "profile must contain at least two levels",
+        )));
+    }
+
+    if pressure.iter().any(|p| !(100.0..=150_000.0).contains(p)) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    if temperature.iter().any(|t| !(193.0..=324.0).contains(t)) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if dewpoint.iter().any(|t| !(193.0..=324.0).contains(t)) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    if pressure.windows(2).any(|p| p[1] >= p[0]) {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "pressure must be strictly decreasing",
+        )));
+    }
+
+    if dewpoint.iter().zip(temperature).any(|(d, t)| d > t) {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "dewpoint cannot be higher than temperature",
+        )));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn environment_vapour_pressure(
+    dewpoint: Float,
+    pressure: Float,
+) -> Result<Float, InputError> {
+    if dewpoint >= 232.0 {
+        vapour_pressure::buck1(dewpoint, pressure)
+    } else {
+        vapour_pressure::buck2(dewpoint, pressure)
+    }
+}
+
+fn mixing_ratio_at(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
+    let vapour_pressure = environment_vapour_pressure(dewpoint, pressure)?;
+    mixing_ratio::general1(pressure, vapour_pressure)
+}
+
+//returns index of initial level, initial temperature and mixing ratio
+pub(crate) fn initial_parcel(
+    pressure: &[Float],
+    temperature: &[Float],
+    dewpoint: &[Float],
+    parcel_kind: ParcelKind,
+) -> Result<(usize, Float, Float), InputError> {
+    match parcel_kind {
+        ParcelKind::SurfaceBased => Ok((
+            0,
+            temperature[0],
+            mixing_ratio_at(dewpoint[0], pressure[0])?,
+        )),
+        ParcelKind::MixedLayer(depth) => {
+            if !(0.0..=50_000.0).contains(&depth) {
+                return Err(InputError::OutOfRange(String::from("parcel_kind")));
+            }
+
+            let kappa = R_D / C_P;
+            let top = pressure[0] - depth;
+
+            let mut potential_temperatures = vec![];
+            let mut mixing_ratios = vec![];
+
+            for i in 0..pressure.len() {
+                if pressure[i] < top {
+                    break;
+                }
+
+                let vapour_pressure = environment_vapour_pressure(dewpoint[i], pressure[i])?;
+                let mixing_ratio = mixing_ratio::general1(pressure[i], vapour_pressure)?;
+
+                potential_temperatures.push(potential_temperature::davies_jones1(
+                    temperature[i],
+                    pressure[i],
+                    vapour_pressure,
+                )?);
+                mixing_ratios.push(mixing_ratio);
+            }
+
+            let levels = &pressure[..mixing_ratios.len()];
+            let mean_potential_temperature = layer_mean(levels, &potential_temperatures);
+            let mean_mixing_ratio = layer_mean(levels, &mixing_ratios);
+
+            let start_temperature = mean_potential_temperature
+                * ((pressure[0] * EPSILON / (EPSILON + mean_mixing_ratio)) / 100_000.0).powf(kappa);
+
+            Ok((0, start_temperature, mean_mixing_ratio))
+        }
+        ParcelKind::MostUnstable(depth) => {
+            if !(0.0..=50_000.0).contains(&depth) {
+                return Err(InputError::OutOfRange(String::from("parcel_kind")));
+            }
+
+            let top = pressure[0] - depth;
+
+            let mut max_index = None;
+            let mut max_theta_e = Float::NEG_INFINITY;
+
+            for i in 0..pressure.len() {
+                if pressure[i] < top {
+                    break;
+                }
+
+                if let Ok(theta_e) = equivalent_potential_temperature::bolton1(
+                    pressure[i],
+                    temperature[i],
+                    dewpoint[i],
+                ) {
+                    if theta_e > max_theta_e {
+                        max_index = Some(i);
+                        max_theta_e = theta_e;
+                    }
+                }
+            }
+
+            let max_index = max_index.ok_or_else(|| {
+                InputError::IncorrectArgumentSet(String::from(
+                    "no level within parcel_kind depth is in the valid range of bolton1",
+                ))
+            })?;
+
+            Ok((
+                max_index,
+                temperature[max_index],
+                mixing_ratio_at(dewpoint[max_index], pressure[max_index])?,
+            ))
+        }
+    }
+}
+
+//pressure-weighted mean of a quantity over the layer
+fn layer_mean(pressure: &[Float], values: &[Float]) -> Float {
+    if pressure.len() < 2 {
+        return values[0];
+    }
+
+    let integral: Float = pressure
+        .windows(2)
+        .zip(values.windows(2))
+        .map(|(p, v)| 0.5 * (v[0] + v[1]) * (p[0] - p[1]))
+        .sum();
+
+    integral / (pressure[0] - pressure[pressure.len() - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::InputError,
+        parcel::{self, ParcelKind},
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    const PRESSURE: [Float; 8] = [
+        100_000.0, 92500.0, 85000.0, 70000.0, 50000.0, 40000.0, 30000.0, 25000.0,
+    ];
+    const TEMPERATURE: [Float; 8] = [303.0, 297.0, 292.0, 282.0, 266.0, 255.0, 241.0, 231.0];
+    const DEWPOINT: [Float; 8] = [295.0, 292.0, 285.0, 272.0, 250.0, 235.0, 220.0, 210.0];
+
+    #[test]
+    fn lifting_condensation_level1() {
+        let [pressure, temperature] =
+            parcel::lifting_condensation_level1(300.0, 100_000.0, 0.0128).unwrap();
+
+        assert_approx_eq!(Float, temperature, 288.66, epsilon = 0.1);
+        assert_approx_eq!(Float, pressure, 87385.0, epsilon = 10.0);
+
+        assert_eq!(
+            parcel::lifting_condensation_level1(200.0, 100_000.0, 0.01).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+    }
+
+    #[test]
+    fn ascent1() {
+        let levels = [100_000.0, 90000.0, 70000.0, 50000.0];
+        let states = parcel::ascent1(300.0, 100_000.0, 0.0128, &levels, None).unwrap();
+
+        assert_approx_eq!(Float, states[0].temperature, 300.0, epsilon = 0.01);
+        assert_approx_eq!(Float, states[1].vapour_mixing_ratio, 0.0128);
+
+        for state in &states[2..] {
+            assert!(state.vapour_mixing_ratio < 0.0128);
+        }
+
+        assert!(matches!(
+            parcel::ascent1(300.0, 90000.0, 0.0128, &levels, None),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+
+    #[test]
+    fn profile1() {
+        let surface = parcel::profile1(
+            &PRESSURE,
+            &TEMPERATURE,
+            &DEWPOINT,
+            ParcelKind::SurfaceBased,
+            Some([273.15, 250.15]),
+        )
+        .unwrap();
+
+        assert_eq!(surface.start_index, 0);
+        assert_eq!(surface.levels.len(), PRESSURE.len());
+        assert_approx_eq!(Float, surface.levels[0].temperature, 303.0, epsilon = 0.01);
+        assert!(surface.levels[0].buoyancy.abs() < 0.001);
+        assert!(surface.levels[4].buoyancy > 0.0);
+
+        let [lcl_pressure, lcl_temperature] = surface.lifting_condensation_level;
+        assert!(lcl_pressure < PRESSURE[0] && lcl_pressure > PRESSURE[3]);
+        assert!(lcl_temperature < TEMPERATURE[0] && lcl_temperature > DEWPOINT[3]);
+
+        let mixed_layer = parcel::profile1(
+            &PRESSURE,
+            &TEMPERATURE,
+            &DEWPOINT,
+            ParcelKind::MixedLayer(10000.0),
+            Some([273.15, 250.15]),
+        )
+        .unwrap();
+
+        assert!(mixed_layer.levels[4].temperature < surface.levels[4].temperature);
+
+        let most_unstable = parcel::profile1(
+            &PRESSURE,
+            &TEMPERATURE,
+            &DEWPOINT,
+            ParcelKind::MostUnstable(30000.0),
+            Some([273.15, 250.15]),
+        )
+        .unwrap();
+
+        assert_eq!(most_unstable, surface);
+    }
+
+    #[test]
+    fn profile1_most_unstable() {
+        //surface dewpoint below the valid range of bolton1
+        let mut dewpoint = DEWPOINT;
+        dewpoint[0] = 250.0;
+
+        let most_unstable = parcel::profile1(
+            &PRESSURE,
+            &TEMPERATURE,
+            &dewpoint,
+            ParcelKind::MostUnstable(30000.0),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(most_unstable.start_index, 1);
+        assert_eq!(most_unstable.levels.len(), PRESSURE.len() - 1);
+        assert_approx_eq!(Float, most_unstable.levels[0].pressure, PRESSURE[1]);
+
+        assert!(matches!(
+            parcel::profile1(
+                &PRESSURE,
+                &TEMPERATURE,
+                &dewpoint,
+                ParcelKind::MostUnstable(0.0),
+                None
+            ),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+
+    #[test]
+    fn profile1_errors() {
+        assert!(matches!(
+            parcel::profile1(
+                &PRESSURE[..7],
+                &TEMPERATURE,
+                &DEWPOINT,
+                ParcelKind::SurfaceBased,
+                None
+            ),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+
+        let mut reversed = PRESSURE;
+        reversed.reverse();
+
+        assert!(matches!(
+            parcel::profile1(
+                &reversed,
+                &TEMPERATURE,
+                &DEWPOINT,
+                ParcelKind::SurfaceBased,
+                None
+            ),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+
+        assert_eq!(
+            parcel::profile1(
+                &PRESSURE,
+                &TEMPERATURE,
+                &DEWPOINT,
+                ParcelKind::MixedLayer(60000.0),
+                None
+            )
+            .unwrap_err(),
+            InputError::OutOfRange(String::from("parcel_kind"))
+        );
+    }
+}
@@ -2,10 +2,8 @@
 
 use float_cmp::approx_eq;
 use crate::Float;
-use crate::{
-    constants::{C_P, R_D},
-    errors::InputError,
-};
+use crate::{constants::KAPPA, dual::Number, errors::InputError};
+use std::ops::Div;
 
 #[cfg(feature="debug")]
 use floccus_proc::logerr;
@@ -27,43 +25,45 @@ use floccus_proc::logerr;
 ///Returns [`InputError::IncorrectArgumentSet`] when `pressure` is lower than `vapour_pressure`,
 ///in which case floating-point exponentation of negative number occurs.
 #[cfg_attr(feature = "debug", logerr)]
-pub fn davies_jones1(
-    temperature: Float,
-    pressure: Float,
-    vapour_pressure: Float,
-) -> Result<Float, InputError> {
-    if !(253.0..=324.0).contains(&temperature) {
+pub fn davies_jones1<N: Number>(
+    temperature: N,
+    pressure: N,
+    vapour_pressure: N,
+) -> Result<N, InputError>
+where
+    Float: Div<N, Output = N>,
+{
+    if !(253.0..=324.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
-    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+    if !(0.0..=10_000.0).contains(&vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from("vapour_pressure")));
     }
 
-    if approx_eq!(Float, pressure, vapour_pressure, ulps = 2) {
+    if approx_eq!(Float, pressure.value(), vapour_pressure.value(), ulps = 2) {
         return Err(InputError::IncorrectArgumentSet(String::from(
             "pressure and vapour_pressure cannot be equal",
         )));
     }
 
-    if vapour_pressure > pressure {
+    if vapour_pressure.value() > pressure.value() {
         return Err(InputError::IncorrectArgumentSet(String::from(
             "vapour_pressure cannot be higher than pressure",
         )));
     }
 
-    let kappa = R_D / C_P;
-
-    let result = temperature * (100_000.0 / (pressure - vapour_pressure)).powf(kappa);
+    let result = temperature * (100_000.0 / (pressure - vapour_pressure)).powf(KAPPA);
 
     Ok(result)
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
         tests_framework::{self, Argument},
@@ -0,0 +1,262 @@
+//!Functions to calculate precipitable water (column water vapour path) in kg*m^-2.
+//!
+//!Precipitable water is the mass of water vapour in the column of air of unit area,
+//!obtained by integrating humidity over pressure and dividing by gravitational acceleration
+//![AMS Glossary](https://glossary.ametsoc.org/wiki/Precipitable_water).
+//!Its value in kg*m^-2 is equal to the depth in mm of liquid water that would result
+//!from condensing all the vapour in the column.
+//!
+//!Levels of the sounding with missing dewpoint are skipped.
+
+use crate::Float;
+use crate::{
+    constants::G,
+    errors::InputError,
+    sounding::{Level, Sounding},
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Method of vertical integration of humidity between levels of the sounding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Integration {
+    ///Humidity varies linearly with pressure between levels
+    Trapezoidal,
+    ///Product of humidity and pressure varies linearly with logarithm of pressure between levels
+    LogPressure,
+}
+
+///Formula for computing precipitable water of the whole sounding from specific humidity.
+///
+///The column is integrated from the lowest to the highest level with available dewpoint.
+///
+///# Errors
+///
+///Returns [`InputError::IncorrectArgumentSet`] when dewpoint is available at less than two levels.\
+///Returns the errors of [`Level::specific_humidity`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn general1(sounding: &Sounding, integration: Integration) -> Result<Float, InputError> {
+    let points = column_points(sounding, Level::specific_humidity)?;
+    Ok(integrate(&points, integration))
+}
+
+///Formula for computing precipitable water of the whole sounding from mixing ratio.
+///
+///Mixing ratio is approximately equal to specific humidity, so this formula slightly overestimates
+///precipitable water compared to [`general1`]. It is provided for consistency with models
+///and datasets that use mixing ratio as their moisture variable.
+///
+///# Errors
+///
+///Returns [`InputError::IncorrectArgumentSet`] when dewpoint is available at less than two levels.\
+///Returns the errors of [`Level::mixing_ratio`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn general2(sounding: &Sounding, integration: Integration) -> Result<Float, InputError> {
+    let points = column_points(sounding, Level::mixing_ratio)?;
+    Ok(integrate(&points, integration))
+}
+
+///Formula for computing precipitable water of the layer between two pressure levels
+///(`[bottom, top]` in Pa) from specific humidity.
+///
+///Specific humidity at the layer boundaries is interpolated consistently with `integration`,
+///so integrals over adjacent layers add up to the integral over the whole column.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when `layer` is out of range.\
+///Valid `layer` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when top of the `layer` is not lower than its bottom
+///or `layer` is not within the part of the sounding with available dewpoint.\
+///Returns the errors of [`Level::specific_humidity`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn layer1(
+    sounding: &Sounding,
+    layer: [Float; 2],
+    integration: Integration,
+) -> Result<Float, InputError> {
+    let [bottom, top] = layer;
+
+    if !(100.0..=150_000.0).contains(&bottom) || !(100.0..=150_000.0).contains(&top) {
+        return Err(InputError::OutOfRange(String::from("layer")));
+    }
+
+    if top >= bottom {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "top of layer must be lower than its bottom",
+        )));
+    }
+
+    let column = column_points(sounding, Level::specific_humidity)?;
+
+    let mut points = vec![(bottom, interpolate(&column, bottom, integration)?)];
+    points.extend(column.iter().filter(|(p, _)| *p < bottom && *p > top));
+    points.push((top, interpolate(&column, top, integration)?));
+
+    Ok(integrate(&points, integration))
+}
+
+//collects (pressure, humidity) pairs at levels with available dewpoint
+fn column_points(
+    sounding: &Sounding,
+    humidity: fn(&Level) -> Result<Float, InputError>,
+) -> Result<Vec<(Float, Float)>, InputError> {
+    let points = sounding
+        .iter()
+        .filter(|level| level.dewpoint.is_some())
+        .map(|level| Ok((level.pressure, humidity(level)?)))
+        .collect::<Result<Vec<_>, InputError>>()?;
+
+    if points.len() < 2 {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "dewpoint must be available at least at two levels",
+        )));
+    }
+
+    Ok(points)
+}
+
+//interpolates humidity consistently with the integration method,
+//so that integrals over adjacent layers add up to the integral over the whole column
+fn interpolate(
+    points: &[(Float, Float)],
+    pressure: Float,
+    integration: Integration,
+) -> Result<Float, InputError> {
+    for pair in points.windows(2) {
+        let [(p_a, q_a), (p_b, q_b)] = [pair[0], pair[1]];
+
+        if (p_b..=p_a).contains(&pressure) {
+            let result = match integration {
+                Integration::Trapezoidal => q_a + (q_b - q_a) * (pressure - p_a) / (p_b - p_a),
+                Integration::LogPressure => {
+                    let weight = (pressure / p_a).ln() / (p_b / p_a).ln();
+                    (q_a * p_a + weight * (q_b * p_b - q_a * p_a)) / pressure
+                }
+            };
+
+            return Ok(result);
+        }
+    }
+
+    Err(InputError::IncorrectArgumentSet(String::from(
+        "layer must be within the part of the sounding with available dewpoint",
+    )))
+}
+
+//integrates (pressure, humidity) pairs ordered from the bottom up
+fn integrate(points: &[(Float, Float)], integration: Integration) -> Float {
+    let sum: Float = points
+        .windows(2)
+        .map(|pair| {
+            let [(p_a, q_a), (p_b, q_b)] = [pair[0], pair[1]];
+
+            match integration {
+                Integration::Trapezoidal => 0.5 * (q_a + q_b) * (p_a - p_b),
+                //humidity is integrated as q * p over logarithm of pressure
+                Integration::LogPressure => 0.5 * (q_a * p_a + q_b * p_b) * (p_a / p_b).ln(),
+            }
+        })
+        .sum();
+
+    sum / G
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constants::G,
+        errors::InputError,
+        precipitable_water::{self, Integration},
+        sounding::{Level, Sounding},
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    fn sounding() -> Sounding {
+        Sounding::from_hpa_celsius(
+            &[1000.0, 925.0, 850.0, 700.0, 500.0, 400.0, 300.0],
+            &[30.0, 24.0, 18.0, 8.0, -8.5, -19.0, -33.0],
+            &[22.0, 19.0, 14.0, 1.0, -20.0, -32.0, -45.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn general1() {
+        let levels = [
+            Level {
+                pressure: 100_000.0,
+                temperature: 300.0,
+                dewpoint: Some(290.0),
+                ..Level::default()
+            },
+            Level {
+                pressure: 90000.0,
+                temperature: 295.0,
+                dewpoint: None,
+                ..Level::default()
+            },
+            Level {
+                pressure: 80000.0,
+                temperature: 290.0,
+                dewpoint: Some(280.0),
+                ..Level::default()
+            },
+        ];
+        let sounding = Sounding::new(levels.to_vec()).unwrap();
+
+        let bottom = levels[0].specific_humidity().unwrap();
+        let top = levels[2].specific_humidity().unwrap();
+        let expected = 0.5 * (bottom + top) * 20000.0 / G;
+
+        let result = precipitable_water::general1(&sounding, Integration::Trapezoidal).unwrap();
+        assert_approx_eq!(Float, result, expected, epsilon = 0.01);
+
+        let result = precipitable_water::general1(&sounding, Integration::LogPressure).unwrap();
+        assert_approx_eq!(Float, result, expected, epsilon = 0.05 * expected);
+    }
+
+    #[test]
+    fn general2() {
+        let sounding = sounding();
+
+        let specific = precipitable_water::general1(&sounding, Integration::LogPressure).unwrap();
+        let mixing = precipitable_water::general2(&sounding, Integration::LogPressure).unwrap();
+
+        assert!(specific > 30.0 && specific < 60.0);
+        assert!(mixing > specific);
+        assert_approx_eq!(Float, mixing, specific, epsilon = 1.0);
+    }
+
+    #[test]
+    fn layer1() {
+        let sounding = sounding();
+
+        for integration in [Integration::Trapezoidal, Integration::LogPressure] {
+            let column = precipitable_water::general1(&sounding, integration).unwrap();
+            let lower =
+                precipitable_water::layer1(&sounding, [100_000.0, 77000.0], integration).unwrap();
+            let upper =
+                precipitable_water::layer1(&sounding, [77000.0, 30000.0], integration).unwrap();
+
+            assert_approx_eq!(Float, lower + upper, column, epsilon = 0.01);
+        }
+
+        assert_eq!(
+            precipitable_water::layer1(&sounding, [100_000.0, 50.0], Integration::Trapezoidal)
+                .unwrap_err(),
+            InputError::OutOfRange(String::from("layer"))
+        );
+        assert!(matches!(
+            precipitable_water::layer1(&sounding, [70000.0, 85000.0], Integration::Trapezoidal),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert!(matches!(
+            precipitable_water::layer1(&sounding, [70000.0, 20000.0], Integration::Trapezoidal),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+//!Functions to calculate pressure altitude in m.
+//!
+//!Pressure altitude is the altitude in the standard atmosphere at which pressure is equal
+//!to the given pressure [Wikipedia](https://en.wikipedia.org/wiki/Pressure_altitude).
+//!It is the altitude indicated by altimeter set to 1013.25hPa.
+
+use crate::Float;
+use crate::{
+    errors::InputError,
+    standard_atmosphere::{self, Model},
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing pressure altitude from pressure in the International Standard Atmosphere
+///with [`standard_atmosphere::altitude1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn general1(pressure: Float) -> Result<Float, InputError> {
+    if !(100.0..=150_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    standard_atmosphere::altitude1(pressure, Model::Isa)
+}
+
+///Formula for computing pressure altitude from pressure used by the U.S. National Weather Service.
+///Valid only in the troposphere of the standard atmosphere.
+///
+///Provided in National Weather Service (1998). Federal Meteorological Handbook No. 1,
+///Surface Weather Observations and Reports. FCM-H1-1998.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `pressure` range: 23000Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn nws1(pressure: Float) -> Result<Float, InputError> {
+    if !(23_000.0..=150_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    //convert units
+    let pressure = pressure / 100.0;
+
+    let result = (1.0 - (pressure / 1013.25).powf(0.190_284)) * 145_366.45;
+
+    //convert feet to metres
+    Ok(result * 0.3048)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pressure_altitude,
+        tests_framework::{self, Argument},
+    };
+
+    #[test]
+    fn general1() {
+        assert!(tests_framework::test_with_1arg(
+            &pressure_altitude::general1,
+            Argument {
+                name: "pressure",
+                def_val: 85000.0,
+                range: [100.0, 150_000.0]
+            },
+            1457.325
+        ));
+    }
+
+    #[test]
+    fn nws1() {
+        assert!(tests_framework::test_with_1arg(
+            &pressure_altitude::nws1,
+            Argument {
+                name: "pressure",
+                def_val: 85000.0,
+                range: [23000.0, 150_000.0]
+            },
+            1456.698
+        ));
+    }
+}
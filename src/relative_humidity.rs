@@ -1,7 +1,13 @@
 //!Functions to calculate relative humidity in %/100
 
-use crate::{errors::InputError, mixing_ratio, vapour_pressure};
+use crate::{
+    dual::Number,
+    errors::InputError,
+    mixing_ratio,
+    vapour_pressure::{self, Psychrometer},
+};
 use crate::Float;
+use std::ops::{Add, Div, Mul, Sub};
 
 #[cfg(feature="debug")]
 use floccus_proc::logerr;
@@ -18,12 +24,12 @@ use floccus_proc::logerr;
 ///Valid `mixing_ratio` range: 0.00001 - 0.5\
 ///Valid `saturation_mixing_ratio` range: 0.00001 - 0.5
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general1(mixing_ratio: Float, saturation_mixing_ratio: Float) -> Result<Float, InputError> {
-    if !(0.00001..=10.0).contains(&mixing_ratio) {
+pub fn general1<N: Number>(mixing_ratio: N, saturation_mixing_ratio: N) -> Result<N, InputError> {
+    if !(0.00001..=10.0).contains(&mixing_ratio.value()) {
         return Err(InputError::OutOfRange(String::from("mixing_ratio")));
     }
 
-    if !(0.00001..=10.0).contains(&saturation_mixing_ratio) {
+    if !(0.00001..=10.0).contains(&saturation_mixing_ratio.value()) {
         return Err(InputError::OutOfRange(String::from(
             "saturation_mixing_ratio",
         )));
@@ -41,12 +47,15 @@ pub fn general1(mixing_ratio: Float, saturation_mixing_ratio: Float) -> Result<F
 ///Valid `vapour_pressure` range: 0Pa - 10000Pa
 ///Valid `saturation_vapour_pressure` range: 0Pa - 10000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general2(vapour_pressure: Float, saturation_vapour_pressure: Float) -> Result<Float, InputError> {
-    if !(0.0..=50_000.0).contains(&vapour_pressure) {
+pub fn general2<N: Number>(
+    vapour_pressure: N,
+    saturation_vapour_pressure: N,
+) -> Result<N, InputError> {
+    if !(0.0..=50_000.0).contains(&vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from("vapour_pressure")));
     }
 
-    if !(0.1..=50_000.0).contains(&saturation_vapour_pressure) {
+    if !(0.1..=50_000.0).contains(&saturation_vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from(
             "saturation_vapour_pressure",
         )));
@@ -64,12 +73,15 @@ pub fn general2(vapour_pressure: Float, saturation_vapour_pressure: Float) -> Re
 ///Valid `temperature` range: 273K - 353K
 ///Valid `dewpoint` range: 273K - 353K
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general3(temperature: Float, dewpoint: Float) -> Result<Float, InputError> {
-    if !(273.0..=353.0).contains(&temperature) {
+pub fn general3<N: Number>(temperature: N, dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(273.0..=353.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(273.0..=353.0).contains(&temperature) {
+    if !(273.0..=353.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
@@ -90,16 +102,19 @@ pub fn general3(temperature: Float, dewpoint: Float) -> Result<Float, InputError
 ///Valid `dewpoint` range: 253K - 324K\
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general4(temperature: Float, dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
-    if !(253.0..=324.0).contains(&temperature) {
+pub fn general4<N: Number>(temperature: N, dewpoint: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(253.0..=324.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(253.0..=324.0).contains(&temperature) {
+    if !(253.0..=324.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -120,16 +135,19 @@ pub fn general4(temperature: Float, dewpoint: Float, pressure: Float) -> Result<
 ///Valid `dewpoint` range: 232K - 324K\
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general5(temperature: Float, dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
-    if !(232.0..=314.0).contains(&temperature) {
+pub fn general5<N: Number>(temperature: N, dewpoint: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(232.0..=314.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(232.0..=314.0).contains(&dewpoint) {
+    if !(232.0..=314.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
-    if !(10000.0..=150_000.0).contains(&pressure) {
+    if !(10000.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -141,12 +159,268 @@ pub fn general5(temperature: Float, dewpoint: Float, pressure: Float) -> Result<
     Ok(result)
 }
 
+///Formula for computing relative humidity from dry bulb and wet bulb temperatures and pressure
+///measured with psychrometer.
+///
+///Vapour pressure is computed with [`psychrometric1`](vapour_pressure::psychrometric1)
+///and saturation vapour pressure with [`buck1`](vapour_pressure::buck1).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K\
+///Valid `wet_bulb_temperature` range: 232K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `wet_bulb_temperature` is higher than `temperature`
+///or when the readings give negative vapour pressure.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn psychrometric1<N: Number>(
+    temperature: N,
+    wet_bulb_temperature: N,
+    pressure: N,
+    psychrometer: Psychrometer,
+) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    let vapour_pressure =
+        vapour_pressure::psychrometric1(temperature, wet_bulb_temperature, pressure, psychrometer)?;
+    let saturation_vapour_pressure = vapour_pressure::buck1(temperature, pressure)?;
+    let result = general2(vapour_pressure, saturation_vapour_pressure)?;
+
+    Ok(result)
+}
+
+///Formula for computing relative humidity (with respect to water) from dry bulb and ice bulb temperatures
+///and pressure measured with psychrometer which wet bulb is covered with ice.
+///
+///Vapour pressure is computed with [`psychrometric2`](vapour_pressure::psychrometric2)
+///and saturation vapour pressure with [`buck1`](vapour_pressure::buck1).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K\
+///Valid `ice_bulb_temperature` range: 193K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `ice_bulb_temperature` is higher than `temperature`
+///or when the readings give negative vapour pressure.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn psychrometric2<N: Number>(
+    temperature: N,
+    ice_bulb_temperature: N,
+    pressure: N,
+    psychrometer: Psychrometer,
+) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(232.0..=324.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let vapour_pressure =
+        vapour_pressure::psychrometric2(temperature, ice_bulb_temperature, pressure, psychrometer)?;
+    let saturation_vapour_pressure = vapour_pressure::buck1(temperature, pressure)?;
+    let result = general2(vapour_pressure, saturation_vapour_pressure)?;
+
+    Ok(result)
+}
+
+///Formula for computing relative humidity with respect to ice from vapour pressure, temperature and pressure
+///using [`buck2`](vapour_pressure::buck2) function for saturation vapour pressure calculation.
+///Should be used when accuracy is desired.
+///
+///Relative humidity with respect to ice can exceed 1.0 in supersaturated air.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa\
+///Valid `temperature` range: 193K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn ice1<N: Number>(vapour_pressure: N, temperature: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    validate_vapour_pressure(vapour_pressure)?;
+
+    if !(193.0..=274.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck2(temperature, pressure)?;
+
+    Ok(vapour_pressure / saturation_vapour_pressure)
+}
+
+///Formula for computing relative humidity with respect to ice from vapour pressure, temperature and pressure
+///using [`buck4`](vapour_pressure::buck4) function for saturation vapour pressure calculation.
+///Should be used for general use.
+///
+///Relative humidity with respect to ice can exceed 1.0 in supersaturated air.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa\
+///Valid `temperature` range: 223K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn ice2<N: Number>(vapour_pressure: N, temperature: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    validate_vapour_pressure(vapour_pressure)?;
+
+    if !(223.0..=274.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck4(temperature, pressure)?;
+
+    Ok(vapour_pressure / saturation_vapour_pressure)
+}
+
+///Formula for computing relative humidity with respect to ice from vapour pressure and temperature
+///using [`wexler2`](vapour_pressure::wexler2) function for saturation vapour pressure calculation.
+///Should be used for very low temperatures, eg. in the upper troposphere.
+///
+///Relative humidity with respect to ice can exceed 1.0 in supersaturated air.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa\
+///Valid `temperature` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn ice3<N: Number>(vapour_pressure: N, temperature: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    validate_vapour_pressure(vapour_pressure)?;
+
+    if !(173.0..=274.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::wexler2(temperature)?;
+
+    Ok(vapour_pressure / saturation_vapour_pressure)
+}
+
+///Formula for computing supersaturation with respect to ice from vapour pressure, temperature and pressure.
+///Supersaturation is equal to relative humidity over ice computed with [`ice1`] minus 1.0,
+///so it is positive in ice-supersaturated air, where persistent contrails and cirrus can form.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa\
+///Valid `temperature` range: 193K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn ice_supersaturation1<N: Number>(
+    vapour_pressure: N,
+    temperature: N,
+    pressure: N,
+) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    Ok(ice1(vapour_pressure, temperature, pressure)? - 1.0)
+}
+
+///Formula for converting relative humidity with respect to water to relative humidity with respect to ice
+///using ratio of [`murphy_koop1`](vapour_pressure::murphy_koop1) and [`murphy_koop2`](vapour_pressure::murphy_koop2)
+///saturation vapour pressures, so it can be used down to temperatures of cirrus clouds and contrails.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `relative_humidity` range: 0.0 - 2.0\
+///Valid `temperature` range: 123K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn water_to_ice1<N: Number>(relative_humidity: N, temperature: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    let ratio = saturation_ratio(relative_humidity, temperature)?;
+
+    Ok(relative_humidity * ratio)
+}
+
+///Formula for converting relative humidity with respect to ice to relative humidity with respect to water
+///using ratio of [`murphy_koop1`](vapour_pressure::murphy_koop1) and [`murphy_koop2`](vapour_pressure::murphy_koop2)
+///saturation vapour pressures, so it can be used down to temperatures of cirrus clouds and contrails.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `relative_humidity` range: 0.0 - 2.0\
+///Valid `temperature` range: 123K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn ice_to_water1<N: Number>(relative_humidity: N, temperature: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    let ratio = saturation_ratio(relative_humidity, temperature)?;
+
+    Ok(relative_humidity / ratio)
+}
+
+fn validate_vapour_pressure<N: Number>(vapour_pressure: N) -> Result<(), InputError>
+where
+    Float: Div<N, Output = N>,
+{
+    if !(0.0..=10_000.0).contains(&vapour_pressure.value()) {
+        return Err(InputError::OutOfRange(String::from("vapour_pressure")));
+    }
+
+    Ok(())
+}
+
+//ratio of saturation vapour pressure over water to saturation vapour pressure over ice
+fn saturation_ratio<N: Number>(relative_humidity: N, temperature: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(0.0..=2.0).contains(&relative_humidity.value()) {
+        return Err(InputError::OutOfRange(String::from("relative_humidity")));
+    }
+
+    if !(123.0..=274.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let water = vapour_pressure::murphy_koop1(temperature)?;
+    let ice = vapour_pressure::murphy_koop2(temperature)?;
+
+    Ok(water / ice)
+}
+
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
+        errors::InputError,
         relative_humidity,
         tests_framework::{self, Argument},
+        vapour_pressure::{self, Psychrometer},
+        Float,
     };
+    use float_cmp::assert_approx_eq;
 
     #[test]
     fn general1() {
@@ -247,4 +521,161 @@ mod tests {
             0.5338747953552858
         ));
     }
+
+    #[test]
+    fn psychrometric1() {
+        let result =
+            relative_humidity::psychrometric1(293.15, 288.15, 100_000.0, Psychrometer::Aspirated)
+                .unwrap();
+        assert_approx_eq!(Float, result, 0.5881, epsilon = 0.0001);
+
+        //saturated air has equal dry and wet bulb temperatures
+        let result =
+            relative_humidity::psychrometric1(293.15, 293.15, 100_000.0, Psychrometer::Aspirated)
+                .unwrap();
+        assert_approx_eq!(Float, result, 1.0, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn psychrometric2() {
+        let result =
+            relative_humidity::psychrometric2(268.15, 266.15, 100_000.0, Psychrometer::Aspirated)
+                .unwrap();
+        assert_approx_eq!(Float, result, 0.5304, epsilon = 0.0001);
+
+        //air saturated over ice is subsaturated over water
+        let result =
+            relative_humidity::psychrometric2(268.15, 268.15, 100_000.0, Psychrometer::Aspirated)
+                .unwrap();
+        let expected = vapour_pressure::buck2(268.15, 100_000.0).unwrap()
+            / vapour_pressure::buck1(268.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, expected, epsilon = 0.0001);
+
+        assert_eq!(
+            relative_humidity::psychrometric2(220.0, 218.0, 100_000.0, Psychrometer::Aspirated)
+                .unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+    }
+
+    #[test]
+    fn ice1() {
+        assert!(tests_framework::test_with_3args(
+            &relative_humidity::ice1,
+            Argument {
+                name: "vapour_pressure",
+                def_val: 15.0,
+                range: [0.0, 10_000.0]
+            },
+            Argument {
+                name: "temperature",
+                def_val: 233.15,
+                range: [193.0, 274.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 25000.0,
+                range: [100.0, 150_000.0]
+            },
+            1.165_888_1
+        ));
+    }
+
+    #[test]
+    fn ice2() {
+        assert!(tests_framework::test_with_3args(
+            &relative_humidity::ice2,
+            Argument {
+                name: "vapour_pressure",
+                def_val: 15.0,
+                range: [0.0, 10_000.0]
+            },
+            Argument {
+                name: "temperature",
+                def_val: 233.15,
+                range: [223.0, 274.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 25000.0,
+                range: [100.0, 150_000.0]
+            },
+            1.165_608
+        ));
+    }
+
+    #[test]
+    fn ice3() {
+        assert!(tests_framework::test_with_2args(
+            &relative_humidity::ice3,
+            Argument {
+                name: "vapour_pressure",
+                def_val: 15.0,
+                range: [0.0, 10_000.0]
+            },
+            Argument {
+                name: "temperature",
+                def_val: 233.15,
+                range: [173.0, 274.0]
+            },
+            1.167_440_4
+        ));
+    }
+
+    #[test]
+    fn ice_supersaturation1() {
+        let result = relative_humidity::ice_supersaturation1(15.0, 233.15, 25000.0).unwrap();
+        assert_approx_eq!(Float, result, 0.165_888_1, epsilon = 0.0001);
+
+        //air saturated over water is supersaturated over ice
+        let vapour_pressure = vapour_pressure::buck1(253.15, 50000.0).unwrap();
+        let result =
+            relative_humidity::ice_supersaturation1(vapour_pressure, 253.15, 50000.0).unwrap();
+        assert!(result > 0.2);
+    }
+
+    #[test]
+    fn water_to_ice1() {
+        assert!(tests_framework::test_with_2args(
+            &relative_humidity::water_to_ice1,
+            Argument {
+                name: "relative_humidity",
+                def_val: 0.7,
+                range: [0.0, 2.0]
+            },
+            Argument {
+                name: "temperature",
+                def_val: 213.15,
+                range: [123.0, 274.0]
+            },
+            1.205_891_59
+        ));
+
+        //conversions are inverse of each other
+        let result = relative_humidity::water_to_ice1(0.7, 253.15).unwrap();
+        let result = relative_humidity::ice_to_water1(result, 253.15).unwrap();
+        assert_approx_eq!(Float, result, 0.7, epsilon = 0.00001);
+
+        //both relative humidities are equal at melting point
+        let result = relative_humidity::water_to_ice1(0.7, 273.16).unwrap();
+        assert_approx_eq!(Float, result, 0.7, epsilon = 0.001);
+    }
+
+    #[test]
+    fn ice_to_water1() {
+        assert!(tests_framework::test_with_2args(
+            &relative_humidity::ice_to_water1,
+            Argument {
+                name: "relative_humidity",
+                def_val: 1.2,
+                range: [0.0, 2.0]
+            },
+            Argument {
+                name: "temperature",
+                def_val: 213.15,
+                range: [123.0, 274.0]
+            },
+            0.696_580_03
+        ));
+    }
 }
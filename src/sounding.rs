@@ -0,0 +1,641 @@
+//!Data structure for vertical profiles of the atmosphere (eg. radiosonde or model soundings).
+//!
+//![`Sounding`] holds levels ordered from the lowest level up and checks on construction
+//!that pressure is strictly decreasing and that all values are within the ranges
+//!accepted by formulae in this crate. Levels can then be iterated over to compute
+//!any quantity with functions from other modules:
+//!
+//!```
+//!use floccus::{sounding::Sounding, virtual_temperature};
+//!
+//!let sounding = Sounding::from_hpa_celsius(
+//!    &[1000.0, 850.0, 700.0, 500.0],
+//!    &[25.0, 15.0, 5.0, -12.0],
+//!    &[18.0, 10.0, -2.0, -25.0],
+//!)
+//!.unwrap();
+//!
+//!let virtual_temperatures: Vec<_> = sounding
+//!    .iter()
+//!    .map(|level| {
+//!        virtual_temperature::general2(
+//!            level.temperature,
+//!            level.pressure,
+//!            level.vapour_pressure()?,
+//!        )
+//!    })
+//!    .collect();
+//!
+//!assert!(virtual_temperatures.iter().all(|t| t.is_ok()));
+//!```
+
+use crate::Float;
+use crate::{
+    constants::ZERO_CELSIUS, errors::InputError, mixing_ratio, parcel, potential_temperature,
+    relative_humidity, specific_humidity, virtual_temperature,
+};
+
+///Single level of the [`Sounding`].
+///
+///Quantities other than pressure and temperature are optional
+///to allow missing data at some levels.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Level {
+    ///Pressure in `Pa`
+    pub pressure: Float,
+    ///Temperature in `K`
+    pub temperature: Float,
+    ///Dewpoint temperature in `K`
+    pub dewpoint: Option<Float>,
+    ///Geopotential height in `m`
+    pub height: Option<Float>,
+    ///Wind speed in `m*s^-1`
+    pub wind_speed: Option<Float>,
+    ///Wind direction (from which wind is blowing) in degrees
+    pub wind_direction: Option<Float>,
+}
+
+///Vertical profile of the atmosphere consisting of validated [`Level`]s.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sounding {
+    levels: Vec<Level>,
+}
+
+impl Level {
+    ///Computes vapour pressure from dewpoint with [`vapour_pressure::buck1`](crate::vapour_pressure::buck1),
+    ///below its valid range dewpoint is treated as frost point and
+    ///[`vapour_pressure::buck2`](crate::vapour_pressure::buck2) is used.
+    ///
+    ///# Errors
+    ///
+    ///Returns [`InputError::IncorrectArgumentSet`] when dewpoint is missing.
+    pub fn vapour_pressure(&self) -> Result<Float, InputError> {
+        let dewpoint = self.dewpoint.ok_or_else(|| {
+            InputError::IncorrectArgumentSet(String::from("dewpoint is missing at this level"))
+        })?;
+
+        parcel::environment_vapour_pressure(dewpoint, self.pressure)
+    }
+
+    ///Computes mixing ratio with [`mixing_ratio::general1`].
+    ///
+    ///# Errors
+    ///
+    ///Returns [`InputError::IncorrectArgumentSet`] when dewpoint is missing.
+    pub fn mixing_ratio(&self) -> Result<Float, InputError> {
+        mixing_ratio::general1(self.pressure, self.vapour_pressure()?)
+    }
+
+    ///Computes specific humidity with [`specific_humidity::general1`].
+    ///
+    ///# Errors
+    ///
+    ///Returns [`InputError::IncorrectArgumentSet`] when dewpoint is missing.
+    pub fn specific_humidity(&self) -> Result<Float, InputError> {
+        specific_humidity::general1(self.vapour_pressure()?, self.pressure)
+    }
+
+    ///Computes relative humidity with [`relative_humidity::general2`].
+    ///
+    ///# Errors
+    ///
+    ///Returns [`InputError::IncorrectArgumentSet`] when dewpoint is missing.
+    pub fn relative_humidity(&self) -> Result<Float, InputError> {
+        let saturation_vapour_pressure =
+            parcel::environment_vapour_pressure(self.temperature, self.pressure)?;
+
+        relative_humidity::general2(self.vapour_pressure()?, saturation_vapour_pressure)
+    }
+
+    ///Computes virtual temperature with [`virtual_temperature::general2`].
+    ///
+    ///# Errors
+    ///
+    ///Returns [`InputError::IncorrectArgumentSet`] when dewpoint is missing.
+    pub fn virtual_temperature(&self) -> Result<Float, InputError> {
+        virtual_temperature::general2(self.temperature, self.pressure, self.vapour_pressure()?)
+    }
+
+    ///Computes potential temperature with [`potential_temperature::davies_jones1`].
+    ///
+    ///# Errors
+    ///
+    ///Returns [`InputError::IncorrectArgumentSet`] when dewpoint is missing.\
+    ///Returns [`InputError::OutOfRange`] when temperature is out of the valid range
+    ///of [`potential_temperature::davies_jones1`].
+    pub fn potential_temperature(&self) -> Result<Float, InputError> {
+        potential_temperature::davies_jones1(
+            self.temperature,
+            self.pressure,
+            self.vapour_pressure()?,
+        )
+    }
+}
+
+impl Sounding {
+    ///Creates sounding from provided levels ordered from the lowest level up.
+    ///
+    ///# Errors
+    ///
+    ///Returns [`InputError::OutOfRange`] when one of values is out of range.\
+    ///Valid `pressure` range: 100Pa - 150000Pa\
+    ///Valid `temperature` range: 193K - 324K\
+    ///Valid `dewpoint` range: 193K - 324K\
+    ///Valid `height` range: -500m - 90000m\
+    ///Valid `wind_speed` range: 0m/s - 150m/s\
+    ///Valid `wind_direction` range: 0 - 360
+    ///
+    ///Returns [`InputError::IncorrectArgumentSet`] when sounding has less than two levels,
+    ///pressure is not strictly decreasing, height is not strictly increasing
+    ///or dewpoint is higher than temperature.
+    pub fn new(levels: Vec<Level>) -> Result<Self, InputError> {
+        if levels.len() < 2 {
+            return Err(InputError::IncorrectArgumentSet(String::from(
+                "sounding must contain at least two levels",
+            )));
+        }
+
+        for level in &levels {
+            validate_level(level)?;
+        }
+
+        if levels.windows(2).any(|l| l[1].pressure >= l[0].pressure) {
+            return Err(InputError::IncorrectArgumentSet(String::from(
+                "pressure must be strictly decreasing",
+            )));
+        }
+
+        let heights: Vec<Float> = levels.iter().filter_map(|l| l.height).collect();
+
+        if heights.windows(2).any(|h| h[1] <= h[0]) {
+            return Err(InputError::IncorrectArgumentSet(String::from(
+                "height must be strictly increasing",
+            )));
+        }
+
+        Ok(Sounding { levels })
+    }
+
+    ///Creates sounding from pressure (in Pa), temperature (in K) and dewpoint (in K).
+    ///
+    ///# Errors
+    ///
+    ///Returns the same errors as [`Sounding::new`] and [`InputError::IncorrectArgumentSet`]
+    ///when provided slices have different lengths.
+    pub fn from_si(
+        pressure: &[Float],
+        temperature: &[Float],
+        dewpoint: &[Float],
+    ) -> Result<Self, InputError> {
+        check_lengths(pressure.len(), &[temperature.len(), dewpoint.len()])?;
+
+        let levels = pressure
+            .iter()
+            .zip(temperature)
+            .zip(dewpoint)
+            .map(|((p, t), d)| Level {
+                pressure: *p,
+                temperature: *t,
+                dewpoint: Some(*d),
+                ..Default::default()
+            })
+            .collect();
+
+        Sounding::new(levels)
+    }
+
+    ///Creates sounding from pressure (in hPa), temperature (in Celsius) and dewpoint (in Celsius),
+    ///which are commonly used in radiosonde reports.
+    ///
+    ///# Errors
+    ///
+    ///Returns the same errors as [`Sounding::from_si`] (with limits in SI units).
+    pub fn from_hpa_celsius(
+        pressure: &[Float],
+        temperature: &[Float],
+        dewpoint: &[Float],
+    ) -> Result<Self, InputError> {
+        let pressure: Vec<Float> = pressure.iter().map(|p| p * 100.0).collect();
+        let temperature: Vec<Float> = temperature.iter().map(|t| t + ZERO_CELSIUS).collect();
+        let dewpoint: Vec<Float> = dewpoint.iter().map(|t| t + ZERO_CELSIUS).collect();
+
+        Sounding::from_si(&pressure, &temperature, &dewpoint)
+    }
+
+    ///Creates sounding from pressure (in Pa), temperature (in K) and relative humidity (in %/100).
+    ///
+    ///Dewpoint is found by inverting the function used by [`Level::vapour_pressure`].
+    ///
+    ///# Errors
+    ///
+    ///Returns [`InputError::OutOfRange`] when relative humidity is out of range
+    ///or when resulting dewpoint is out of the valid range of [`Sounding::new`].\
+    ///Valid `relative_humidity` range: 0.00001 - 1.0
+    ///
+    ///Returns the same errors as [`Sounding::from_si`].
+    pub fn from_relative_humidity(
+        pressure: &[Float],
+        temperature: &[Float],
+        relative_humidity: &[Float],
+    ) -> Result<Self, InputError> {
+        check_lengths(
+            pressure.len(),
+            &[temperature.len(), relative_humidity.len()],
+        )?;
+
+        let dewpoint = pressure
+            .iter()
+            .zip(temperature)
+            .zip(relative_humidity)
+            .map(|((p, t), rh)| dewpoint_from_relative_humidity(*t, *p, *rh))
+            .collect::<Result<Vec<Float>, InputError>>()?;
+
+        Sounding::from_si(pressure, temperature, &dewpoint)
+    }
+
+    ///Adds geopotential height (in m) to all levels of the sounding.
+    ///
+    ///# Errors
+    ///
+    ///Returns the same errors as [`Sounding::from_si`].
+    pub fn with_height(self, height: &[Float]) -> Result<Self, InputError> {
+        check_lengths(self.levels.len(), &[height.len()])?;
+
+        let levels = self
+            .levels
+            .into_iter()
+            .zip(height)
+            .map(|(level, h)| Level {
+                height: Some(*h),
+                ..level
+            })
+            .collect();
+
+        Sounding::new(levels)
+    }
+
+    ///Adds wind speed (in m/s) and direction (in degrees) to all levels of the sounding.
+    ///
+    ///# Errors
+    ///
+    ///Returns the same errors as [`Sounding::from_si`].
+    pub fn with_wind(
+        self,
+        wind_speed: &[Float],
+        wind_direction: &[Float],
+    ) -> Result<Self, InputError> {
+        check_lengths(self.levels.len(), &[wind_speed.len(), wind_direction.len()])?;
+
+        let levels = self
+            .levels
+            .into_iter()
+            .zip(wind_speed.iter().zip(wind_direction))
+            .map(|(level, (s, d))| Level {
+                wind_speed: Some(*s),
+                wind_direction: Some(*d),
+                ..level
+            })
+            .collect();
+
+        Sounding::new(levels)
+    }
+
+    ///Returns all levels of the sounding, from the lowest level up.
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    ///Returns an iterator over levels of the sounding, from the lowest level up.
+    pub fn iter(&self) -> std::slice::Iter<'_, Level> {
+        self.levels.iter()
+    }
+
+    ///Returns pressure, temperature and dewpoint of levels at which dewpoint is not missing,
+    ///as required by functions working on profiles (eg. [`parcel::profile1`]).
+    pub fn thermodynamic_profile(&self) -> [Vec<Float>; 3] {
+        let mut pressure = vec![];
+        let mut temperature = vec![];
+        let mut dewpoint = vec![];
+
+        for level in &self.levels {
+            if let Some(d) = level.dewpoint {
+                pressure.push(level.pressure);
+                temperature.push(level.temperature);
+                dewpoint.push(d);
+            }
+        }
+
+        [pressure, temperature, dewpoint]
+    }
+
+    ///Returns value of `quantity` at given `pressure` (in Pa), linearly interpolated
+    ///in logarithm of pressure between the nearest levels at which `quantity` is not missing.
+    ///
+    ///```
+    ///# use floccus::sounding::Sounding;
+    ///# let sounding = Sounding::from_si(&[100_000.0, 50000.0], &[300.0, 270.0], &[290.0, 250.0]).unwrap();
+    ///let dewpoint = sounding.interpolate(70000.0, |level| level.dewpoint);
+    ///# assert!(dewpoint.is_ok());
+    ///```
+    ///
+    ///# Errors
+    ///
+    ///Returns [`InputError::IncorrectArgumentSet`] when `pressure` is outside of the part
+    ///of the sounding in which `quantity` is available.
+    pub fn interpolate<F>(&self, pressure: Float, quantity: F) -> Result<Float, InputError>
+    where
+        F: Fn(&Level) -> Option<Float>,
+    {
+        let available: Vec<(Float, Float)> = self
+            .levels
+            .iter()
+            .filter_map(|l| quantity(l).map(|q| (l.pressure, q)))
+            .collect();
+
+        for pair in available.windows(2) {
+            let [(p_a, q_a), (p_b, q_b)] = [pair[0], pair[1]];
+
+            if (p_b..=p_a).contains(&pressure) {
+                let weight = (pressure / p_a).ln() / (p_b / p_a).ln();
+                return Ok(q_a + weight * (q_b - q_a));
+            }
+        }
+
+        match available.as_slice() {
+            [(p, q)] if *p == pressure => Ok(*q),
+            _ => Err(InputError::IncorrectArgumentSet(String::from(
+                "pressure is outside of the sounding or quantity is missing",
+            ))),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Sounding {
+    type Item = &'a Level;
+    type IntoIter = std::slice::Iter<'a, Level>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.levels.iter()
+    }
+}
+
+fn validate_level(level: &Level) -> Result<(), InputError> {
+    if !(100.0..=150_000.0).contains(&level.pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    if !(193.0..=324.0).contains(&level.temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if let Some(dewpoint) = level.dewpoint {
+        if !(193.0..=324.0).contains(&dewpoint) {
+            return Err(InputError::OutOfRange(String::from("dewpoint")));
+        }
+
+        if dewpoint > level.temperature {
+            return Err(InputError::IncorrectArgumentSet(String::from(
+                "dewpoint cannot be higher than temperature",
+            )));
+        }
+    }
+
+    if let Some(height) = level.height {
+        if !(-500.0..=90_000.0).contains(&height) {
+            return Err(InputError::OutOfRange(String::from("height")));
+        }
+    }
+
+    if let Some(wind_speed) = level.wind_speed {
+        if !(0.0..=150.0).contains(&wind_speed) {
+            return Err(InputError::OutOfRange(String::from("wind_speed")));
+        }
+    }
+
+    if let Some(wind_direction) = level.wind_direction {
+        if !(0.0..=360.0).contains(&wind_direction) {
+            return Err(InputError::OutOfRange(String::from("wind_direction")));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_lengths(expected: usize, lengths: &[usize]) -> Result<(), InputError> {
+    if lengths.iter().any(|l| *l != expected) {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "all provided slices must have the same length",
+        )));
+    }
+
+    Ok(())
+}
+
+fn dewpoint_from_relative_humidity(
+    temperature: Float,
+    pressure: Float,
+    relative_humidity: Float,
+) -> Result<Float, InputError> {
+    if !(0.00001..=1.0).contains(&relative_humidity) {
+        return Err(InputError::OutOfRange(String::from("relative_humidity")));
+    }
+
+    if !(193.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let vapour_pressure =
+        relative_humidity * parcel::environment_vapour_pressure(temperature, pressure)?;
+
+    if vapour_pressure < parcel::environment_vapour_pressure(193.0, pressure)? {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    //vapour pressure increases monotonically with dewpoint
+    let mut lower = 193.0;
+    let mut upper = temperature;
+
+    for _ in 0..40 {
+        let middle = 0.5 * (lower + upper);
+
+        if parcel::environment_vapour_pressure(middle, pressure)? > vapour_pressure {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+
+    Ok(0.5 * (lower + upper))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::InputError,
+        sounding::{Level, Sounding},
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    fn sounding() -> Sounding {
+        Sounding::from_hpa_celsius(
+            &[1000.0, 850.0, 700.0, 500.0],
+            &[25.0, 15.0, 5.0, -12.0],
+            &[18.0, 10.0, -2.0, -25.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn constructors() {
+        let sounding = sounding();
+
+        assert_approx_eq!(Float, sounding.levels()[1].pressure, 85000.0);
+        assert_approx_eq!(
+            Float,
+            sounding.levels()[1].temperature,
+            288.15,
+            epsilon = 0.001
+        );
+        assert_approx_eq!(
+            Float,
+            sounding.levels()[3].dewpoint.unwrap(),
+            248.15,
+            epsilon = 0.001
+        );
+
+        let relative_humidity: Vec<Float> = sounding
+            .iter()
+            .map(|l| l.relative_humidity().unwrap())
+            .collect();
+        let pressure: Vec<Float> = sounding.iter().map(|l| l.pressure).collect();
+        let temperature: Vec<Float> = sounding.iter().map(|l| l.temperature).collect();
+
+        let from_rh =
+            Sounding::from_relative_humidity(&pressure, &temperature, &relative_humidity).unwrap();
+
+        for (a, b) in from_rh.iter().zip(&sounding) {
+            assert_approx_eq!(
+                Float,
+                a.dewpoint.unwrap(),
+                b.dewpoint.unwrap(),
+                epsilon = 0.01
+            );
+        }
+
+        let sounding = sounding
+            .with_height(&[110.0, 1500.0, 3100.0, 5800.0])
+            .unwrap()
+            .with_wind(&[5.0, 10.0, 15.0, 25.0], &[180.0, 200.0, 230.0, 250.0])
+            .unwrap();
+
+        assert_approx_eq!(Float, sounding.levels()[2].height.unwrap(), 3100.0);
+        assert_approx_eq!(Float, sounding.levels()[3].wind_direction.unwrap(), 250.0);
+    }
+
+    #[test]
+    fn levels() {
+        let sounding = sounding();
+
+        let level = sounding.levels()[0];
+        assert_approx_eq!(
+            Float,
+            level.vapour_pressure().unwrap(),
+            2072.3,
+            epsilon = 1.0
+        );
+        assert_approx_eq!(
+            Float,
+            level.mixing_ratio().unwrap(),
+            0.01316,
+            epsilon = 0.0001
+        );
+        assert!(level.virtual_temperature().unwrap() > level.temperature);
+        assert!(level.potential_temperature().unwrap() > level.temperature);
+
+        let mut levels = sounding.levels().to_vec();
+        levels[1].dewpoint = None;
+
+        let sounding = Sounding::new(levels).unwrap();
+        assert!(matches!(
+            sounding.levels()[1].mixing_ratio(),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+
+        let [pressure, temperature, dewpoint] = sounding.thermodynamic_profile();
+        assert_eq!(pressure.len(), 3);
+        assert_eq!(temperature.len(), 3);
+        assert_eq!(dewpoint.len(), 3);
+    }
+
+    #[test]
+    fn validation() {
+        assert!(matches!(
+            Sounding::from_si(&[100_000.0], &[300.0], &[290.0]),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert!(matches!(
+            Sounding::from_si(&[100_000.0, 90000.0], &[300.0, 295.0], &[290.0]),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert!(matches!(
+            Sounding::from_si(&[90000.0, 100_000.0], &[300.0, 295.0], &[290.0, 285.0]),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert!(matches!(
+            Sounding::from_si(&[100_000.0, 90000.0], &[300.0, 295.0], &[290.0, 296.0]),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert_eq!(
+            Sounding::from_si(&[200_000.0, 90000.0], &[300.0, 295.0], &[290.0, 285.0]).unwrap_err(),
+            InputError::OutOfRange(String::from("pressure"))
+        );
+        assert_eq!(
+            Sounding::from_si(&[100_000.0, 90000.0], &[400.0, 295.0], &[290.0, 285.0]).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+
+        //levels outside of the range of profile functions are rejected, not skipped
+        assert_eq!(
+            Sounding::from_si(
+                &[100_000.0, 95000.0, 50000.0],
+                &[330.0, 310.0, 270.0],
+                &[290.0, 288.0, 250.0],
+            )
+            .unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+        assert_eq!(
+            Sounding::from_si(&[100_000.0, 10000.0], &[300.0, 190.0], &[290.0, 185.0]).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+        assert_eq!(
+            sounding()
+                .with_wind(&[5.0, 10.0, 200.0, 25.0], &[0.0, 0.0, 0.0, 0.0])
+                .unwrap_err(),
+            InputError::OutOfRange(String::from("wind_speed"))
+        );
+        assert!(matches!(
+            sounding().with_height(&[100.0, 50.0, 3000.0, 5000.0]),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+
+        let levels = vec![
+            Level {
+                pressure: 100_000.0,
+                temperature: 300.0,
+                ..Default::default()
+            },
+            Level {
+                pressure: 90000.0,
+                temperature: 295.0,
+                wind_direction: Some(400.0),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(
+            Sounding::new(levels).unwrap_err(),
+            InputError::OutOfRange(String::from("wind_direction"))
+        );
+    }
+}
@@ -5,8 +5,9 @@
 //!
 //!Specific humidity is approximately equal to mixing ratio.
 
-use crate::{constants::EPSILON, errors::InputError};
+use crate::{constants::EPSILON, dual::Number, errors::InputError};
 use crate::Float;
+use std::ops::Mul;
 
 #[cfg(feature="debug")]
 use floccus_proc::logerr;
@@ -23,12 +24,15 @@ use floccus_proc::logerr;
 ///Valid `vapour_pressure` range: 0Pa - 50000OPa\,
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general1(vapour_pressure: Float, pressure: Float) -> Result<Float, InputError> {
-    if !(0.0..=50_000.0).contains(&vapour_pressure) {
+pub fn general1<N: Number>(vapour_pressure: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N>,
+{
+    if !(0.0..=50_000.0).contains(&vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from("vapour_pressure")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -37,6 +41,7 @@ pub fn general1(vapour_pressure: Float, pressure: Float) -> Result<Float, InputE
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
         specific_humidity,
@@ -0,0 +1,302 @@
+//!Functions to calculate classic stability indices of the atmosphere from the sounding.
+//!
+//!Values at standard pressure levels are interpolated from the sounding with [`Sounding::interpolate`].
+//!Parcels are lifted with [`parcel::ascent1`], so that indices are consistent with thermodynamics
+//!of this crate.
+
+use crate::Float;
+use crate::{
+    constants::ZERO_CELSIUS,
+    errors::InputError,
+    parcel::{self, ParcelKind},
+    sounding::Sounding,
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing lifted index in K from the sounding.
+///Lifted index is the difference between environmental temperature and temperature of parcel
+///lifted to 500hPa. Negative values indicate instability.
+///
+///Initial parcel is chosen according to `parcel_kind` as in [`parcel::profile1`].
+///
+///Derived by J. G. Galway (1956) [(doi:10.1175/1520-0477-37.10.528)](https://doi.org/10.1175/1520-0477-37.10.528)
+///
+///# Errors
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the sounding does not reach 500hPa
+///or parcel starts above 500hPa.
+///
+///Returns the errors of [`parcel::ascent1`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn lifted_index1(
+    sounding: &Sounding,
+    parcel_kind: ParcelKind,
+    freezing_range: Option<[Float; 2]>,
+) -> Result<Float, InputError> {
+    let [pressure, temperature, dewpoint] = sounding.thermodynamic_profile();
+    parcel::validate_profile(&pressure, &temperature, &dewpoint)?;
+
+    let (start_index, start_temperature, start_mixing_ratio) =
+        parcel::initial_parcel(&pressure, &temperature, &dewpoint, parcel_kind)?;
+
+    let parcel_temperature = parcel::ascent1(
+        start_temperature,
+        pressure[start_index],
+        start_mixing_ratio,
+        &[50000.0],
+        freezing_range,
+    )?[0]
+        .temperature;
+
+    let environment_temperature = sounding.interpolate(50000.0, |l| Some(l.temperature))?;
+
+    Ok(environment_temperature - parcel_temperature)
+}
+
+///Formula for computing Showalter index in K from the sounding.
+///Showalter index is the difference between environmental temperature and temperature
+///of parcel lifted from 850hPa to 500hPa. Negative values indicate instability.
+///
+///Derived by A. K. Showalter (1953) [(doi:10.1175/1520-0477-34.6.250)](https://doi.org/10.1175/1520-0477-34.6.250)
+///
+///# Errors
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the sounding does not cover 850hPa - 500hPa
+///or dewpoint is missing at 850hPa.
+///
+///Returns the errors of [`parcel::ascent1`].
+#[cfg_attr(feature = "debug", logerr)]
+pub fn showalter_index1(
+    sounding: &Sounding,
+    freezing_range: Option<[Float; 2]>,
+) -> Result<Float, InputError> {
+    let temperature_850 = sounding.interpolate(85000.0, |l| Some(l.temperature))?;
+    let dewpoint_850 = sounding.interpolate(85000.0, |l| l.dewpoint)?;
+    let environment_temperature = sounding.interpolate(50000.0, |l| Some(l.temperature))?;
+
+    let vapour_pressure = parcel::environment_vapour_pressure(dewpoint_850, 85000.0)?;
+    let mixing_ratio = crate::mixing_ratio::general1(85000.0, vapour_pressure)?;
+
+    let parcel_temperature = parcel::ascent1(
+        temperature_850,
+        85000.0,
+        mixing_ratio,
+        &[50000.0],
+        freezing_range,
+    )?[0]
+        .temperature;
+
+    Ok(environment_temperature - parcel_temperature)
+}
+
+///Formula for computing K-index from the sounding.
+///The index is computed from temperatures in Celsius, as in its original definition.
+///
+///Derived by J. J. George (1960). Weather Forecasting for Aeronautics. Academic Press.
+///
+///# Errors
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the sounding does not cover 850hPa - 500hPa
+///or dewpoint is missing at 850hPa or 700hPa.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn k_index1(sounding: &Sounding) -> Result<Float, InputError> {
+    let temperature_850 = sounding.interpolate(85000.0, |l| Some(l.temperature))?;
+    let dewpoint_850 = sounding.interpolate(85000.0, |l| l.dewpoint)?;
+    let temperature_700 = sounding.interpolate(70000.0, |l| Some(l.temperature))?;
+    let dewpoint_700 = sounding.interpolate(70000.0, |l| l.dewpoint)?;
+    let temperature_500 = sounding.interpolate(50000.0, |l| Some(l.temperature))?;
+
+    let result = (temperature_850 - temperature_500) + (dewpoint_850 - ZERO_CELSIUS)
+        - (temperature_700 - dewpoint_700);
+
+    Ok(result)
+}
+
+///Formula for computing Total Totals index from the sounding.
+///The index is the sum of Vertical Totals (temperature difference between 850hPa and 500hPa)
+///and Cross Totals (difference between dewpoint at 850hPa and temperature at 500hPa).
+///
+///Derived by R. C. Miller (1972). Notes on analysis and severe-storm forecasting procedures
+///of the Air Force Global Weather Central. AWS Tech. Rep. 200 (Rev).
+///
+///# Errors
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the sounding does not cover 850hPa - 500hPa
+///or dewpoint is missing at 850hPa.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn total_totals1(sounding: &Sounding) -> Result<Float, InputError> {
+    let temperature_850 = sounding.interpolate(85000.0, |l| Some(l.temperature))?;
+    let dewpoint_850 = sounding.interpolate(85000.0, |l| l.dewpoint)?;
+    let temperature_500 = sounding.interpolate(50000.0, |l| Some(l.temperature))?;
+
+    Ok(temperature_850 + dewpoint_850 - 2.0 * temperature_500)
+}
+
+///Formula for computing Severe Weather Threat (SWEAT) index from the sounding.
+///
+///The index is computed with dewpoint in Celsius and wind speed in knots, as in its original definition.
+///Wind at 850hPa and 500hPa is interpolated from its components.
+///
+///Derived by R. C. Miller (1972). Notes on analysis and severe-storm forecasting procedures
+///of the Air Force Global Weather Central. AWS Tech. Rep. 200 (Rev).
+///
+///# Errors
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the sounding does not cover 850hPa - 500hPa
+///or dewpoint or wind is missing at 850hPa or 500hPa.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn sweat_index1(sounding: &Sounding) -> Result<Float, InputError> {
+    let dewpoint_850 = sounding.interpolate(85000.0, |l| l.dewpoint)? - ZERO_CELSIUS;
+    let total_totals = total_totals1(sounding)?;
+
+    let [speed_850, direction_850] = wind_at(sounding, 85000.0)?;
+    let [speed_500, direction_500] = wind_at(sounding, 50000.0)?;
+
+    //convert to knots
+    let speed_850 = speed_850 / 0.514_444;
+    let speed_500 = speed_500 / 0.514_444;
+
+    let mut result = 12.0 * dewpoint_850.max(0.0)
+        + 20.0 * (total_totals - 49.0).max(0.0)
+        + 2.0 * speed_850
+        + speed_500;
+
+    let veering = direction_500 - direction_850;
+
+    if (130.0..=250.0).contains(&direction_850)
+        && (210.0..=310.0).contains(&direction_500)
+        && veering > 0.0
+        && speed_850 >= 15.0
+        && speed_500 >= 15.0
+    {
+        result += 125.0 * (veering.to_radians().sin() + 0.2);
+    }
+
+    Ok(result)
+}
+
+///Formula for computing Boyden index from the sounding.
+///The index is computed from 1000hPa - 700hPa thickness in decametres and temperature
+///at 700hPa in Celsius, as in its original definition.
+///
+///Derived by C. J. Boyden (1963). A simple instability index for use as a synoptic parameter.
+///Meteorological Magazine, 92, 198-210.
+///
+///# Errors
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the sounding does not cover 1000hPa - 700hPa
+///or height is missing at 1000hPa or 700hPa.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn boyden_index1(sounding: &Sounding) -> Result<Float, InputError> {
+    let height_1000 = sounding.interpolate(100_000.0, |l| l.height)?;
+    let height_700 = sounding.interpolate(70000.0, |l| l.height)?;
+    let temperature_700 = sounding.interpolate(70000.0, |l| Some(l.temperature))? - ZERO_CELSIUS;
+
+    Ok((height_700 - height_1000) / 10.0 - temperature_700 - 200.0)
+}
+
+//returns wind speed and direction interpolated from wind components
+fn wind_at(sounding: &Sounding, pressure: Float) -> Result<[Float; 2], InputError> {
+    let eastward = sounding.interpolate(pressure, |l| {
+        Some(-l.wind_speed? * l.wind_direction?.to_radians().sin())
+    })?;
+    let northward = sounding.interpolate(pressure, |l| {
+        Some(-l.wind_speed? * l.wind_direction?.to_radians().cos())
+    })?;
+
+    let speed = eastward.hypot(northward);
+    let direction = (-eastward).atan2(-northward).to_degrees().rem_euclid(360.0);
+
+    Ok([speed, direction])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parcel::ParcelKind, sounding::Sounding, stability_indices, Float};
+    use float_cmp::assert_approx_eq;
+
+    fn sounding() -> Sounding {
+        let knot = 0.514_444;
+
+        Sounding::from_hpa_celsius(
+            &[1000.0, 850.0, 700.0, 500.0, 300.0],
+            &[30.0, 18.0, 8.0, -8.5, -33.0],
+            &[22.0, 14.0, 1.0, -20.0, -45.0],
+        )
+        .unwrap()
+        .with_height(&[110.0, 1530.0, 3100.0, 5800.0, 9500.0])
+        .unwrap()
+        .with_wind(
+            &[
+                10.0 * knot,
+                20.0 * knot,
+                30.0 * knot,
+                40.0 * knot,
+                60.0 * knot,
+            ],
+            &[160.0, 180.0, 210.0, 240.0, 250.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn lifted_index1() {
+        let sounding = sounding();
+
+        let surface = stability_indices::lifted_index1(
+            &sounding,
+            ParcelKind::SurfaceBased,
+            Some([273.15, 250.15]),
+        )
+        .unwrap();
+        let mixed_layer = stability_indices::lifted_index1(
+            &sounding,
+            ParcelKind::MixedLayer(20000.0),
+            Some([273.15, 250.15]),
+        )
+        .unwrap();
+
+        assert!(surface < -5.0);
+        assert!(mixed_layer > surface);
+    }
+
+    #[test]
+    fn showalter_index1() {
+        let result = stability_indices::showalter_index1(&sounding(), None).unwrap();
+        assert!(result < 0.0);
+    }
+
+    #[test]
+    fn k_index1() {
+        let result = stability_indices::k_index1(&sounding()).unwrap();
+        assert_approx_eq!(Float, result, 33.5, epsilon = 0.001);
+    }
+
+    #[test]
+    fn total_totals1() {
+        let result = stability_indices::total_totals1(&sounding()).unwrap();
+        assert_approx_eq!(Float, result, 49.0, epsilon = 0.001);
+    }
+
+    #[test]
+    fn sweat_index1() {
+        let result = stability_indices::sweat_index1(&sounding()).unwrap();
+        assert_approx_eq!(Float, result, 381.253, epsilon = 0.01);
+
+        let no_wind = Sounding::from_hpa_celsius(
+            &[1000.0, 850.0, 500.0],
+            &[30.0, 18.0, -8.5],
+            &[22.0, 14.0, -20.0],
+        )
+        .unwrap();
+        assert!(stability_indices::sweat_index1(&no_wind).is_err());
+    }
+
+    #[test]
+    fn boyden_index1() {
+        let result = stability_indices::boyden_index1(&sounding()).unwrap();
+        assert_approx_eq!(Float, result, 91.0, epsilon = 0.001);
+    }
+}
@@ -0,0 +1,344 @@
+//!Functions to calculate properties of reference standard atmospheres.
+//!
+//!Standard atmospheres define temperature as piecewise linear function of geopotential altitude,
+//!with pressure following from the hydrostatic equation and the ideal gas law.
+//!Both supported models share the same layers and sea-level values (288.15K and 101325Pa)
+//!and differ only in their upper limit. All computations use gas constant, molar mass of dry air
+//!and gravitational acceleration from [`constants`](crate::constants), so results may differ
+//!from published tables in the last significant digits.
+//!
+//!All altitudes are geopotential altitudes in m.
+
+use crate::Float;
+use crate::{
+    constants::{C_P, C_V, G, R_D},
+    errors::InputError,
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Reference model of the standard atmosphere.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Model {
+    ///International Standard Atmosphere (ISO 2533:1975), identical to ICAO Standard Atmosphere,
+    ///defined from -5000m to 80000m
+    Isa,
+    ///U.S. Standard Atmosphere (1976), defined from -5000m to 84852m (86km of geometric altitude)
+    Us76,
+}
+
+impl Model {
+    fn max_altitude(self) -> Float {
+        match self {
+            Model::Isa => 80_000.0,
+            Model::Us76 => 84_852.0,
+        }
+    }
+}
+
+const MIN_ALTITUDE: Float = -5000.0;
+
+//decrease of temperature with altitude in the lowest layer in K*m^-1
+pub(crate) const LAPSE_RATE: Float = 0.0065;
+
+//base geopotential altitude in m and temperature lapse rate in K*m^-1 of each layer
+const LAYERS: [[Float; 2]; 7] = [
+    [0.0, -LAPSE_RATE],
+    [11_000.0, 0.0],
+    [20_000.0, 0.001],
+    [32_000.0, 0.0028],
+    [47_000.0, 0.0],
+    [51_000.0, -0.0028],
+    [71_000.0, -0.002],
+];
+
+pub(crate) const SEA_LEVEL_TEMPERATURE: Float = 288.15;
+pub(crate) const SEA_LEVEL_PRESSURE: Float = 101_325.0;
+
+///Formula for computing temperature in K of the standard atmosphere at given geopotential altitude.
+///
+///Provided in ISO 2533:1975 and U.S. Standard Atmosphere (1976), NOAA-S/T 76-1562.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `geopotential_altitude` range: -5000m - 80000m ([`Model::Isa`]), -5000m - 84852m ([`Model::Us76`])
+#[cfg_attr(feature = "debug", logerr)]
+pub fn temperature1(geopotential_altitude: Float, model: Model) -> Result<Float, InputError> {
+    let [temperature, _] = state(geopotential_altitude, model)?;
+    Ok(temperature)
+}
+
+///Formula for computing pressure in Pa of the standard atmosphere at given geopotential altitude.
+///
+///Provided in ISO 2533:1975 and U.S. Standard Atmosphere (1976), NOAA-S/T 76-1562.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `geopotential_altitude` range: -5000m - 80000m ([`Model::Isa`]), -5000m - 84852m ([`Model::Us76`])
+#[cfg_attr(feature = "debug", logerr)]
+pub fn pressure1(geopotential_altitude: Float, model: Model) -> Result<Float, InputError> {
+    let [_, pressure] = state(geopotential_altitude, model)?;
+    Ok(pressure)
+}
+
+///Formula for computing density of dry air in kg*m^-3 of the standard atmosphere at given geopotential altitude.
+///
+///Provided in ISO 2533:1975 and U.S. Standard Atmosphere (1976), NOAA-S/T 76-1562.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `geopotential_altitude` range: -5000m - 80000m ([`Model::Isa`]), -5000m - 84852m ([`Model::Us76`])
+#[cfg_attr(feature = "debug", logerr)]
+pub fn density1(geopotential_altitude: Float, model: Model) -> Result<Float, InputError> {
+    let [temperature, pressure] = state(geopotential_altitude, model)?;
+    Ok(pressure / (R_D * temperature))
+}
+
+///Formula for computing speed of sound in m*s^-1 of the standard atmosphere at given geopotential altitude.
+///
+///Ratio of specific heats of dry air is computed from [`C_P`](crate::constants::C_P)
+///and [`C_V`](crate::constants::C_V).
+///
+///Provided in ISO 2533:1975 and U.S. Standard Atmosphere (1976), NOAA-S/T 76-1562.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `geopotential_altitude` range: -5000m - 80000m ([`Model::Isa`]), -5000m - 84852m ([`Model::Us76`])
+#[cfg_attr(feature = "debug", logerr)]
+pub fn speed_of_sound1(geopotential_altitude: Float, model: Model) -> Result<Float, InputError> {
+    let [temperature, _] = state(geopotential_altitude, model)?;
+    Ok(((C_P / C_V) * R_D * temperature).sqrt())
+}
+
+///Formula for computing geopotential altitude in m at which the standard atmosphere has given pressure.
+///Reverse function of [`pressure1`].
+///
+///Provided in ISO 2533:1975 and U.S. Standard Atmosphere (1976), NOAA-S/T 76-1562.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `pressure` range: pressure of the standard atmosphere within its altitude range,
+///approximately 1.05Pa ([`Model::Isa`]) or 0.37Pa ([`Model::Us76`]) - 177687Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn altitude1(pressure: Float, model: Model) -> Result<Float, InputError> {
+    let [_, max_pressure] = state(MIN_ALTITUDE, model)?;
+    let [_, min_pressure] = state(model.max_altitude(), model)?;
+
+    if !(min_pressure..=max_pressure).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    let [lower_layers @ .., [last_base_altitude, last_lapse_rate]] = LAYERS;
+
+    let mut base_temperature = SEA_LEVEL_TEMPERATURE;
+    let mut base_pressure = SEA_LEVEL_PRESSURE;
+
+    for ([base_altitude, lapse_rate], &[top_altitude, _]) in
+        lower_layers.into_iter().zip(&LAYERS[1..])
+    {
+        let [top_temperature, top_pressure] = layer_state(
+            base_temperature,
+            base_pressure,
+            lapse_rate,
+            top_altitude - base_altitude,
+        );
+
+        //the lowest layer also covers altitudes below sea level
+        if pressure >= top_pressure {
+            return Ok(layer_altitude(
+                base_altitude,
+                base_temperature,
+                base_pressure,
+                lapse_rate,
+                pressure,
+            ));
+        }
+
+        base_temperature = top_temperature;
+        base_pressure = top_pressure;
+    }
+
+    Ok(layer_altitude(
+        last_base_altitude,
+        base_temperature,
+        base_pressure,
+        last_lapse_rate,
+        pressure,
+    ))
+}
+
+//returns temperature and pressure at given altitude
+fn state(geopotential_altitude: Float, model: Model) -> Result<[Float; 2], InputError> {
+    if !(MIN_ALTITUDE..=model.max_altitude()).contains(&geopotential_altitude) {
+        return Err(InputError::OutOfRange(String::from(
+            "geopotential_altitude",
+        )));
+    }
+
+    let [lower_layers @ .., [last_base_altitude, last_lapse_rate]] = LAYERS;
+
+    let mut base_temperature = SEA_LEVEL_TEMPERATURE;
+    let mut base_pressure = SEA_LEVEL_PRESSURE;
+
+    for ([base_altitude, lapse_rate], &[top_altitude, _]) in
+        lower_layers.into_iter().zip(&LAYERS[1..])
+    {
+        if geopotential_altitude <= top_altitude {
+            return Ok(layer_state(
+                base_temperature,
+                base_pressure,
+                lapse_rate,
+                geopotential_altitude - base_altitude,
+            ));
+        }
+
+        [base_temperature, base_pressure] = layer_state(
+            base_temperature,
+            base_pressure,
+            lapse_rate,
+            top_altitude - base_altitude,
+        );
+    }
+
+    Ok(layer_state(
+        base_temperature,
+        base_pressure,
+        last_lapse_rate,
+        geopotential_altitude - last_base_altitude,
+    ))
+}
+
+//integrates the hydrostatic equation over the layer with constant lapse rate
+fn layer_state(
+    base_temperature: Float,
+    base_pressure: Float,
+    lapse_rate: Float,
+    altitude_change: Float,
+) -> [Float; 2] {
+    let temperature = base_temperature + lapse_rate * altitude_change;
+
+    let pressure = if lapse_rate == 0.0 {
+        base_pressure * (-G * altitude_change / (R_D * base_temperature)).exp()
+    } else {
+        base_pressure * (temperature / base_temperature).powf(-G / (lapse_rate * R_D))
+    };
+
+    [temperature, pressure]
+}
+
+//inverts the hydrostatic equation over the layer with constant lapse rate
+fn layer_altitude(
+    base_altitude: Float,
+    base_temperature: Float,
+    base_pressure: Float,
+    lapse_rate: Float,
+    pressure: Float,
+) -> Float {
+    if lapse_rate == 0.0 {
+        base_altitude + (R_D * base_temperature / G) * (base_pressure / pressure).ln()
+    } else {
+        base_altitude
+            + (base_temperature / lapse_rate)
+                * ((pressure / base_pressure).powf(-lapse_rate * R_D / G) - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::InputError,
+        standard_atmosphere::{self, Model},
+        Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    //geopotential altitude, temperature and pressure from U.S. Standard Atmosphere (1976) tables
+    const REFERENCE: [[Float; 3]; 9] = [
+        [-5000.0, 320.65, 177_687.0],
+        [0.0, 288.15, 101_325.0],
+        [11_000.0, 216.65, 22_632.1],
+        [20_000.0, 216.65, 5474.89],
+        [32_000.0, 228.65, 868.019],
+        [47_000.0, 270.65, 110.906],
+        [51_000.0, 270.65, 66.9389],
+        [71_000.0, 214.65, 3.95642],
+        [84_852.0, 186.946, 0.373_384],
+    ];
+
+    #[test]
+    fn temperature1() {
+        for [altitude, temperature, _] in REFERENCE {
+            let result = standard_atmosphere::temperature1(altitude, Model::Us76).unwrap();
+            assert_approx_eq!(Float, result, temperature, epsilon = 0.01);
+        }
+
+        assert_eq!(
+            standard_atmosphere::temperature1(-5001.0, Model::Us76).unwrap_err(),
+            InputError::OutOfRange(String::from("geopotential_altitude"))
+        );
+        assert_eq!(
+            standard_atmosphere::temperature1(84_852.0, Model::Isa).unwrap_err(),
+            InputError::OutOfRange(String::from("geopotential_altitude"))
+        );
+    }
+
+    #[test]
+    fn pressure1() {
+        for [altitude, _, pressure] in REFERENCE {
+            let result = standard_atmosphere::pressure1(altitude, Model::Us76).unwrap();
+            assert_approx_eq!(Float, result, pressure, epsilon = 0.0005 * pressure);
+        }
+
+        let isa = standard_atmosphere::pressure1(5000.0, Model::Isa).unwrap();
+        let us76 = standard_atmosphere::pressure1(5000.0, Model::Us76).unwrap();
+        assert_approx_eq!(Float, isa, us76);
+    }
+
+    #[test]
+    fn density1() {
+        let result = standard_atmosphere::density1(0.0, Model::Isa).unwrap();
+        assert_approx_eq!(Float, result, 1.225, epsilon = 0.001);
+
+        let result = standard_atmosphere::density1(11_000.0, Model::Isa).unwrap();
+        assert_approx_eq!(Float, result, 0.363_918, epsilon = 0.001);
+    }
+
+    #[test]
+    fn speed_of_sound1() {
+        let result = standard_atmosphere::speed_of_sound1(0.0, Model::Isa).unwrap();
+        assert_approx_eq!(Float, result, 340.294, epsilon = 0.05);
+
+        let result = standard_atmosphere::speed_of_sound1(20_000.0, Model::Isa).unwrap();
+        assert_approx_eq!(Float, result, 295.070, epsilon = 0.05);
+    }
+
+    #[test]
+    fn altitude1() {
+        for model in [Model::Isa, Model::Us76] {
+            for altitude in [
+                -5000.0, -1000.0, 0.0, 5000.0, 11_000.0, 25_000.0, 50_000.0, 75_000.0,
+            ] {
+                let pressure = standard_atmosphere::pressure1(altitude, model).unwrap();
+                let result = standard_atmosphere::altitude1(pressure, model).unwrap();
+                assert_approx_eq!(Float, result, altitude, epsilon = 0.5);
+            }
+        }
+
+        let pressure = standard_atmosphere::pressure1(84_000.0, Model::Us76).unwrap();
+        assert_eq!(
+            standard_atmosphere::altitude1(pressure, Model::Isa).unwrap_err(),
+            InputError::OutOfRange(String::from("pressure"))
+        );
+        assert!(standard_atmosphere::altitude1(pressure, Model::Us76).is_ok());
+        assert_eq!(
+            standard_atmosphere::altitude1(200_000.0, Model::Us76).unwrap_err(),
+            InputError::OutOfRange(String::from("pressure"))
+        );
+    }
+}
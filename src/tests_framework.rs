@@ -38,10 +38,8 @@ pub fn test_with_2args(
         tested_function(0.0, 0.0),
     ];
 
-    for result in results {
-        if result.is_ok() {
-            assert!(result.unwrap().is_finite());
-        }
+    for result in results.into_iter().flatten() {
+        assert!(result.is_finite());
     }
 
     //the third promise of the crate is to always return finite f64
@@ -57,13 +55,12 @@ pub fn test_with_2args(
 
             let result = tested_function(arg1_tmp, arg2_tmp);
 
-            if result.is_err() {
-                assert!(
+            match result {
+                Err(err) => assert!(
                     discriminant(&InputError::IncorrectArgumentSet(String::from("")))
-                        == discriminant(&result.unwrap_err())
-                );
-            } else {
-                assert!(result.unwrap().is_finite());
+                        == discriminant(&err)
+                ),
+                Ok(result) => assert!(result.is_finite()),
             }
         }
     }
@@ -96,10 +93,8 @@ pub fn test_with_1arg(
 
     let results = vec![tested_function(0.0)];
 
-    for result in results {
-        if result.is_ok() {
-            assert!(result.unwrap().is_finite());
-        }
+    for result in results.into_iter().flatten() {
+        assert!(result.is_finite());
     }
 
     for arg1_itr in 0..=100 {
@@ -108,13 +103,12 @@ pub fn test_with_1arg(
 
         let result = tested_function(arg1_tmp);
 
-        if result.is_err() {
-            assert!(
+        match result {
+            Err(err) => assert!(
                 discriminant(&InputError::IncorrectArgumentSet(String::from("")))
-                    == discriminant(&result.unwrap_err())
-            );
-        } else {
-            assert!(result.unwrap().is_finite());
+                    == discriminant(&err)
+            ),
+            Ok(result) => assert!(result.is_finite()),
         }
     }
 
@@ -145,10 +139,8 @@ pub fn test_with_3args(
         tested_function(0.0, 0.0, 0.0),
     ];
 
-    for result in results {
-        if result.is_ok() {
-            assert!(result.unwrap().is_finite());
-        }
+    for result in results.into_iter().flatten() {
+        assert!(result.is_finite());
     }
 
     for arg1_itr in 0..=100 {
@@ -163,14 +155,15 @@ pub fn test_with_3args(
 
                 let result = tested_function(arg1_tmp, arg2_tmp, arg3_tmp);
 
-                if result.is_err() {
-                    assert!(
+                match result {
+                    Err(err) => assert!(
                         discriminant(&InputError::IncorrectArgumentSet(String::from("")))
-                            == discriminant(&result.unwrap_err())
-                    );
-                } else {
-                    println!("{} {} {} {:?}", arg1_tmp, arg2_tmp, arg3_tmp, result);
-                    assert!(result.unwrap().is_finite());
+                            == discriminant(&err)
+                    ),
+                    Ok(result) => {
+                        println!("{} {} {} {:?}", arg1_tmp, arg2_tmp, arg3_tmp, result);
+                        assert!(result.is_finite());
+                    }
                 }
             }
         }
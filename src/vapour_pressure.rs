@@ -5,12 +5,98 @@
 use crate::Float;
 use crate::{
     constants::{EPSILON, ZERO_CELSIUS},
+    dual::Number,
     errors::InputError,
 };
+use std::ops::{Add, Div, Mul, Sub};
 
 #[cfg(feature="debug")]
 use floccus_proc::logerr;
 
+///Type of psychrometer determining the psychrometer coefficient used in psychrometric formulas.
+///
+///Coefficients are provided in WMO (2018). Guide to Instruments and Methods of Observation (WMO-No. 8),
+///Volume I, Annex 4.B.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Psychrometer {
+    ///Aspirated psychrometer of Assmann type with ventilation of at least 2.2 m/s.
+    ///Coefficient is `6.53e-4 * (1 + 9.44e-4 * Tw) K^-1` over water (`Tw` in °C) and `5.75e-4 K^-1` over ice.
+    Aspirated,
+    ///Psychrometer in a naturally ventilated screen.
+    ///Coefficient is `7.99e-4 K^-1` over water and `7.20e-4 K^-1` over ice.
+    NonAspirated,
+    ///Psychrometer with custom coefficient (in K^-1) used both over water and over ice.
+    Custom(Float),
+}
+
+impl Psychrometer {
+    fn coefficient<N: Number>(self, bulb_temperature: N, ice_bulb: bool) -> N
+    where
+        Float: Add<N, Output = N> + Mul<N, Output = N>,
+    {
+        match (self, ice_bulb) {
+            (Psychrometer::Aspirated, false) => {
+                6.53e-4 * (1.0 + 9.44e-4 * (bulb_temperature - ZERO_CELSIUS))
+            }
+            (Psychrometer::Aspirated, true) => N::from_float(5.75e-4),
+            (Psychrometer::NonAspirated, false) => N::from_float(7.99e-4),
+            (Psychrometer::NonAspirated, true) => N::from_float(7.20e-4),
+            (Psychrometer::Custom(coefficient), _) => N::from_float(coefficient),
+        }
+    }
+}
+
+///Function blending saturation vapour pressure over water and over ice in mixed-phase formulas.
+///
+///In both functions the fraction of water `α` rises from 0 at 250.16K to 1 at 273.16K,
+///and saturation vapour pressure is computed as `α * e_water + (1 - α) * e_ice`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PhaseBlending {
+    ///Quadratic function `α = ((T - 250.16) / 23)^2` used in the ECMWF Integrated Forecasting System.
+    ///
+    ///Provided in ECMWF (2023). IFS Documentation CY48R1 - Part IV: Physical Processes, Chapter 12.
+    Ifs,
+    ///Linear function `α = (T - 250.16) / 23`.
+    Linear,
+}
+
+impl PhaseBlending {
+    //temperatures below which only ice and above which only water is present
+    const ICE_TEMPERATURE: Float = 250.16;
+    const WATER_TEMPERATURE: Float = 273.16;
+
+    pub(crate) fn water_fraction<N: Number>(self, temperature: N) -> N {
+        let fraction = if temperature.value() <= Self::ICE_TEMPERATURE {
+            N::from_float(0.0)
+        } else if temperature.value() >= Self::WATER_TEMPERATURE {
+            N::from_float(1.0)
+        } else {
+            (temperature - Self::ICE_TEMPERATURE)
+                / (Self::WATER_TEMPERATURE - Self::ICE_TEMPERATURE)
+        };
+
+        match self {
+            PhaseBlending::Ifs => fraction * fraction,
+            PhaseBlending::Linear => fraction,
+        }
+    }
+
+    //derivative of water fraction with respect to temperature
+    pub(crate) fn water_fraction_derivative(self, temperature: Float) -> Float {
+        if !(Self::ICE_TEMPERATURE..=Self::WATER_TEMPERATURE).contains(&temperature) {
+            return 0.0;
+        }
+
+        let width = Self::WATER_TEMPERATURE - Self::ICE_TEMPERATURE;
+        let fraction = (temperature - Self::ICE_TEMPERATURE) / width;
+
+        match self {
+            PhaseBlending::Ifs => 2.0 * fraction / width,
+            PhaseBlending::Linear => 1.0 / width,
+        }
+    }
+}
+
 ///Formula for computing vapour pressure from specific humidity and pressure.
 ///This function is theoretical not empirical.
 ///
@@ -22,13 +108,13 @@ use floccus_proc::logerr;
 ///Valid `specific_humidity` range: 0.00001 - 2.0\
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general1(specific_humidity: Float, pressure: Float) -> Result<Float, InputError> {
+pub fn general1<N: Number>(specific_humidity: N, pressure: N) -> Result<N, InputError> {
     //validate inputs
-    if !(0.00001..=2.0).contains(&specific_humidity) {
+    if !(0.00001..=2.0).contains(&specific_humidity.value()) {
         return Err(InputError::OutOfRange(String::from("specific_humidity")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -38,6 +124,32 @@ pub fn general1(specific_humidity: Float, pressure: Float) -> Result<Float, Inpu
     Ok(result)
 }
 
+///Formula for computing vapour pressure from mixing ratio and pressure.
+///This function is theoretical not empirical.
+///
+///Provided by [Rogers & Yau (1989)](https://www.elsevier.com/books/a-short-course-in-cloud-physics/yau/978-0-08-057094-5).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `mixing_ratio` range: 0.0 - 0.5\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn general2<N: Number>(mixing_ratio: N, pressure: N) -> Result<N, InputError> {
+    //validate inputs
+    if !(0.0..=0.5).contains(&mixing_ratio.value()) {
+        return Err(InputError::OutOfRange(String::from("mixing_ratio")));
+    }
+
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    let result = (pressure * mixing_ratio) / (mixing_ratio + EPSILON);
+
+    Ok(result)
+}
+
 ///Formula for computing vapour pressure from dewpoint temperature and pressure.
 ///Should be used for air over water when accuracy is desired.
 ///
@@ -48,13 +160,16 @@ pub fn general1(specific_humidity: Float, pressure: Float) -> Result<Float, Inpu
 ///Valid `dewpoint` range: 232K - 324K\
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn buck1(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
+pub fn buck1<N: Number>(dewpoint: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
     //validate inputs
-    if !(232.0..=324.0).contains(&dewpoint) {
+    if !(232.0..=324.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -72,7 +187,7 @@ pub fn buck1(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
 
     let lower_e =
         lower_a * (((lower_b - (dewpoint / lower_d)) * dewpoint) / (dewpoint + lower_c)).exp();
-    let lower_f = 1.0 + upper_a + (pressure * (upper_b + (upper_c * dewpoint * dewpoint)));
+    let lower_f = 1.0 + (upper_a + (pressure * (upper_b + (upper_c * dewpoint * dewpoint))));
 
     Ok((lower_e * lower_f) * 100.0) //return in Pa
 }
@@ -87,13 +202,16 @@ pub fn buck1(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
 ///Valid `dewpoint` range: 193K - 274K\
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn buck2(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
+pub fn buck2<N: Number>(dewpoint: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
     //validate inputs
-    if !(193.0..=274.0).contains(&dewpoint) {
+    if !(193.0..=274.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -111,7 +229,7 @@ pub fn buck2(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
 
     let lower_e =
         lower_a * (((lower_b - (dewpoint / lower_d)) * dewpoint) / (dewpoint + lower_c)).exp();
-    let lower_f = 1.0 + upper_a + (pressure * (upper_b + (upper_c * dewpoint * dewpoint)));
+    let lower_f = 1.0 + (upper_a + (pressure * (upper_b + (upper_c * dewpoint * dewpoint))));
 
     Ok((lower_e * lower_f) * 100.0) //return in Pa
 }
@@ -126,13 +244,16 @@ pub fn buck2(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
 ///Valid `dewpoint` range: 253K - 324K\
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn buck3(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
+pub fn buck3<N: Number>(dewpoint: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
     //validate inputs
-    if !(253.0..=324.0).contains(&dewpoint) {
+    if !(253.0..=324.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -147,7 +268,7 @@ pub fn buck3(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
     let upper_b = 0.000_003_46;
 
     let lower_e = lower_a * ((lower_b * dewpoint) / (dewpoint + lower_c)).exp();
-    let lower_f = 1.0 + upper_a + (pressure * upper_b);
+    let lower_f = 1.0 + (upper_a + (pressure * upper_b));
 
     Ok((lower_e * lower_f) * 100.0) //return in Pa
 }
@@ -161,9 +282,12 @@ pub fn buck3(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
 ///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
 ///Valid `dewpoint` range: 253K - 324K
 #[cfg_attr(feature = "debug", logerr)]
-pub fn buck3_simplified(dewpoint: Float) -> Result<Float, InputError> {
+pub fn buck3_simplified<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N>,
+{
     //validate inputs
-    if !(253.0..=324.0).contains(&dewpoint) {
+    if !(253.0..=324.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
@@ -188,13 +312,16 @@ pub fn buck3_simplified(dewpoint: Float) -> Result<Float, InputError> {
 ///Valid `dewpoint` range: 223K - 274K\
 ///Valid `pressure` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn buck4(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
+pub fn buck4<N: Number>(dewpoint: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
     //validate inputs
-    if !(223.0..=274.0).contains(&dewpoint) {
+    if !(223.0..=274.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -209,7 +336,7 @@ pub fn buck4(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
     let upper_b = 0.000_004_18;
 
     let lower_e = lower_a * ((lower_b * dewpoint) / (dewpoint + lower_c)).exp();
-    let lower_f = 1.0 + upper_a + (pressure * upper_b);
+    let lower_f = 1.0 + (upper_a + (pressure * upper_b));
 
     Ok((lower_e * lower_f) * 100.0) //return in Pa
 }
@@ -223,9 +350,12 @@ pub fn buck4(dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
 ///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
 ///Valid `dewpoint` range: 223K - 274K
 #[cfg_attr(feature = "debug", logerr)]
-pub fn buck4_simplified(dewpoint: Float) -> Result<Float, InputError> {
+pub fn buck4_simplified<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N>,
+{
     //validate inputs
-    if !(223.0..=274.0).contains(&dewpoint) {
+    if !(223.0..=274.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
@@ -250,9 +380,12 @@ pub fn buck4_simplified(dewpoint: Float) -> Result<Float, InputError> {
 ///Returns [`InputError::OutOfRange`] when input is out of range.\
 ///Valid `dewpoint` range: 273K - 353K
 #[cfg_attr(feature = "debug", logerr)]
-pub fn tetens1(dewpoint: Float) -> Result<Float, InputError> {
+pub fn tetens1<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N>,
+{
     //validate inputs
-    if !(273.0..=353.0).contains(&dewpoint) {
+    if !(273.0..=353.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
@@ -267,6 +400,31 @@ pub fn tetens1(dewpoint: Float) -> Result<Float, InputError> {
     Ok(result * 1000.0) //return in Pa
 }
 
+///Formula for computing vapour pressure over water from dewpoint temperature.
+///Simple formula used in the original definition of humidex ([`comfort_index::humidex1`](crate::comfort_index::humidex1)).
+///
+///Derived by J. M. Masterton and F. A. Richardson (1979). Humidex: a method of quantifying human discomfort
+///due to excessive heat and humidity. Environment Canada, CLI 1-79.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 232K - 324K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn masterton_richardson1<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(232.0..=324.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    let result = 611.0 * (5417.7530 * ((1.0 / 273.16) - dewpoint.recip())).exp();
+
+    Ok(result)
+}
+
 ///Formula for computing **ONLY** vapour pressure from saturation vapour pressure and relative humidity.
 ///For saturation vapour pressure use [`saturation_specific2`]
 ///
@@ -276,15 +434,15 @@ pub fn tetens1(dewpoint: Float) -> Result<Float, InputError> {
 ///Valid `saturation_vapour_pressure` range: 0Pa - 10000Pa\
 ///Valid `relative_humidity` range: 0.0 - 1.0
 #[cfg_attr(feature = "debug", logerr)]
-pub fn saturation_specific1(
-    saturation_vapour_pressure: Float,
-    relative_humidity: Float,
-) -> Result<Float, InputError> {
-    if !(0.0..=2.0).contains(&relative_humidity) {
+pub fn saturation_specific1<N: Number>(
+    saturation_vapour_pressure: N,
+    relative_humidity: N,
+) -> Result<N, InputError> {
+    if !(0.0..=2.0).contains(&relative_humidity.value()) {
         return Err(InputError::OutOfRange(String::from("relative_humidity")));
     }
 
-    if !(0.0..=50_000.0).contains(&saturation_vapour_pressure) {
+    if !(0.0..=50_000.0).contains(&saturation_vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from(
             "saturation_vapour_pressure",
         )));
@@ -302,15 +460,18 @@ pub fn saturation_specific1(
 ///Valid `vapour_pressure` range: 0Pa - 10000Pa\
 ///Valid `relative_humidity` range: 0.00001 - 1.0
 #[cfg_attr(feature = "debug", logerr)]
-pub fn saturation_specific2(
-    vapour_pressure: Float,
-    relative_humidity: Float,
-) -> Result<Float, InputError> {
-    if !(0.00001..=2.0).contains(&relative_humidity) {
+pub fn saturation_specific2<N: Number>(
+    vapour_pressure: N,
+    relative_humidity: N,
+) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N>,
+{
+    if !(0.00001..=2.0).contains(&relative_humidity.value()) {
         return Err(InputError::OutOfRange(String::from("relative_humidity")));
     }
 
-    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+    if !(0.0..=10_000.0).contains(&vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from("vapour_pressure")));
     }
 
@@ -328,8 +489,12 @@ pub fn saturation_specific2(
 ///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
 ///Valid `dewpoint` range: 273K - 374K
 #[cfg_attr(feature = "debug", logerr)]
-pub fn wexler1(dewpoint: Float) -> Result<Float, InputError> {
-    if !(273.0..=374.0).contains(&dewpoint) {
+#[allow(clippy::excessive_precision)]
+pub fn wexler1<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N>,
+{
+    if !(273.0..=374.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
@@ -347,8 +512,8 @@ pub fn wexler1(dewpoint: Float) -> Result<Float, InputError> {
 
     let mut ln_p = g[7] * dewpoint.ln();
 
-    for i in 0..=6 {
-        ln_p += g[i] * dewpoint.powi(i as i32 - 2);
+    for (i, g_i) in g.iter().enumerate().take(7) {
+        ln_p = ln_p + *g_i * dewpoint.powi(i as i32 - 2);
     }
 
     Ok(ln_p.exp())
@@ -365,8 +530,11 @@ pub fn wexler1(dewpoint: Float) -> Result<Float, InputError> {
 ///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
 ///Valid `dewpoint` range: 173K - 274K
 #[cfg_attr(feature = "debug", logerr)]
-pub fn wexler2(dewpoint: Float) -> Result<Float, InputError> {
-    if !(173.0..=274.0).contains(&dewpoint) {
+pub fn wexler2<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(173.0..=274.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
@@ -382,19 +550,570 @@ pub fn wexler2(dewpoint: Float) -> Result<Float, InputError> {
 
     let mut ln_p = big_k[5] * dewpoint.ln();
 
-    for j in 0..=4 {
-        ln_p += big_k[j] * dewpoint.powi(j as i32 - 1);
+    for (j, big_k_j) in big_k.iter().enumerate().take(5) {
+        ln_p = ln_p + *big_k_j * dewpoint.powi(j as i32 - 1);
     }
 
     Ok(ln_p.exp())
 }
 
+///Formula for computing vapour pressure over water from dewpoint temperature.
+///Historical reference formulation, which was recommended by the WMO.
+///
+///Derived by J. A. Goff and S. Gratch (1946). Low-pressure properties of water from -160 to 212 °F.
+///Transactions of the American Society of Heating and Ventilating Engineers, 52, 95-122.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 223K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn goff_gratch1<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(223.0..=374.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    //steam point temperature and pressure in hPa
+    let t_s = 373.16;
+    let e_s: Float = 1013.246;
+
+    let ratio = t_s / dewpoint;
+
+    let log_p = -7.90298 * (ratio - 1.0) + 5.02808 * ratio.log10()
+        - 1.3816e-7 * (pow10(11.344 * (1.0 - (dewpoint / t_s))) - 1.0)
+        + 8.1328e-3 * (pow10(-3.49149 * (ratio - 1.0)) - 1.0)
+        + e_s.log10();
+
+    Ok(pow10(log_p) * 100.0) //return in Pa
+}
+
+///Formula for computing vapour pressure over ice from dewpoint temperature.
+///Historical reference formulation, which was recommended by the WMO.
+///
+///Derived by J. A. Goff and S. Gratch (1946). Low-pressure properties of water from -160 to 212 °F.
+///Transactions of the American Society of Heating and Ventilating Engineers, 52, 95-122.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn goff_gratch2<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(173.0..=274.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    //ice point temperature and pressure in hPa
+    let t_0 = 273.16;
+    let e_0: Float = 6.1173;
+
+    let ratio = t_0 / dewpoint;
+
+    let log_p = -9.09718 * (ratio - 1.0) - 3.56654 * ratio.log10()
+        + 0.876793 * (1.0 - (dewpoint / t_0))
+        + e_0.log10();
+
+    Ok(pow10(log_p) * 100.0) //return in Pa
+}
+
+///Formula for computing vapour pressure over water from dewpoint temperature.
+///Formulation used in ASHRAE psychrometric tables, also valid for high temperatures.
+///
+///Derived by R. W. Hyland and A. Wexler (1983). Formulations for the thermodynamic properties
+///of the saturated phases of H2O from 173.15 K to 473.15 K. ASHRAE Transactions, 89(2A), 500-519.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 273K - 473K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn hyland_wexler1<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(273.0..=473.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    // constants from the paper
+    let c: [Float; 6] = [
+        -5.800_220_6e3,
+        1.391_499_3,
+        -4.864_023_9e-2,
+        4.176_476_8e-5,
+        -1.445_209_3e-8,
+        6.545_967_3,
+    ];
+
+    let ln_p = c[0] / dewpoint
+        + c[1]
+        + c[2] * dewpoint
+        + c[3] * dewpoint.powi(2)
+        + c[4] * dewpoint.powi(3)
+        + c[5] * dewpoint.ln();
+
+    Ok(ln_p.exp())
+}
+
+///Formula for computing vapour pressure over ice from dewpoint temperature.
+///Formulation used in ASHRAE psychrometric tables.
+///
+///Derived by R. W. Hyland and A. Wexler (1983). Formulations for the thermodynamic properties
+///of the saturated phases of H2O from 173.15 K to 473.15 K. ASHRAE Transactions, 89(2A), 500-519.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn hyland_wexler2<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(173.0..=274.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    // constants from the paper
+    let c: [Float; 7] = [
+        -5.674_535_9e3,
+        6.392_524_7,
+        -9.677_843e-3,
+        6.221_570_1e-7,
+        2.074_782_5e-9,
+        -9.484_024e-13,
+        4.163_501_9,
+    ];
+
+    let ln_p = c[0] / dewpoint
+        + c[1]
+        + c[2] * dewpoint
+        + c[3] * dewpoint.powi(2)
+        + c[4] * dewpoint.powi(3)
+        + c[5] * dewpoint.powi(4)
+        + c[6] * dewpoint.ln();
+
+    Ok(ln_p.exp())
+}
+
+///Formula for computing vapour pressure over water (including supercooled water) from dewpoint temperature.
+///Formulation based on the ITS-90 temperature scale.
+///
+///Derived by D. Sonntag (1990). Important new values of the physical constants of 1986, vapour pressure
+///formulations based on the ITS-90, and psychrometer formulae. Zeitschrift für Meteorologie, 40(5), 340-344.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 173K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn sonntag1<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(173.0..=374.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    let ln_p = -6096.9385 / dewpoint + 16.635_794 - 2.711_193e-2 * dewpoint
+        + 1.673_952e-5 * dewpoint.powi(2)
+        + 2.433_502 * dewpoint.ln();
+
+    Ok(ln_p.exp() * 100.0) //return in Pa
+}
+
+///Formula for computing vapour pressure over ice from dewpoint temperature.
+///Formulation based on the ITS-90 temperature scale.
+///
+///Derived by D. Sonntag (1990). Important new values of the physical constants of 1986, vapour pressure
+///formulations based on the ITS-90, and psychrometer formulae. Zeitschrift für Meteorologie, 40(5), 340-344.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn sonntag2<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(173.0..=274.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    let ln_p = -6024.5282 / dewpoint + 24.7219 + 1.061_386_8e-2 * dewpoint
+        - 1.319_882_5e-5 * dewpoint.powi(2)
+        - 0.493_825_77 * dewpoint.ln();
+
+    Ok(ln_p.exp() * 100.0) //return in Pa
+}
+
+///Formula for computing vapour pressure over water (including supercooled water) from dewpoint temperature.
+///Should be used for supercooled water at very low temperatures.
+///
+///Derived by D. M. Murphy and T. Koop (2005) [(doi:10.1256/qj.04.94)](https://doi.org/10.1256/qj.04.94).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 123K - 332K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn murphy_koop1<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(123.0..=332.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    let ln_p = 54.842_763 - 6763.22 / dewpoint - 4.210 * dewpoint.ln()
+        + 0.000_367 * dewpoint
+        + (0.0415 * (dewpoint - 218.8)).tanh()
+            * (53.878 - 1331.22 / dewpoint - 9.445_23 * dewpoint.ln() + 0.014_025 * dewpoint);
+
+    Ok(ln_p.exp())
+}
+
+///Formula for computing vapour pressure over ice from dewpoint temperature.
+///Should be used for ice at very low temperatures.
+///
+///Derived by D. M. Murphy and T. Koop (2005) [(doi:10.1256/qj.04.94)](https://doi.org/10.1256/qj.04.94).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 110K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn murphy_koop2<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(110.0..=274.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    let ln_p = 9.550_426 - 5723.265 / dewpoint + 3.530_68 * dewpoint.ln() - 0.007_283_32 * dewpoint;
+
+    Ok(ln_p.exp())
+}
+
+///Formula for computing vapour pressure over water from dewpoint temperature.
+///Simple formula with accuracy comparable to the reference formulations.
+///
+///Derived by J. Huang (2018) [(doi:10.1175/JAMC-D-17-0334.1)](https://doi.org/10.1175/JAMC-D-17-0334.1).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 273K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn huang1<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Div<N, Output = N>,
+{
+    if !(273.0..=374.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    let dewpoint = dewpoint - ZERO_CELSIUS; //convert to C
+
+    let result = (34.494 - 4924.99 / (dewpoint + 237.1)).exp() / (dewpoint + 105.0).powf(1.57);
+
+    Ok(result)
+}
+
+///Formula for computing vapour pressure over ice from dewpoint temperature.
+///Simple formula with accuracy comparable to the reference formulations.
+///
+///Derived by J. Huang (2018) [(doi:10.1175/JAMC-D-17-0334.1)](https://doi.org/10.1175/JAMC-D-17-0334.1).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn huang2<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Div<N, Output = N>,
+{
+    if !(173.0..=274.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    let dewpoint = dewpoint - ZERO_CELSIUS; //convert to C
+
+    let result = (43.494 - 6545.8 / (dewpoint + 278.0)).exp() / (dewpoint + 868.0).powi(2);
+
+    Ok(result)
+}
+
+///Reference formula for computing saturation vapour pressure over water from temperature,
+///valid from the triple point up to the critical point of water.
+///
+///This is the auxiliary equation for the vapour-liquid phase boundary of the IAPWS-95 formulation
+///(IAPWS SR1-86(1992)), and should be used as a reference when accuracy is most important.
+///
+///Derived by W. Wagner and A. Pruß (2002) [(doi:10.1063/1.1461829)](https://doi.org/10.1063/1.1461829).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 273.16K - 647.096K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn iapws1<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(273.16..=647.096).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    //critical point temperature and pressure
+    let t_c = 647.096;
+    let p_c = 22.064e6;
+
+    // constants from the paper
+    let a: [Float; 6] = [
+        -7.859_517_83,
+        1.844_082_59,
+        -11.786_649_7,
+        22.680_741_1,
+        -15.961_871_9,
+        1.801_225_02,
+    ];
+
+    let tau = 1.0 - (dewpoint / t_c);
+
+    let ln_p = (t_c / dewpoint)
+        * (a[0] * tau
+            + a[1] * tau.powf(1.5)
+            + a[2] * tau.powi(3)
+            + a[3] * tau.powf(3.5)
+            + a[4] * tau.powi(4)
+            + a[5] * tau.powf(7.5));
+
+    Ok(p_c * ln_p.exp())
+}
+
+///Reference formula for computing saturation vapour pressure over ice (sublimation pressure) from temperature.
+///
+///This is the sublimation pressure equation of the IAPWS R14-08(2011) release,
+///and should be used as a reference when accuracy is most important.
+///
+///Derived by W. Wagner et al. (2011) [(doi:10.1063/1.3657937)](https://doi.org/10.1063/1.3657937).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 50K - 273.16K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn iapws2<N: Number>(dewpoint: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(50.0..=273.16).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    //triple point temperature and pressure
+    let t_t = 273.16;
+    let p_t = 611.657;
+
+    // constants from the paper
+    let a: [Float; 3] = [-21.214_400_6, 27.320_381_9, -6.105_981_30];
+    let b: [Float; 3] = [0.003_333_333_33, 1.206_666_67, 1.703_333_33];
+
+    let theta = dewpoint / t_t;
+
+    let ln_p =
+        (a[0] * theta.powf(b[0]) + a[1] * theta.powf(b[1]) + a[2] * theta.powf(b[2])) / theta;
+
+    Ok(p_t * ln_p.exp())
+}
+
+///Formula for computing mixed-phase vapour pressure from dewpoint temperature.
+///Should be used when saturation over water and ice is blended between 250.16K and 273.16K,
+///as in numerical weather prediction models.
+///
+///Vapour pressure over water is computed with [`murphy_koop1`] and over ice with [`murphy_koop2`],
+///then both are combined with the chosen [`PhaseBlending`] function.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `dewpoint` range: 110K - 332K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn mixed_phase1<N: Number>(dewpoint: N, blending: PhaseBlending) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(110.0..=332.0).contains(&dewpoint.value()) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    let water_fraction = blending.water_fraction(dewpoint);
+
+    if water_fraction.value() >= 1.0 {
+        return murphy_koop1(dewpoint);
+    }
+
+    if water_fraction.value() <= 0.0 {
+        return murphy_koop2(dewpoint);
+    }
+
+    let water = murphy_koop1(dewpoint)?;
+    let ice = murphy_koop2(dewpoint)?;
+
+    Ok(water_fraction * water + (1.0 - water_fraction) * ice)
+}
+
+///Formula for computing vapour pressure from dry bulb and wet bulb temperatures and pressure
+///measured with psychrometer.
+///
+///Saturation vapour pressure at wet bulb temperature is computed with [`buck1`].
+///
+///Provided in WMO (2018). Guide to Instruments and Methods of Observation (WMO-No. 8), Volume I, Annex 4.B.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K\
+///Valid `wet_bulb_temperature` range: 232K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `psychrometer` coefficient range (only for [`Psychrometer::Custom`]): 0.0 - 0.002
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `wet_bulb_temperature` is higher than `temperature`
+///or when the readings give negative vapour pressure.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn psychrometric1<N: Number>(
+    temperature: N,
+    wet_bulb_temperature: N,
+    pressure: N,
+    psychrometer: Psychrometer,
+) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(232.0..=324.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(232.0..=324.0).contains(&wet_bulb_temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("wet_bulb_temperature")));
+    }
+
+    let saturation_vapour_pressure = buck1(wet_bulb_temperature, pressure)?;
+
+    psychrometric(
+        temperature,
+        wet_bulb_temperature,
+        pressure,
+        saturation_vapour_pressure,
+        psychrometer.coefficient(wet_bulb_temperature, false),
+    )
+}
+
+///Formula for computing vapour pressure from dry bulb and ice bulb temperatures and pressure
+///measured with psychrometer which wet bulb is covered with ice.
+///
+///Saturation vapour pressure over ice at ice bulb temperature is computed with [`buck2`].
+///
+///Provided in WMO (2018). Guide to Instruments and Methods of Observation (WMO-No. 8), Volume I, Annex 4.B.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 193K - 324K\
+///Valid `ice_bulb_temperature` range: 193K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `psychrometer` coefficient range (only for [`Psychrometer::Custom`]): 0.0 - 0.002
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `ice_bulb_temperature` is higher than `temperature`
+///or when the readings give negative vapour pressure.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn psychrometric2<N: Number>(
+    temperature: N,
+    ice_bulb_temperature: N,
+    pressure: N,
+    psychrometer: Psychrometer,
+) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Sub<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(193.0..=324.0).contains(&temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(193.0..=274.0).contains(&ice_bulb_temperature.value()) {
+        return Err(InputError::OutOfRange(String::from("ice_bulb_temperature")));
+    }
+
+    let saturation_vapour_pressure = buck2(ice_bulb_temperature, pressure)?;
+
+    psychrometric(
+        temperature,
+        ice_bulb_temperature,
+        pressure,
+        saturation_vapour_pressure,
+        psychrometer.coefficient(ice_bulb_temperature, true),
+    )
+}
+
+//psychrometer equation shared by wet bulb and ice bulb formulas
+fn psychrometric<N: Number>(
+    temperature: N,
+    bulb_temperature: N,
+    pressure: N,
+    saturation_vapour_pressure: N,
+    coefficient: N,
+) -> Result<N, InputError> {
+    if !(0.0..=0.002).contains(&coefficient.value()) {
+        return Err(InputError::OutOfRange(String::from("psychrometer")));
+    }
+
+    if bulb_temperature.value() > temperature.value() {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "bulb temperature cannot be higher than temperature",
+        )));
+    }
+
+    let result =
+        saturation_vapour_pressure - coefficient * pressure * (temperature - bulb_temperature);
+
+    if result.value() < 0.0 {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "psychrometer readings give negative vapour pressure",
+        )));
+    }
+
+    Ok(result)
+}
+
+//10 raised to the power of the number
+fn pow10<N: Number>(exponent: N) -> N {
+    (exponent * Float::ln(10.0)).exp()
+}
+
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
+        errors::InputError,
         tests_framework::{self, Argument},
-        vapour_pressure,
+        vapour_pressure::{self, PhaseBlending, Psychrometer},
+        Float,
     };
+    use float_cmp::assert_approx_eq;
 
     #[test]
     fn general1() {
@@ -414,6 +1133,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn general2() {
+        assert!(tests_framework::test_with_2args(
+            &vapour_pressure::general2,
+            Argument {
+                name: "mixing_ratio",
+                def_val: 0.022,
+                range: [0.0, 0.5]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 101325.0,
+                range: [100.0, 150_000.0]
+            },
+            3461.5195
+        ));
+    }
+
     #[test]
     fn buck1() {
         assert!(tests_framework::test_with_2args(
@@ -525,6 +1262,19 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn masterton_richardson1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::masterton_richardson1,
+            Argument {
+                name: "dewpoint",
+                def_val: 300.0,
+                range: [232.0, 324.0]
+            },
+            3603.083458886106
+        ));
+    }
+
     #[test]
     fn saturation_specific1() {
         assert!(tests_framework::test_with_2args(
@@ -586,4 +1336,363 @@ mod tests {
             76.04351136780438
         ));
     }
+
+    #[test]
+    fn psychrometric1() {
+        let result =
+            vapour_pressure::psychrometric1(293.15, 288.15, 100_000.0, Psychrometer::Aspirated)
+                .unwrap();
+        assert_approx_eq!(Float, result, 1380.870, epsilon = 0.01);
+
+        let result =
+            vapour_pressure::psychrometric1(293.15, 288.15, 100_000.0, Psychrometer::NonAspirated)
+                .unwrap();
+        assert_approx_eq!(Float, result, 1312.494, epsilon = 0.01);
+
+        let result =
+            vapour_pressure::psychrometric1(293.15, 288.15, 100_000.0, Psychrometer::Custom(0.0))
+                .unwrap();
+        let expected = vapour_pressure::buck1(288.15, 100_000.0).unwrap();
+        assert_approx_eq!(Float, result, expected, epsilon = 0.01);
+
+        assert_eq!(
+            vapour_pressure::psychrometric1(293.15, 288.15, 100_000.0, Psychrometer::Custom(0.1))
+                .unwrap_err(),
+            InputError::OutOfRange(String::from("psychrometer"))
+        );
+        assert!(matches!(
+            vapour_pressure::psychrometric1(288.15, 293.15, 100_000.0, Psychrometer::Aspirated),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+        assert!(matches!(
+            vapour_pressure::psychrometric1(313.15, 253.15, 100_000.0, Psychrometer::Aspirated),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+
+    #[test]
+    fn psychrometric2() {
+        let result =
+            vapour_pressure::psychrometric2(268.15, 266.15, 100_000.0, Psychrometer::Aspirated)
+                .unwrap();
+        assert_approx_eq!(Float, result, 224.618, epsilon = 0.01);
+
+        let result =
+            vapour_pressure::psychrometric2(268.15, 266.15, 100_000.0, Psychrometer::NonAspirated)
+                .unwrap();
+        assert_approx_eq!(Float, result, 195.618, epsilon = 0.01);
+
+        assert_eq!(
+            vapour_pressure::psychrometric2(268.15, 275.15, 100_000.0, Psychrometer::Aspirated)
+                .unwrap_err(),
+            InputError::OutOfRange(String::from("ice_bulb_temperature"))
+        );
+    }
+
+    #[test]
+    fn goff_gratch1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::goff_gratch1,
+            Argument {
+                name: "dewpoint",
+                def_val: 300.0,
+                range: [223.0, 374.0]
+            },
+            3531.5148665394636
+        ));
+    }
+
+    #[test]
+    fn goff_gratch2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::goff_gratch2,
+            Argument {
+                name: "dewpoint",
+                def_val: 250.0,
+                range: [173.0, 274.0]
+            },
+            76.01621375187358
+        ));
+    }
+
+    #[test]
+    fn hyland_wexler1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::hyland_wexler1,
+            Argument {
+                name: "dewpoint",
+                def_val: 300.0,
+                range: [273.0, 473.0]
+            },
+            3536.013026606885
+        ));
+    }
+
+    #[test]
+    fn hyland_wexler2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::hyland_wexler2,
+            Argument {
+                name: "dewpoint",
+                def_val: 250.0,
+                range: [173.0, 274.0]
+            },
+            76.03030152549353
+        ));
+    }
+
+    #[test]
+    fn sonntag1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::sonntag1,
+            Argument {
+                name: "dewpoint",
+                def_val: 300.0,
+                range: [173.0, 374.0]
+            },
+            3536.8073204180023
+        ));
+    }
+
+    #[test]
+    fn sonntag2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::sonntag2,
+            Argument {
+                name: "dewpoint",
+                def_val: 250.0,
+                range: [173.0, 274.0]
+            },
+            76.00948492924687
+        ));
+    }
+
+    #[test]
+    fn murphy_koop1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::murphy_koop1,
+            Argument {
+                name: "dewpoint",
+                def_val: 300.0,
+                range: [123.0, 332.0]
+            },
+            3536.7644130514645
+        ));
+    }
+
+    #[test]
+    fn murphy_koop2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::murphy_koop2,
+            Argument {
+                name: "dewpoint",
+                def_val: 250.0,
+                range: [110.0, 274.0]
+            },
+            76.02389003659836
+        ));
+    }
+
+    #[test]
+    fn huang1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::huang1,
+            Argument {
+                name: "dewpoint",
+                def_val: 300.0,
+                range: [273.0, 374.0]
+            },
+            3536.833350158609
+        ));
+    }
+
+    #[test]
+    fn huang2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::huang2,
+            Argument {
+                name: "dewpoint",
+                def_val: 250.0,
+                range: [173.0, 274.0]
+            },
+            76.00271874790698
+        ));
+    }
+
+    #[test]
+    fn saturation_reference_values() {
+        //check values provided by Murphy and Koop (2005)
+        for (temperature, expected) in [
+            (150.0, 6.1061e-6),
+            (180.0, 0.0053975),
+            (210.0, 0.70202),
+            (240.0, 27.272),
+        ] {
+            let result = vapour_pressure::murphy_koop2(temperature).unwrap();
+            assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.0001);
+        }
+
+        //modern formulations agree on the triple point pressure of 611.657Pa
+        let triple_point = 273.16;
+        let water: [fn(Float) -> Result<Float, InputError>; 3] = [
+            vapour_pressure::hyland_wexler1,
+            vapour_pressure::sonntag1,
+            vapour_pressure::murphy_koop1,
+        ];
+        let ice: [fn(Float) -> Result<Float, InputError>; 3] = [
+            vapour_pressure::hyland_wexler2,
+            vapour_pressure::sonntag2,
+            vapour_pressure::murphy_koop2,
+        ];
+
+        for formula in water.iter().chain(ice.iter()) {
+            let result = formula(triple_point).unwrap();
+            assert_approx_eq!(Float, result, 611.657, epsilon = 0.01);
+        }
+
+        //all formulations agree within 0.2% at room temperature
+        let water: [fn(Float) -> Result<Float, InputError>; 6] = [
+            vapour_pressure::wexler1,
+            vapour_pressure::goff_gratch1,
+            vapour_pressure::hyland_wexler1,
+            vapour_pressure::sonntag1,
+            vapour_pressure::murphy_koop1,
+            vapour_pressure::huang1,
+        ];
+
+        for formula in water {
+            let result = formula(293.15).unwrap();
+            assert_approx_eq!(Float, result / 2339.2, 1.0, epsilon = 0.002);
+        }
+
+        let ice: [fn(Float) -> Result<Float, InputError>; 5] = [
+            vapour_pressure::wexler2,
+            vapour_pressure::goff_gratch2,
+            vapour_pressure::hyland_wexler2,
+            vapour_pressure::sonntag2,
+            vapour_pressure::huang2,
+        ];
+
+        for formula in ice {
+            let result = formula(233.15).unwrap();
+            assert_approx_eq!(Float, result / 12.84, 1.0, epsilon = 0.002);
+        }
+    }
+
+    #[test]
+    fn iapws1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::iapws1,
+            Argument {
+                name: "dewpoint",
+                def_val: 300.0,
+                range: [273.16, 647.096]
+            },
+            3536.7175865049244
+        ));
+
+        //verification values of IAPWS SR1-86(1992) and IAPWS-95 releases
+        for (temperature, expected) in [
+            (273.16, 611.657),
+            (275.0, 698.451_167),
+            (373.1243, 101_325.0),
+            (450.0, 932_203.564),
+            (625.0, 16_908_269.3),
+            (647.096, 22_064_000.0),
+        ] {
+            let result = vapour_pressure::iapws1(temperature).unwrap();
+            assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn iapws2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure::iapws2,
+            Argument {
+                name: "dewpoint",
+                def_val: 250.0,
+                range: [50.0, 273.16]
+            },
+            76.01266951024671
+        ));
+
+        //verification values of IAPWS R14-08(2011) release
+        for (temperature, expected) in [(230.0, 8.947_352_740_189), (273.16, 611.657)] {
+            let result = vapour_pressure::iapws2(temperature).unwrap();
+            assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn mixed_phase1() {
+        for (blending, expected) in [
+            (PhaseBlending::Ifs, 200.717_268_645_347_25),
+            (PhaseBlending::Linear, 207.267_742_802_230_7),
+        ] {
+            let result = vapour_pressure::mixed_phase1(260.0, blending).unwrap();
+            assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.00001);
+
+            //only water above and only ice below the blending range
+            let water = vapour_pressure::mixed_phase1(280.0, blending).unwrap();
+            let expected = vapour_pressure::murphy_koop1(280.0).unwrap();
+            assert_approx_eq!(Float, water, expected, epsilon = 0.01);
+
+            let ice = vapour_pressure::mixed_phase1(240.0, blending).unwrap();
+            let expected = vapour_pressure::murphy_koop2(240.0).unwrap();
+            assert_approx_eq!(Float, ice, expected, epsilon = 0.01);
+
+            for dewpoint in [100.0, 340.0] {
+                assert_eq!(
+                    vapour_pressure::mixed_phase1(dewpoint, blending).unwrap_err(),
+                    InputError::OutOfRange(String::from("dewpoint"))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn empirical_formulas_against_iapws() {
+        let water = [
+            (vapour_pressure::wexler1 as fn(_) -> _, 373.15),
+            (vapour_pressure::goff_gratch1, 373.15),
+            (vapour_pressure::hyland_wexler1, 373.15),
+            (vapour_pressure::sonntag1, 373.15),
+            (vapour_pressure::murphy_koop1, 332.0),
+            (vapour_pressure::huang1, 373.15),
+        ];
+
+        for (formula, max_temperature) in water {
+            let mut temperature = 273.16;
+
+            while temperature <= max_temperature {
+                let expected = vapour_pressure::iapws1(temperature).unwrap();
+                let result = formula(temperature).unwrap();
+                assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.002);
+
+                temperature += 1.0;
+            }
+        }
+
+        let ice: [fn(Float) -> Result<Float, InputError>; 6] = [
+            vapour_pressure::wexler2,
+            vapour_pressure::goff_gratch2,
+            vapour_pressure::hyland_wexler2,
+            vapour_pressure::sonntag2,
+            vapour_pressure::murphy_koop2,
+            vapour_pressure::huang2,
+        ];
+
+        for formula in ice {
+            let mut temperature = 173.16;
+
+            while temperature <= 273.16 {
+                let expected = vapour_pressure::iapws2(temperature).unwrap();
+                let result = formula(temperature).unwrap();
+                assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.002);
+
+                temperature += 1.0;
+            }
+        }
+    }
 }
@@ -4,8 +4,9 @@
 //!the amount of moisture in the air and how much moisture the air can hold
 //!when it is saturated ([Wikipedia](https://en.wikipedia.org/wiki/Vapour-pressure_deficit)).
 
-use crate::{errors::InputError, vapour_pressure};
+use crate::{dual::Number, errors::InputError, vapour_pressure};
 use crate::Float;
+use std::ops::{Add, Mul};
 
 #[cfg(feature="debug")]
 use floccus_proc::logerr;
@@ -18,12 +19,15 @@ use floccus_proc::logerr;
 ///Valid `vapour_pressure` range: 0Pa - 10000Pa
 ///Valid `saturation_vapour_pressure` range: 0Pa - 10000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general1(vapour_pressure: Float, saturation_vapour_pressure: Float) -> Result<Float, InputError> {
-    if !(0.0..=50_000.0).contains(&vapour_pressure) {
+pub fn general1<N: Number>(
+    vapour_pressure: N,
+    saturation_vapour_pressure: N,
+) -> Result<N, InputError> {
+    if !(0.0..=50_000.0).contains(&vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from("vapour_pressure")));
     }
 
-    if !(0.0..=50_000.0).contains(&saturation_vapour_pressure) {
+    if !(0.0..=50_000.0).contains(&saturation_vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from(
             "saturation_vapour_pressure",
         )));
@@ -41,16 +45,19 @@ pub fn general1(vapour_pressure: Float, saturation_vapour_pressure: Float) -> Re
 ///Valid `vapour_pressure` range: 0Pa - 10000Pa
 ///Valid `saturation_vapour_pressure` range: 0Pa - 10000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general2(temperature: Float, dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
-    if !(253.0..=324.0).contains(&temperature) {
+pub fn general2<N: Number>(temperature: N, dewpoint: N, pressure: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(253.0..=324.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(253.0..=324.0).contains(&dewpoint) {
+    if !(253.0..=324.0).contains(&dewpoint.value()) {
         return Err(InputError::OutOfRange(String::from("dewpoint")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -71,20 +78,23 @@ pub fn general2(temperature: Float, dewpoint: Float, pressure: Float) -> Result<
 ///Valid `vapour_pressure` range: 0Pa - 10000Pa
 ///Valid `saturation_vapour_pressure` range: 0Pa - 10000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general3(
-    temperature: Float,
-    relative_humidity: Float,
-    pressure: Float,
-) -> Result<Float, InputError> {
-    if !(253.0..=319.0).contains(&temperature) {
+pub fn general3<N: Number>(
+    temperature: N,
+    relative_humidity: N,
+    pressure: N,
+) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(253.0..=319.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(0.05..=1.0).contains(&relative_humidity) {
+    if !(0.05..=1.0).contains(&relative_humidity.value()) {
         return Err(InputError::OutOfRange(String::from("relative_humidity")));
     }
 
-    if !(10000.0..=150_000.0).contains(&pressure) {
+    if !(10000.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
@@ -98,6 +108,7 @@ pub fn general3(
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
         tests_framework::{self, Argument},
@@ -0,0 +1,1460 @@
+//!Functions to calculate slope of the saturation vapour pressure curve in Pa*K^-1.
+//!
+//!Each function is the analytic derivative with respect to temperature of the saturation
+//!vapour pressure formula with the same name in [`vapour_pressure`] module, so it should be
+//!used together with that formula (eg. in Newton solvers or Penman-type evaporation formulas).
+//!Functions with `_pressure` suffix are derivatives with respect to pressure (in Pa*Pa^-1)
+//!of formulas including enhancement factor.
+//!
+//![`clausius_clapeyron1`] and [`clausius_clapeyron2`] provide the theoretical reference slope.
+
+use crate::Float;
+use crate::{
+    constants::{L_V, R_V, ZERO_CELSIUS},
+    errors::InputError,
+    latent_heat,
+    vapour_pressure::{self, PhaseBlending},
+};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing slope of the saturation vapour pressure curve from temperature and saturation vapour pressure.
+///This function is theoretical not empirical.
+///
+///Derived from the Clausius-Clapeyron equation with constant latent heat of vaporization
+///([`L_V`]) and water vapour treated as an ideal gas.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 173K - 374K\
+///Valid `saturation_vapour_pressure` range: 0Pa - 110000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn clausius_clapeyron1(
+    temperature: Float,
+    saturation_vapour_pressure: Float,
+) -> Result<Float, InputError> {
+    if !(173.0..=374.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(0.0..=110_000.0).contains(&saturation_vapour_pressure) {
+        return Err(InputError::OutOfRange(String::from(
+            "saturation_vapour_pressure",
+        )));
+    }
+
+    let result = (L_V * saturation_vapour_pressure) / (R_V * temperature * temperature);
+
+    Ok(result)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve from temperature and saturation vapour pressure.
+///This function is theoretical not empirical.
+///
+///Derived from the Clausius-Clapeyron equation with temperature-dependent latent heat of vaporization
+///([`latent_heat::vaporisation1`]) and water vapour treated as an ideal gas.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 173K - 374K\
+///Valid `saturation_vapour_pressure` range: 0Pa - 110000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn clausius_clapeyron2(
+    temperature: Float,
+    saturation_vapour_pressure: Float,
+) -> Result<Float, InputError> {
+    if !(0.0..=110_000.0).contains(&saturation_vapour_pressure) {
+        return Err(InputError::OutOfRange(String::from(
+            "saturation_vapour_pressure",
+        )));
+    }
+
+    let latent_heat = latent_heat::vaporisation1(temperature)?;
+
+    let result = (latent_heat * saturation_vapour_pressure) / (R_V * temperature * temperature);
+
+    Ok(result)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature and pressure.
+///Derivative of [`vapour_pressure::buck1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck1(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(232.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck1(temperature, pressure)?;
+
+    let result = saturation_vapour_pressure
+        * buck_ln_slope(
+            temperature,
+            pressure,
+            [18.729, 257.87, 227.3],
+            [0.000_72, 0.000_003_2, 5.9e-10],
+        );
+
+    Ok(result)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature and pressure.
+///Derivative of [`vapour_pressure::buck2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 193K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck2(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(193.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck2(temperature, pressure)?;
+
+    let result = saturation_vapour_pressure
+        * buck_ln_slope(
+            temperature,
+            pressure,
+            [23.036, 279.82, 333.7],
+            [0.000_22, 0.000_003_83, 6.4e-10],
+        );
+
+    Ok(result)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature and pressure.
+///Derivative of [`vapour_pressure::buck3`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 253K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck3(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(253.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck3(temperature, pressure)?;
+
+    Ok(saturation_vapour_pressure * magnus_ln_slope(temperature, 17.502, 240.97))
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature.
+///Derivative of [`vapour_pressure::buck3_simplified`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 253K - 324K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck3_simplified(temperature: Float) -> Result<Float, InputError> {
+    if !(253.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck3_simplified(temperature)?;
+
+    Ok(saturation_vapour_pressure * magnus_ln_slope(temperature, 17.502, 240.97))
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature and pressure.
+///Derivative of [`vapour_pressure::buck4`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 223K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck4(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(223.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck4(temperature, pressure)?;
+
+    Ok(saturation_vapour_pressure * magnus_ln_slope(temperature, 22.452, 272.55))
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature.
+///Derivative of [`vapour_pressure::buck4_simplified`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 223K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck4_simplified(temperature: Float) -> Result<Float, InputError> {
+    if !(223.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck4_simplified(temperature)?;
+
+    Ok(saturation_vapour_pressure * magnus_ln_slope(temperature, 22.452, 272.55))
+}
+
+///Formula for computing derivative of saturation vapour pressure over water with respect to pressure
+///from temperature and pressure. Derivative of [`vapour_pressure::buck1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 232K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck1_pressure(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(232.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck1(temperature, pressure)?;
+
+    let result = saturation_vapour_pressure
+        * buck_ln_pressure_slope(temperature, pressure, [0.000_72, 0.000_003_2, 5.9e-10]);
+
+    Ok(result)
+}
+
+///Formula for computing derivative of saturation vapour pressure over ice with respect to pressure
+///from temperature and pressure. Derivative of [`vapour_pressure::buck2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 193K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck2_pressure(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(193.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck2(temperature, pressure)?;
+
+    let result = saturation_vapour_pressure
+        * buck_ln_pressure_slope(temperature, pressure, [0.000_22, 0.000_003_83, 6.4e-10]);
+
+    Ok(result)
+}
+
+///Formula for computing derivative of saturation vapour pressure over water with respect to pressure
+///from temperature and pressure. Derivative of [`vapour_pressure::buck3`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 253K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck3_pressure(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(253.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck3(temperature, pressure)?;
+
+    let result = saturation_vapour_pressure
+        * buck_ln_pressure_slope(ZERO_CELSIUS, pressure, [0.000_7, 0.000_003_46, 0.0]);
+
+    Ok(result)
+}
+
+///Formula for computing derivative of saturation vapour pressure over ice with respect to pressure
+///from temperature and pressure. Derivative of [`vapour_pressure::buck4`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 223K - 274K\
+///Valid `pressure` range: 100Pa - 150000Pa
+#[cfg_attr(feature = "debug", logerr)]
+pub fn buck4_pressure(temperature: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(223.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::buck4(temperature, pressure)?;
+
+    let result = saturation_vapour_pressure
+        * buck_ln_pressure_slope(ZERO_CELSIUS, pressure, [0.000_3, 0.000_004_18, 0.0]);
+
+    Ok(result)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature.
+///Derivative of [`vapour_pressure::tetens1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 273K - 353K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn tetens1(temperature: Float) -> Result<Float, InputError> {
+    if !(273.0..=353.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::tetens1(temperature)?;
+
+    Ok(saturation_vapour_pressure * magnus_ln_slope(temperature, 17.27, 237.3))
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature.
+///Derivative of [`vapour_pressure::masterton_richardson1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 232K - 324K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn masterton_richardson1(temperature: Float) -> Result<Float, InputError> {
+    if !(232.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::masterton_richardson1(temperature)?;
+
+    Ok(saturation_vapour_pressure * 5417.7530 / temperature.powi(2))
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature.
+///Derivative of [`vapour_pressure::wexler1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 273K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn wexler1(temperature: Float) -> Result<Float, InputError> {
+    if !(273.0..=374.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::wexler1(temperature)?;
+
+    // constants from the paper
+    let g: [Float; 8] = [
+        -2991.2729,
+        -6017.0128,
+        18.87643854,
+        -0.028354721,
+        0.0000178383,
+        -0.00000000084150417,
+        0.00000000000044412543,
+        2.858487,
+    ];
+
+    let mut ln_slope = g[7] / temperature;
+
+    for (i, g_i) in g.iter().enumerate().take(7) {
+        ln_slope += (i as Float - 2.0) * g_i * temperature.powi(i as i32 - 3);
+    }
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature.
+///Derivative of [`vapour_pressure::wexler2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn wexler2(temperature: Float) -> Result<Float, InputError> {
+    if !(173.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::wexler2(temperature)?;
+
+    // constants from the paper
+    let big_k: [Float; 6] = [
+        -5865.3696,
+        22.241033,
+        0.013749042,
+        -0.00003403177,
+        0.000000026967687,
+        0.6918651,
+    ];
+
+    let mut ln_slope = big_k[5] / temperature;
+
+    for (j, big_k_j) in big_k.iter().enumerate().take(5) {
+        ln_slope += (j as Float - 1.0) * big_k_j * temperature.powi(j as i32 - 2);
+    }
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature.
+///Derivative of [`vapour_pressure::goff_gratch1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 223K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn goff_gratch1(temperature: Float) -> Result<Float, InputError> {
+    if !(223.0..=374.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::goff_gratch1(temperature)?;
+
+    //steam point temperature
+    let t_s = 373.16;
+    let ln_10 = Float::ln(10.0);
+
+    let log_slope = 7.90298 * t_s / temperature.powi(2) - 5.02808 / (temperature * ln_10)
+        + 1.3816e-7 * Float::powf(10.0, 11.344 * (1.0 - temperature / t_s)) * ln_10 * 11.344 / t_s
+        + 8.1328e-3
+            * Float::powf(10.0, -3.49149 * (t_s / temperature - 1.0))
+            * ln_10
+            * 3.49149
+            * t_s
+            / temperature.powi(2);
+
+    Ok(saturation_vapour_pressure * log_slope * ln_10)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature.
+///Derivative of [`vapour_pressure::goff_gratch2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn goff_gratch2(temperature: Float) -> Result<Float, InputError> {
+    if !(173.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::goff_gratch2(temperature)?;
+
+    //ice point temperature
+    let t_0 = 273.16;
+    let ln_10 = Float::ln(10.0);
+
+    let log_slope =
+        9.09718 * t_0 / temperature.powi(2) + 3.56654 / (temperature * ln_10) - 0.876793 / t_0;
+
+    Ok(saturation_vapour_pressure * log_slope * ln_10)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature.
+///Derivative of [`vapour_pressure::hyland_wexler1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 273K - 473K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn hyland_wexler1(temperature: Float) -> Result<Float, InputError> {
+    if !(273.0..=473.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::hyland_wexler1(temperature)?;
+
+    // constants from the paper
+    let c: [Float; 6] = [
+        -5.800_220_6e3,
+        1.391_499_3,
+        -4.864_023_9e-2,
+        4.176_476_8e-5,
+        -1.445_209_3e-8,
+        6.545_967_3,
+    ];
+
+    let ln_slope = -c[0] / temperature.powi(2)
+        + c[2]
+        + 2.0 * c[3] * temperature
+        + 3.0 * c[4] * temperature.powi(2)
+        + c[5] / temperature;
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature.
+///Derivative of [`vapour_pressure::hyland_wexler2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn hyland_wexler2(temperature: Float) -> Result<Float, InputError> {
+    if !(173.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::hyland_wexler2(temperature)?;
+
+    // constants from the paper
+    let c: [Float; 7] = [
+        -5.674_535_9e3,
+        6.392_524_7,
+        -9.677_843e-3,
+        6.221_570_1e-7,
+        2.074_782_5e-9,
+        -9.484_024e-13,
+        4.163_501_9,
+    ];
+
+    let ln_slope = -c[0] / temperature.powi(2)
+        + c[2]
+        + 2.0 * c[3] * temperature
+        + 3.0 * c[4] * temperature.powi(2)
+        + 4.0 * c[5] * temperature.powi(3)
+        + c[6] / temperature;
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water (including supercooled water)
+///from temperature. Derivative of [`vapour_pressure::sonntag1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 173K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn sonntag1(temperature: Float) -> Result<Float, InputError> {
+    if !(173.0..=374.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::sonntag1(temperature)?;
+
+    let ln_slope = 6096.9385 / temperature.powi(2) - 2.711_193e-2
+        + 2.0 * 1.673_952e-5 * temperature
+        + 2.433_502 / temperature;
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature.
+///Derivative of [`vapour_pressure::sonntag2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn sonntag2(temperature: Float) -> Result<Float, InputError> {
+    if !(173.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::sonntag2(temperature)?;
+
+    let ln_slope = 6024.5282 / temperature.powi(2) + 1.061_386_8e-2
+        - 2.0 * 1.319_882_5e-5 * temperature
+        - 0.493_825_77 / temperature;
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water (including supercooled water)
+///from temperature. Derivative of [`vapour_pressure::murphy_koop1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 123K - 332K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn murphy_koop1(temperature: Float) -> Result<Float, InputError> {
+    if !(123.0..=332.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::murphy_koop1(temperature)?;
+
+    let tanh = (0.0415 * (temperature - 218.8)).tanh();
+    let correction =
+        53.878 - 1331.22 / temperature - 9.445_23 * temperature.ln() + 0.014_025 * temperature;
+    let correction_slope = 1331.22 / temperature.powi(2) - 9.445_23 / temperature + 0.014_025;
+
+    let ln_slope = 6763.22 / temperature.powi(2) - 4.210 / temperature
+        + 0.000_367
+        + 0.0415 * (1.0 - tanh * tanh) * correction
+        + tanh * correction_slope;
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature.
+///Derivative of [`vapour_pressure::murphy_koop2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 110K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn murphy_koop2(temperature: Float) -> Result<Float, InputError> {
+    if !(110.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::murphy_koop2(temperature)?;
+
+    let ln_slope = 5723.265 / temperature.powi(2) + 3.530_68 / temperature - 0.007_283_32;
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature.
+///Derivative of [`vapour_pressure::huang1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 273K - 374K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn huang1(temperature: Float) -> Result<Float, InputError> {
+    if !(273.0..=374.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::huang1(temperature)?;
+
+    let temperature = temperature - ZERO_CELSIUS; //convert to C
+
+    let ln_slope = 4924.99 / (temperature + 237.1).powi(2) - 1.57 / (temperature + 105.0);
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature.
+///Derivative of [`vapour_pressure::huang2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 173K - 274K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn huang2(temperature: Float) -> Result<Float, InputError> {
+    if !(173.0..=274.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::huang2(temperature)?;
+
+    let temperature = temperature - ZERO_CELSIUS; //convert to C
+
+    let ln_slope = 6545.8 / (temperature + 278.0).powi(2) - 2.0 / (temperature + 868.0);
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over water from temperature.
+///Derivative of [`vapour_pressure::iapws1`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 273.16K - 647.096K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn iapws1(temperature: Float) -> Result<Float, InputError> {
+    if !(273.16..=647.096).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::iapws1(temperature)?;
+
+    //critical point temperature
+    let t_c = 647.096;
+
+    // constants from the paper
+    let a: [Float; 6] = [
+        -7.859_517_83,
+        1.844_082_59,
+        -11.786_649_7,
+        22.680_741_1,
+        -15.961_871_9,
+        1.801_225_02,
+    ];
+
+    let tau = 1.0 - temperature / t_c;
+
+    let sum = a[0] * tau
+        + a[1] * tau.powf(1.5)
+        + a[2] * tau.powi(3)
+        + a[3] * tau.powf(3.5)
+        + a[4] * tau.powi(4)
+        + a[5] * tau.powf(7.5);
+
+    let sum_slope = a[0]
+        + 1.5 * a[1] * tau.sqrt()
+        + 3.0 * a[2] * tau.powi(2)
+        + 3.5 * a[3] * tau.powf(2.5)
+        + 4.0 * a[4] * tau.powi(3)
+        + 7.5 * a[5] * tau.powf(6.5);
+
+    let ln_slope = -(t_c * sum / temperature + sum_slope) / temperature;
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the saturation vapour pressure curve over ice from temperature.
+///Derivative of [`vapour_pressure::iapws2`].
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 50K - 273.16K
+#[cfg_attr(feature = "debug", logerr)]
+#[allow(clippy::excessive_precision)]
+pub fn iapws2(temperature: Float) -> Result<Float, InputError> {
+    if !(50.0..=273.16).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::iapws2(temperature)?;
+
+    //triple point temperature
+    let t_t = 273.16;
+
+    // constants from the paper
+    let a: [Float; 3] = [-21.214_400_6, 27.320_381_9, -6.105_981_30];
+    let b: [Float; 3] = [0.003_333_333_33, 1.206_666_67, 1.703_333_33];
+
+    let theta = temperature / t_t;
+
+    let ln_slope = a
+        .iter()
+        .zip(b.iter())
+        .map(|(a_i, b_i)| a_i * (b_i - 1.0) * theta.powf(b_i - 2.0))
+        .sum::<Float>()
+        / t_t;
+
+    Ok(saturation_vapour_pressure * ln_slope)
+}
+
+///Formula for computing slope of the mixed-phase saturation vapour pressure curve from temperature.
+///Derivative of [`vapour_pressure::mixed_phase1`], including the change of blending fraction.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when input is out of range.\
+///Valid `temperature` range: 110K - 332K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn mixed_phase1(temperature: Float, blending: PhaseBlending) -> Result<Float, InputError> {
+    if !(110.0..=332.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    let water_fraction = blending.water_fraction(temperature);
+
+    if water_fraction >= 1.0 {
+        return murphy_koop1(temperature);
+    }
+
+    if water_fraction <= 0.0 {
+        return murphy_koop2(temperature);
+    }
+
+    let water = vapour_pressure::murphy_koop1(temperature)?;
+    let ice = vapour_pressure::murphy_koop2(temperature)?;
+
+    let result = blending.water_fraction_derivative(temperature) * (water - ice)
+        + water_fraction * murphy_koop1(temperature)?
+        + (1.0 - water_fraction) * murphy_koop2(temperature)?;
+
+    Ok(result)
+}
+
+//derivative of logarithm of vapour pressure in Magnus form `a * exp(b * t / (t + c))`
+fn magnus_ln_slope(temperature: Float, b: Float, c: Float) -> Float {
+    let temperature = temperature - ZERO_CELSIUS; //convert to C
+
+    (b * c) / (temperature + c).powi(2)
+}
+
+//derivative of logarithm of vapour pressure in Buck form `a * exp((b - t / d) * t / (t + c)) * f`
+fn buck_ln_slope(
+    temperature: Float,
+    pressure: Float,
+    [b, c, d]: [Float; 3],
+    enhancement: [Float; 3],
+) -> Float {
+    let temperature = temperature - ZERO_CELSIUS; //convert to C
+    let pressure = pressure / 100.0; //convert to hPa
+
+    let exponent_slope = ((b - 2.0 * temperature / d) * (temperature + c)
+        - (b - temperature / d) * temperature)
+        / (temperature + c).powi(2);
+
+    let enhancement_factor = 1.0
+        + enhancement[0]
+        + pressure * (enhancement[1] + enhancement[2] * temperature * temperature);
+    let enhancement_slope = 2.0 * pressure * enhancement[2] * temperature;
+
+    exponent_slope + enhancement_slope / enhancement_factor
+}
+
+//derivative of logarithm of vapour pressure in Buck form with respect to pressure in Pa
+fn buck_ln_pressure_slope(temperature: Float, pressure: Float, enhancement: [Float; 3]) -> Float {
+    let temperature = temperature - ZERO_CELSIUS; //convert to C
+    let pressure = pressure / 100.0; //convert to hPa
+
+    let pressure_term = enhancement[1] + enhancement[2] * temperature * temperature;
+    let enhancement_factor = 1.0 + enhancement[0] + pressure * pressure_term;
+
+    pressure_term / (100.0 * enhancement_factor)
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use crate::{
+        errors::InputError,
+        tests_framework::{self, Argument},
+        vapour_pressure::{self, PhaseBlending},
+        vapour_pressure_slope, Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    //compares analytic slope with central finite difference of the formula
+    fn check_finite_difference(
+        slope: impl Fn(Float) -> Result<Float, InputError>,
+        formula: impl Fn(Float) -> Result<Float, InputError>,
+        temperature: Float,
+    ) {
+        let step = 0.1;
+        let expected = (formula(temperature + step).unwrap()
+            - formula(temperature - step).unwrap())
+            / (2.0 * step);
+        let result = slope(temperature).unwrap();
+
+        assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.001);
+    }
+
+    #[test]
+    fn clausius_clapeyron1() {
+        assert!(tests_framework::test_with_2args(
+            &vapour_pressure_slope::clausius_clapeyron1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [173.0, 374.0]
+            },
+            Argument {
+                name: "saturation_vapour_pressure",
+                def_val: 3500.0,
+                range: [0.0, 110_000.0]
+            },
+            210.722_74
+        ));
+
+        //with latent heat at 0C reference slope is close to empirical formulas
+        let saturation_vapour_pressure = vapour_pressure::iapws1(273.16).unwrap();
+        let result =
+            vapour_pressure_slope::clausius_clapeyron1(273.16, saturation_vapour_pressure).unwrap();
+        let expected = vapour_pressure_slope::iapws1(273.16).unwrap();
+        assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.005);
+    }
+
+    #[test]
+    fn clausius_clapeyron2() {
+        assert!(tests_framework::test_with_2args(
+            &vapour_pressure_slope::clausius_clapeyron2,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [173.0, 374.0]
+            },
+            Argument {
+                name: "saturation_vapour_pressure",
+                def_val: 3500.0,
+                range: [0.0, 110_000.0]
+            },
+            205.356_46
+        ));
+
+        //with temperature-dependent latent heat slope stays close to empirical formulas when warm
+        for temperature in [273.16, 300.0, 330.0] {
+            let saturation_vapour_pressure = vapour_pressure::iapws1(temperature).unwrap();
+            let result =
+                vapour_pressure_slope::clausius_clapeyron2(temperature, saturation_vapour_pressure)
+                    .unwrap();
+            let expected = vapour_pressure_slope::iapws1(temperature).unwrap();
+            assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.005);
+        }
+    }
+
+    #[test]
+    fn buck1() {
+        assert!(tests_framework::test_with_2args(
+            &vapour_pressure_slope::buck1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [232.0, 324.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 101325.0,
+                range: [100.0, 150_000.0]
+            },
+            208.844_28
+        ));
+
+        for temperature in [235.0, 300.0, 320.0] {
+            check_finite_difference(
+                |t| vapour_pressure_slope::buck1(t, 80000.0),
+                |t| vapour_pressure::buck1(t, 80000.0),
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn buck2() {
+        assert!(tests_framework::test_with_2args(
+            &vapour_pressure_slope::buck2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [193.0, 274.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 101325.0,
+                range: [100.0, 150_000.0]
+            },
+            7.514_985
+        ));
+
+        for temperature in [195.0, 250.0, 273.0] {
+            check_finite_difference(
+                |t| vapour_pressure_slope::buck2(t, 80000.0),
+                |t| vapour_pressure::buck2(t, 80000.0),
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn buck3() {
+        assert!(tests_framework::test_with_2args(
+            &vapour_pressure_slope::buck3,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [253.0, 324.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 101325.0,
+                range: [100.0, 150_000.0]
+            },
+            208.645_95
+        ));
+
+        for temperature in [255.0, 300.0, 320.0] {
+            check_finite_difference(
+                |t| vapour_pressure_slope::buck3(t, 80000.0),
+                |t| vapour_pressure::buck3(t, 80000.0),
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn buck4() {
+        assert!(tests_framework::test_with_2args(
+            &vapour_pressure_slope::buck4,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [223.0, 274.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 101325.0,
+                range: [100.0, 150_000.0]
+            },
+            7.514_965
+        ));
+
+        for temperature in [225.0, 250.0, 273.0] {
+            check_finite_difference(
+                |t| vapour_pressure_slope::buck4(t, 80000.0),
+                |t| vapour_pressure::buck4(t, 80000.0),
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn buck3_simplified() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::buck3_simplified,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [253.0, 324.0]
+            },
+            207.772_09
+        ));
+
+        for temperature in [255.0, 300.0, 320.0] {
+            check_finite_difference(
+                vapour_pressure_slope::buck3_simplified,
+                vapour_pressure::buck3_simplified,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn buck4_simplified() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::buck4_simplified,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [223.0, 274.0]
+            },
+            7.481_035
+        ));
+
+        for temperature in [225.0, 250.0, 273.0] {
+            check_finite_difference(
+                vapour_pressure_slope::buck4_simplified,
+                vapour_pressure::buck4_simplified,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn tetens1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::tetens1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [273.0, 353.0]
+            },
+            207.563_79
+        ));
+
+        for temperature in [275.0, 300.0, 350.0] {
+            check_finite_difference(
+                vapour_pressure_slope::tetens1,
+                vapour_pressure::tetens1,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn masterton_richardson1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::masterton_richardson1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [232.0, 324.0]
+            },
+            216.895_74
+        ));
+
+        for temperature in [235.0, 273.0, 320.0] {
+            check_finite_difference(
+                vapour_pressure_slope::masterton_richardson1,
+                vapour_pressure::masterton_richardson1,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn wexler1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::wexler1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [273.0, 374.0]
+            },
+            207.793_04
+        ));
+
+        for temperature in [275.0, 300.0, 370.0] {
+            check_finite_difference(
+                vapour_pressure_slope::wexler1,
+                vapour_pressure::wexler1,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn wexler2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::wexler2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [173.0, 274.0]
+            },
+            7.482_907
+        ));
+
+        for temperature in [175.0, 250.0, 273.0] {
+            check_finite_difference(
+                vapour_pressure_slope::wexler2,
+                vapour_pressure::wexler2,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn goff_gratch1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::goff_gratch1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [223.0, 374.0]
+            },
+            207.587_12
+        ));
+
+        for temperature in [225.0, 300.0, 370.0] {
+            check_finite_difference(
+                vapour_pressure_slope::goff_gratch1,
+                vapour_pressure::goff_gratch1,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn goff_gratch2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::goff_gratch2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [173.0, 274.0]
+            },
+            7.481_937
+        ));
+
+        for temperature in [175.0, 250.0, 273.0] {
+            check_finite_difference(
+                vapour_pressure_slope::goff_gratch2,
+                vapour_pressure::goff_gratch2,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn hyland_wexler1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::hyland_wexler1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [273.0, 473.0]
+            },
+            207.858_67
+        ));
+
+        for temperature in [275.0, 300.0, 470.0] {
+            check_finite_difference(
+                vapour_pressure_slope::hyland_wexler1,
+                vapour_pressure::hyland_wexler1,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn hyland_wexler2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::hyland_wexler2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [173.0, 274.0]
+            },
+            7.482_108
+        ));
+
+        for temperature in [175.0, 250.0, 273.0] {
+            check_finite_difference(
+                vapour_pressure_slope::hyland_wexler2,
+                vapour_pressure::hyland_wexler2,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn sonntag1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::sonntag1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [173.0, 374.0]
+            },
+            207.919_06
+        ));
+
+        for temperature in [175.0, 300.0, 370.0] {
+            check_finite_difference(
+                vapour_pressure_slope::sonntag1,
+                vapour_pressure::sonntag1,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn sonntag2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::sonntag2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [173.0, 274.0]
+            },
+            7.481_735
+        ));
+
+        for temperature in [175.0, 250.0, 273.0] {
+            check_finite_difference(
+                vapour_pressure_slope::sonntag2,
+                vapour_pressure::sonntag2,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn murphy_koop1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::murphy_koop1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [123.0, 332.0]
+            },
+            207.873_06
+        ));
+
+        for temperature in [125.0, 220.0, 300.0, 330.0] {
+            check_finite_difference(
+                vapour_pressure_slope::murphy_koop1,
+                vapour_pressure::murphy_koop1,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn murphy_koop2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::murphy_koop2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [110.0, 274.0]
+            },
+            7.481_636
+        ));
+
+        for temperature in [115.0, 250.0, 273.0] {
+            check_finite_difference(
+                vapour_pressure_slope::murphy_koop2,
+                vapour_pressure::murphy_koop2,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn huang1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::huang1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [273.0, 374.0]
+            },
+            207.906_34
+        ));
+
+        for temperature in [275.0, 300.0, 370.0] {
+            check_finite_difference(
+                vapour_pressure_slope::huang1,
+                vapour_pressure::huang1,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn huang2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::huang2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [173.0, 274.0]
+            },
+            7.479_971
+        ));
+
+        for temperature in [175.0, 250.0, 273.0] {
+            check_finite_difference(
+                vapour_pressure_slope::huang2,
+                vapour_pressure::huang2,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn iapws1() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::iapws1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [273.16, 647.096]
+            },
+            207.913_26
+        ));
+
+        for temperature in [275.0, 300.0, 500.0, 640.0] {
+            check_finite_difference(
+                vapour_pressure_slope::iapws1,
+                vapour_pressure::iapws1,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn iapws2() {
+        assert!(tests_framework::test_with_1arg(
+            &vapour_pressure_slope::iapws2,
+            Argument {
+                name: "temperature",
+                def_val: 250.0,
+                range: [50.0, 273.16]
+            },
+            7.480_95
+        ));
+
+        for temperature in [100.0, 250.0, 273.0] {
+            check_finite_difference(
+                vapour_pressure_slope::iapws2,
+                vapour_pressure::iapws2,
+                temperature,
+            );
+        }
+    }
+
+    #[test]
+    fn buck1_pressure() {
+        let result = vapour_pressure_slope::buck1_pressure(300.0, 101325.0).unwrap();
+        assert_approx_eq!(Float, result / 0.000_128_160_6, 1.0, epsilon = 0.0001);
+
+        let step = 1000.0;
+        let expected = (vapour_pressure::buck1(300.0, 101325.0 + step).unwrap()
+            - vapour_pressure::buck1(300.0, 101325.0 - step).unwrap())
+            / (2.0 * step);
+        assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn buck2_pressure() {
+        let result = vapour_pressure_slope::buck2_pressure(250.0, 101325.0).unwrap();
+        assert_approx_eq!(Float, result / 3.173_54e-6, 1.0, epsilon = 0.0001);
+
+        let step = 1000.0;
+        let expected = (vapour_pressure::buck2(250.0, 101325.0 + step).unwrap()
+            - vapour_pressure::buck2(250.0, 101325.0 - step).unwrap())
+            / (2.0 * step);
+        assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn buck3_pressure() {
+        let result = vapour_pressure_slope::buck3_pressure(300.0, 101325.0).unwrap();
+        assert_approx_eq!(Float, result / 0.000_122_264_02, 1.0, epsilon = 0.0001);
+
+        let step = 1000.0;
+        let expected = (vapour_pressure::buck3(300.0, 101325.0 + step).unwrap()
+            - vapour_pressure::buck3(300.0, 101325.0 - step).unwrap())
+            / (2.0 * step);
+        assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn buck4_pressure() {
+        let result = vapour_pressure_slope::buck4_pressure(250.0, 101325.0).unwrap();
+        assert_approx_eq!(Float, result / 3.178_555e-6, 1.0, epsilon = 0.0001);
+
+        let step = 1000.0;
+        let expected = (vapour_pressure::buck4(250.0, 101325.0 + step).unwrap()
+            - vapour_pressure::buck4(250.0, 101325.0 - step).unwrap())
+            / (2.0 * step);
+        assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn mixed_phase1() {
+        for (blending, expected) in [
+            (PhaseBlending::Ifs, 18.854_268),
+            (PhaseBlending::Linear, 19.085_13),
+        ] {
+            let result = vapour_pressure_slope::mixed_phase1(260.0, blending).unwrap();
+            assert_approx_eq!(Float, result / expected, 1.0, epsilon = 0.0001);
+
+            for temperature in [240.0, 255.0, 260.0, 270.0, 290.0] {
+                check_finite_difference(
+                    |t| vapour_pressure_slope::mixed_phase1(t, blending),
+                    |t| vapour_pressure::mixed_phase1(t, blending),
+                    temperature,
+                );
+            }
+        }
+
+        assert_eq!(
+            vapour_pressure_slope::mixed_phase1(100.0, PhaseBlending::Ifs).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+    }
+}
@@ -4,8 +4,9 @@
 //!at which a theoretical dry air parcel would have a total pressure and density equal
 //!to the moist parcel of air ([Wikipedia](https://en.wikipedia.org/wiki/Virtual_temperature)).
 
-use crate::{constants::EPSILON, errors::InputError};
+use crate::{constants::EPSILON, dual::Number, errors::InputError};
 use crate::Float;
+use std::ops::{Add, Mul, Sub};
 
 #[cfg(feature="debug")]
 use floccus_proc::logerr;
@@ -18,12 +19,15 @@ use floccus_proc::logerr;
 ///Valid `temperature` range: 173K - 373K\
 ///Valid `mixing_ratio` range: 0.0000000001 - 0.5
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general1(temperature: Float, mixing_ratio: Float) -> Result<Float, InputError> {
-    if !(173.0..=354.0).contains(&temperature) {
+pub fn general1<N: Number>(temperature: N, mixing_ratio: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N> + Mul<N, Output = N>,
+{
+    if !(173.0..=354.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(0.000_000_000_1..=0.5).contains(&mixing_ratio) {
+    if !(0.000_000_000_1..=0.5).contains(&mixing_ratio.value()) {
         return Err(InputError::OutOfRange(String::from("mixing_ratio")));
     }
 
@@ -41,20 +45,25 @@ pub fn general1(temperature: Float, mixing_ratio: Float) -> Result<Float, InputE
 ///Valid `pressure` range: 100Pa - 150000Pa\
 ///Valid `vapour_pressure` range: 0Pa - 10000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general2(temperature: Float, pressure: Float, vapour_pressure: Float) -> Result<Float, InputError> {
-    if !(173.0..=354.0).contains(&temperature) {
+pub fn general2<N: Number>(temperature: N, pressure: N, vapour_pressure: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N>,
+{
+    if !(173.0..=354.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(100.0..=150_000.0).contains(&pressure) {
+    if !(100.0..=150_000.0).contains(&pressure.value()) {
         return Err(InputError::OutOfRange(String::from("pressure")));
     }
 
-    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+    if !(0.0..=10_000.0).contains(&vapour_pressure.value()) {
         return Err(InputError::OutOfRange(String::from("vapour_pressure")));
     }
 
-    let result = temperature / (1.0 - ((vapour_pressure / pressure) * (1.0 - EPSILON)));
+    //constant is converted to N, as the bound on Float also applies to Float - Float subtraction
+    let result =
+        temperature / (1.0 - ((vapour_pressure / pressure) * (1.0 - N::from_float(EPSILON))));
 
     Ok(result)
 }
@@ -67,12 +76,15 @@ pub fn general2(temperature: Float, pressure: Float, vapour_pressure: Float) ->
 ///Valid `temperature` range: 173K - 373K\
 ///Valid `specific_humidity` range: 100Pa - 150000Pa
 #[cfg_attr(feature = "debug", logerr)]
-pub fn general3(temperature: Float, specific_humidity: Float) -> Result<Float, InputError> {
-    if !(173.0..=354.0).contains(&temperature) {
+pub fn general3<N: Number>(temperature: N, specific_humidity: N) -> Result<N, InputError>
+where
+    Float: Add<N, Output = N>,
+{
+    if !(173.0..=354.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(0.000000001..=2.0).contains(&specific_humidity) {
+    if !(0.000000001..=2.0).contains(&specific_humidity.value()) {
         return Err(InputError::OutOfRange(String::from("specific_humidity")));
     }
 
@@ -82,6 +94,7 @@ pub fn general3(temperature: Float, specific_humidity: Float) -> Result<Float, I
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
         tests_framework::{self, Argument},
@@ -0,0 +1,653 @@
+//!Functions to calculate wet bulb globe temperature (WBGT) and its components in K.
+//!
+//!Wet bulb globe temperature is the heat stress index used in occupational safety,
+//!combining air (dry bulb) temperature, natural wet bulb temperature and black globe temperature
+//![Wikipedia](https://en.wikipedia.org/wiki/Wet-bulb_globe_temperature).
+//!
+//!Natural wet bulb temperature is the temperature of a wetted wick exposed to ambient wind and radiation,
+//!and it differs from the (psychrometric) wet bulb temperature computed in [`wet_bulb_temperature`](crate::wet_bulb_temperature) module.
+//!Globe temperature is the temperature inside a black globe of 6 inches diameter exposed to ambient wind and radiation.
+//!
+//!Natural wet bulb and globe temperatures can be estimated from meteorological data with the model
+//!of J. C. Liljegren et al. (2008) [(doi:10.1080/15459620802310770)](https://doi.org/10.1080/15459620802310770)
+//!([`natural_wet_bulb_temperature1`], [`globe_temperature1`] and [`liljegren1`]).
+//!In this model wind speed measured at 10m is reduced to the 2m height of the instruments
+//!with the power law for neutral stability over rural terrain.
+//!Fraction of direct solar radiation is estimated from the ratio of solar radiation
+//!to the solar radiation at the top of the atmosphere.
+
+use crate::errors::InputError;
+use crate::Float;
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing outdoor wet bulb globe temperature from
+///dry bulb, natural wet bulb and globe temperatures.
+///
+///Derived by C. P. Yaglou and D. Minard (1957). Control of heat casualties at military training centers.
+///AMA Archives of Industrial Health, 16(4), 302-316. Also provided in ISO 7243:2017.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 233K - 333K\
+///Valid `natural_wet_bulb_temperature` range: 233K - 333K\
+///Valid `globe_temperature` range: 233K - 373K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn outdoor1(
+    temperature: Float,
+    natural_wet_bulb_temperature: Float,
+    globe_temperature: Float,
+) -> Result<Float, InputError> {
+    if !(233.0..=333.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    validate_components(natural_wet_bulb_temperature, globe_temperature)?;
+
+    let result = 0.7 * natural_wet_bulb_temperature + 0.2 * globe_temperature + 0.1 * temperature;
+
+    Ok(result)
+}
+
+///Formula for computing indoor (or outdoor without solar load) wet bulb globe temperature from
+///natural wet bulb and globe temperatures.
+///
+///Derived by C. P. Yaglou and D. Minard (1957). Control of heat casualties at military training centers.
+///AMA Archives of Industrial Health, 16(4), 302-316. Also provided in ISO 7243:2017.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `natural_wet_bulb_temperature` range: 233K - 333K\
+///Valid `globe_temperature` range: 233K - 373K
+#[cfg_attr(feature = "debug", logerr)]
+pub fn indoor1(
+    natural_wet_bulb_temperature: Float,
+    globe_temperature: Float,
+) -> Result<Float, InputError> {
+    validate_components(natural_wet_bulb_temperature, globe_temperature)?;
+
+    let result = 0.7 * natural_wet_bulb_temperature + 0.3 * globe_temperature;
+
+    Ok(result)
+}
+
+///Formula for computing outdoor wet bulb globe temperature from temperature, relative humidity, pressure,
+///wind speed at 10m, global horizontal solar radiation and solar zenith angle (in degrees).
+///
+///Natural wet bulb and globe temperatures are computed with [`natural_wet_bulb_temperature1`]
+///and [`globe_temperature1`] and then combined with [`outdoor1`].
+///
+///Derived by J. C. Liljegren et al. (2008) [(doi:10.1080/15459620802310770)](https://doi.org/10.1080/15459620802310770).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 243K - 323K\
+///Valid `relative_humidity` range: 0.0 - 1.0\
+///Valid `pressure` range: 50000Pa - 110000Pa\
+///Valid `wind_speed` range: 0m/s - 50m/s\
+///Valid `solar_radiation` range: 0W/m^2 - 1400W/m^2\
+///Valid `zenith_angle` range: 0 - 180
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the model iteration does not converge.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn liljegren1(
+    temperature: Float,
+    relative_humidity: Float,
+    pressure: Float,
+    wind_speed: Float,
+    solar_radiation: Float,
+    zenith_angle: Float,
+) -> Result<Float, InputError> {
+    let conditions = liljegren::Conditions::new(
+        temperature,
+        relative_humidity,
+        pressure,
+        wind_speed,
+        solar_radiation,
+        zenith_angle,
+    )?;
+
+    let natural_wet_bulb_temperature = conditions.natural_wet_bulb_temperature()?;
+    let globe_temperature = conditions.globe_temperature()?;
+
+    let result = 0.7 * natural_wet_bulb_temperature + 0.2 * globe_temperature + 0.1 * temperature;
+
+    Ok(result)
+}
+
+///Formula for computing natural wet bulb temperature from temperature, relative humidity, pressure,
+///wind speed at 10m, global horizontal solar radiation and solar zenith angle (in degrees).
+///
+///The energy balance of the wick is solved iteratively, with vapour pressure
+///computed with [`vapour_pressure::buck1`](crate::vapour_pressure::buck1) and latent heat
+///with [`latent_heat::vaporisation1`](crate::latent_heat::vaporisation1).
+///
+///Derived by J. C. Liljegren et al. (2008) [(doi:10.1080/15459620802310770)](https://doi.org/10.1080/15459620802310770).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 243K - 323K\
+///Valid `relative_humidity` range: 0.0 - 1.0\
+///Valid `pressure` range: 50000Pa - 110000Pa\
+///Valid `wind_speed` range: 0m/s - 50m/s\
+///Valid `solar_radiation` range: 0W/m^2 - 1400W/m^2\
+///Valid `zenith_angle` range: 0 - 180
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the model iteration does not converge.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn natural_wet_bulb_temperature1(
+    temperature: Float,
+    relative_humidity: Float,
+    pressure: Float,
+    wind_speed: Float,
+    solar_radiation: Float,
+    zenith_angle: Float,
+) -> Result<Float, InputError> {
+    liljegren::Conditions::new(
+        temperature,
+        relative_humidity,
+        pressure,
+        wind_speed,
+        solar_radiation,
+        zenith_angle,
+    )?
+    .natural_wet_bulb_temperature()
+}
+
+///Formula for computing globe temperature from temperature, relative humidity, pressure,
+///wind speed at 10m, global horizontal solar radiation and solar zenith angle (in degrees).
+///
+///The energy balance of the globe is solved iteratively.
+///
+///Derived by J. C. Liljegren et al. (2008) [(doi:10.1080/15459620802310770)](https://doi.org/10.1080/15459620802310770).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 243K - 323K\
+///Valid `relative_humidity` range: 0.0 - 1.0\
+///Valid `pressure` range: 50000Pa - 110000Pa\
+///Valid `wind_speed` range: 0m/s - 50m/s\
+///Valid `solar_radiation` range: 0W/m^2 - 1400W/m^2\
+///Valid `zenith_angle` range: 0 - 180
+///
+///Returns [`InputError::IncorrectArgumentSet`] when the model iteration does not converge.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn globe_temperature1(
+    temperature: Float,
+    relative_humidity: Float,
+    pressure: Float,
+    wind_speed: Float,
+    solar_radiation: Float,
+    zenith_angle: Float,
+) -> Result<Float, InputError> {
+    liljegren::Conditions::new(
+        temperature,
+        relative_humidity,
+        pressure,
+        wind_speed,
+        solar_radiation,
+        zenith_angle,
+    )?
+    .globe_temperature()
+}
+
+fn validate_components(
+    natural_wet_bulb_temperature: Float,
+    globe_temperature: Float,
+) -> Result<(), InputError> {
+    if !(233.0..=333.0).contains(&natural_wet_bulb_temperature) {
+        return Err(InputError::OutOfRange(String::from(
+            "natural_wet_bulb_temperature",
+        )));
+    }
+
+    if !(233.0..=373.0).contains(&globe_temperature) {
+        return Err(InputError::OutOfRange(String::from("globe_temperature")));
+    }
+
+    Ok(())
+}
+
+//Implementation of the model from Liljegren et al. (2008), instrument constants and transport
+//properties of air follow the paper, while vapour pressure, latent heat and physical constants
+//are taken from this crate
+mod liljegren {
+    use crate::Float;
+    use crate::{
+        constants::{C_P, EPSILON, M_D, M_V, R_D, STEFAN_BOLTZMANN},
+        errors::InputError,
+        latent_heat, vapour_pressure,
+    };
+
+    const SOLAR_CONSTANT: Float = 1367.0;
+
+    //wick of the natural wet bulb thermometer
+    const EMISSIVITY_WICK: Float = 0.95;
+    const ALBEDO_WICK: Float = 0.4;
+    const DIAMETER_WICK: Float = 0.007;
+    const LENGTH_WICK: Float = 0.0254;
+
+    //black globe
+    const EMISSIVITY_GLOBE: Float = 0.95;
+    const ALBEDO_GLOBE: Float = 0.05;
+    const DIAMETER_GLOBE: Float = 0.0508;
+
+    //ground surface
+    const EMISSIVITY_SURFACE: Float = 0.999;
+    const ALBEDO_SURFACE: Float = 0.45;
+
+    const MIN_COS_ZENITH: Float = 0.00873;
+    const MAX_NORMALIZED_SOLAR: Float = 0.85;
+    const MIN_WIND_SPEED: Float = 0.13;
+
+    //power law exponent for neutral stability over rural terrain
+    const WIND_EXPONENT: Float = 0.15;
+    const INSTRUMENT_HEIGHT: Float = 2.0;
+    const WIND_HEIGHT: Float = 10.0;
+
+    const CONVERGENCE: Float = 0.02;
+    const MAX_ITERATIONS: usize = 500;
+
+    pub(super) struct Conditions {
+        temperature: Float,
+        vapour_pressure: Float,
+        pressure: Float,
+        wind_speed: Float,
+        solar_radiation: Float,
+        direct_fraction: Float,
+        cos_zenith: Float,
+        atmospheric_emissivity: Float,
+    }
+
+    impl Conditions {
+        pub(super) fn new(
+            temperature: Float,
+            relative_humidity: Float,
+            pressure: Float,
+            wind_speed: Float,
+            solar_radiation: Float,
+            zenith_angle: Float,
+        ) -> Result<Self, InputError> {
+            if !(243.0..=323.0).contains(&temperature) {
+                return Err(InputError::OutOfRange(String::from("temperature")));
+            }
+
+            if !(0.0..=1.0).contains(&relative_humidity) {
+                return Err(InputError::OutOfRange(String::from("relative_humidity")));
+            }
+
+            if !(50000.0..=110_000.0).contains(&pressure) {
+                return Err(InputError::OutOfRange(String::from("pressure")));
+            }
+
+            if !(0.0..=50.0).contains(&wind_speed) {
+                return Err(InputError::OutOfRange(String::from("wind_speed")));
+            }
+
+            if !(0.0..=1400.0).contains(&solar_radiation) {
+                return Err(InputError::OutOfRange(String::from("solar_radiation")));
+            }
+
+            if !(0.0..=180.0).contains(&zenith_angle) {
+                return Err(InputError::OutOfRange(String::from("zenith_angle")));
+            }
+
+            let vapour_pressure =
+                relative_humidity * vapour_pressure::buck1(temperature, pressure)?;
+
+            let wind_speed = (wind_speed * (INSTRUMENT_HEIGHT / WIND_HEIGHT).powf(WIND_EXPONENT))
+                .max(MIN_WIND_SPEED);
+
+            //solar radiation is limited to physically plausible fraction of top of the atmosphere radiation
+            let cos_zenith = zenith_angle.to_radians().cos();
+            let (solar_radiation, direct_fraction) = if cos_zenith < MIN_COS_ZENITH {
+                (0.0, 0.0)
+            } else {
+                let top_solar_radiation = SOLAR_CONSTANT * cos_zenith;
+                let normalized_solar =
+                    (solar_radiation / top_solar_radiation).min(MAX_NORMALIZED_SOLAR);
+
+                let direct_fraction = if normalized_solar > 0.0 {
+                    (3.0 - 1.34 * normalized_solar - 1.65 / normalized_solar)
+                        .exp()
+                        .clamp(0.0, 0.9)
+                } else {
+                    0.0
+                };
+
+                (normalized_solar * top_solar_radiation, direct_fraction)
+            };
+
+            let atmospheric_emissivity = 0.575 * (vapour_pressure / 100.0).powf(0.143);
+
+            Ok(Self {
+                temperature,
+                vapour_pressure,
+                pressure,
+                wind_speed,
+                solar_radiation,
+                direct_fraction,
+                cos_zenith,
+                atmospheric_emissivity,
+            })
+        }
+
+        pub(super) fn globe_temperature(&self) -> Result<Float, InputError> {
+            let t = self.temperature;
+
+            //the ground surface is assumed to be at air temperature
+            let longwave =
+                0.5 * (self.atmospheric_emissivity * t.powi(4) + EMISSIVITY_SURFACE * t.powi(4));
+
+            let direct = if self.direct_fraction > 0.0 {
+                self.direct_fraction * (1.0 / (2.0 * self.cos_zenith) - 1.0)
+            } else {
+                0.0
+            };
+
+            let shortwave = self.solar_radiation / (2.0 * STEFAN_BOLTZMANN * EMISSIVITY_GLOBE)
+                * (1.0 - ALBEDO_GLOBE)
+                * (direct + 1.0 + ALBEDO_SURFACE);
+
+            iterate(t, |previous| {
+                let reference_temperature = 0.5 * (previous + t);
+                let h = self.sphere_heat_transfer(reference_temperature);
+
+                let result = (longwave
+                    - h / (STEFAN_BOLTZMANN * EMISSIVITY_GLOBE) * (previous - t)
+                    + shortwave)
+                    .powf(0.25);
+
+                Ok(result)
+            })
+        }
+
+        pub(super) fn natural_wet_bulb_temperature(&self) -> Result<Float, InputError> {
+            let t = self.temperature;
+
+            let longwave =
+                0.5 * (self.atmospheric_emissivity * t.powi(4) + EMISSIVITY_SURFACE * t.powi(4));
+
+            let direct = if self.direct_fraction > 0.0 {
+                let zenith = self.cos_zenith.acos();
+                self.direct_fraction
+                    * ((zenith.tan() / std::f64::consts::PI as Float)
+                        + 0.25 * DIAMETER_WICK / LENGTH_WICK)
+            } else {
+                0.0
+            };
+
+            let shortwave = (1.0 - ALBEDO_WICK)
+                * self.solar_radiation
+                * ((1.0 - self.direct_fraction) * (1.0 + 0.25 * DIAMETER_WICK / LENGTH_WICK)
+                    + direct
+                    + ALBEDO_SURFACE);
+
+            let prandtl = prandtl_number();
+
+            iterate(t, |previous| {
+                let reference_temperature = 0.5 * (previous + t);
+                let h = self.cylinder_heat_transfer(reference_temperature);
+
+                let radiation =
+                    STEFAN_BOLTZMANN * EMISSIVITY_WICK * (longwave - previous.powi(4)) + shortwave;
+
+                //wick temperature outside of the vapour pressure formula range means that the iteration diverges
+                let wick_vapour_pressure = vapour_pressure::buck1(previous, self.pressure)
+                    .map_err(|_| not_converged())?;
+                let density = self.pressure / (R_D * reference_temperature);
+                let schmidt = viscosity(reference_temperature)
+                    / (density * diffusivity(reference_temperature, self.pressure));
+
+                let latent_heat = latent_heat::vaporisation1(reference_temperature)
+                    .map_err(|_| not_converged())?;
+
+                let result = t - latent_heat / (C_P / EPSILON)
+                    * (wick_vapour_pressure - self.vapour_pressure)
+                    / (self.pressure - wick_vapour_pressure)
+                    * (prandtl / schmidt).powf(0.56)
+                    + radiation / h;
+
+                Ok(result)
+            })
+        }
+
+        //convective heat transfer coefficient for flow around a sphere
+        fn sphere_heat_transfer(&self, temperature: Float) -> Float {
+            let density = self.pressure / (R_D * temperature);
+            let reynolds = self.wind_speed * density * DIAMETER_GLOBE / viscosity(temperature);
+            let nusselt = 2.0 + 0.6 * reynolds.sqrt() * prandtl_number().powf(0.3333);
+
+            nusselt * thermal_conductivity(temperature) / DIAMETER_GLOBE
+        }
+
+        //convective heat transfer coefficient for flow across a cylinder
+        fn cylinder_heat_transfer(&self, temperature: Float) -> Float {
+            let density = self.pressure / (R_D * temperature);
+            let reynolds = self.wind_speed * density * DIAMETER_WICK / viscosity(temperature);
+            let nusselt = 0.281 * reynolds.powf(1.0 - 0.4) * prandtl_number().powf(1.0 - 0.56);
+
+            nusselt * thermal_conductivity(temperature) / DIAMETER_WICK
+        }
+    }
+
+    //damped fixed-point iteration of the energy balance
+    fn iterate<F>(initial: Float, function: F) -> Result<Float, InputError>
+    where
+        F: Fn(Float) -> Result<Float, InputError>,
+    {
+        let mut previous = initial;
+
+        for _ in 0..MAX_ITERATIONS {
+            let next = function(previous)?;
+
+            if !next.is_finite() {
+                break;
+            }
+
+            if (next - previous).abs() < CONVERGENCE {
+                return Ok(next);
+            }
+
+            previous = 0.9 * previous + 0.1 * next;
+        }
+
+        Err(not_converged())
+    }
+
+    fn not_converged() -> InputError {
+        InputError::IncorrectArgumentSet(String::from(
+            "Liljegren model iteration does not converge for given inputs",
+        ))
+    }
+
+    fn prandtl_number() -> Float {
+        C_P / (C_P + 1.25 * R_D)
+    }
+
+    //dynamic viscosity of air in kg/(m s)
+    fn viscosity(temperature: Float) -> Float {
+        let sigma = 3.617;
+        let eps_kappa = 97.0;
+
+        let reduced_temperature = temperature / eps_kappa;
+        let omega = (reduced_temperature - 2.9) / 0.4 * (-0.034) + 1.048;
+
+        2.6693e-6 * (M_D * 1000.0 * temperature).sqrt() / (sigma * sigma * omega)
+    }
+
+    //thermal conductivity of air in W/(m K)
+    fn thermal_conductivity(temperature: Float) -> Float {
+        (C_P + 1.25 * R_D) * viscosity(temperature)
+    }
+
+    //diffusivity of water vapour in air in m^2/s
+    fn diffusivity(temperature: Float, pressure: Float) -> Float {
+        let critical_pressure: Float = 36.4 * 218.0;
+        let critical_temperature: Float = 132.0 * 647.3;
+        let molar_mass = (1.0 / (M_D * 1000.0) + 1.0 / (M_V * 1000.0)).sqrt();
+
+        3.640e-4
+            * (temperature / critical_temperature.sqrt()).powf(2.334)
+            * critical_pressure.cbrt()
+            * critical_temperature.powf(5.0 / 12.0)
+            * molar_mass
+            / (pressure / 101_325.0)
+            * 1.0e-4
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::InputError,
+        tests_framework::{self, Argument},
+        vapour_pressure, wet_bulb_globe_temperature, wet_bulb_temperature, Float,
+    };
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn outdoor1() {
+        assert!(tests_framework::test_with_3args(
+            &wet_bulb_globe_temperature::outdoor1,
+            Argument {
+                name: "temperature",
+                def_val: 303.15,
+                range: [233.0, 333.0]
+            },
+            Argument {
+                name: "natural_wet_bulb_temperature",
+                def_val: 296.15,
+                range: [233.0, 333.0]
+            },
+            Argument {
+                name: "globe_temperature",
+                def_val: 318.15,
+                range: [233.0, 373.0]
+            },
+            301.25
+        ));
+    }
+
+    #[test]
+    fn indoor1() {
+        assert!(tests_framework::test_with_2args(
+            &wet_bulb_globe_temperature::indoor1,
+            Argument {
+                name: "natural_wet_bulb_temperature",
+                def_val: 296.15,
+                range: [233.0, 333.0]
+            },
+            Argument {
+                name: "globe_temperature",
+                def_val: 318.15,
+                range: [233.0, 373.0]
+            },
+            302.75
+        ));
+    }
+
+    #[test]
+    fn liljegren1() {
+        //in strong wind without solar radiation convection dominates the energy balance,
+        //so globe approaches air temperature and natural wet bulb approaches psychrometric wet bulb
+        for [temperature, relative_humidity] in [[303.15, 0.5], [293.15, 0.8]] {
+            let vapour_pressure =
+                relative_humidity * vapour_pressure::buck1(temperature, 101_325.0).unwrap();
+            let psychrometric =
+                wet_bulb_temperature::psychrometric1(temperature, 101_325.0, vapour_pressure)
+                    .unwrap();
+
+            let natural_wet_bulb = wet_bulb_globe_temperature::natural_wet_bulb_temperature1(
+                temperature,
+                relative_humidity,
+                101_325.0,
+                50.0,
+                0.0,
+                120.0,
+            )
+            .unwrap();
+            let globe = wet_bulb_globe_temperature::globe_temperature1(
+                temperature,
+                relative_humidity,
+                101_325.0,
+                50.0,
+                0.0,
+                120.0,
+            )
+            .unwrap();
+
+            assert_approx_eq!(Float, natural_wet_bulb, psychrometric, epsilon = 0.5);
+            assert_approx_eq!(Float, globe, temperature, epsilon = 0.5);
+        }
+
+        //solar radiation heats both thermometers above their ventilated values
+        let args = (303.15, 0.5, 101_325.0, 3.0, 800.0, 30.0);
+
+        let natural_wet_bulb = wet_bulb_globe_temperature::natural_wet_bulb_temperature1(
+            args.0, args.1, args.2, args.3, args.4, args.5,
+        )
+        .unwrap();
+        let globe = wet_bulb_globe_temperature::globe_temperature1(
+            args.0, args.1, args.2, args.3, args.4, args.5,
+        )
+        .unwrap();
+        let result =
+            wet_bulb_globe_temperature::liljegren1(args.0, args.1, args.2, args.3, args.4, args.5)
+                .unwrap();
+
+        let vapour_pressure = args.1 * vapour_pressure::buck1(args.0, args.2).unwrap();
+        let psychrometric =
+            wet_bulb_temperature::psychrometric1(args.0, args.2, vapour_pressure).unwrap();
+
+        assert!(natural_wet_bulb > psychrometric);
+        assert!(globe > args.0 + 5.0);
+
+        //regression values of this implementation, not taken from Liljegren's reference code
+        assert_approx_eq!(Float, natural_wet_bulb, 296.925, epsilon = 0.01);
+        assert_approx_eq!(Float, globe, 316.562, epsilon = 0.01);
+        assert_approx_eq!(
+            Float,
+            result,
+            wet_bulb_globe_temperature::outdoor1(args.0, natural_wet_bulb, globe).unwrap(),
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn liljegren1_night() {
+        //without solar radiation globe is colder than air because of longwave loss to the sky
+        //and natural wet bulb is close to the psychrometric wet bulb
+        let globe =
+            wet_bulb_globe_temperature::globe_temperature1(303.15, 0.5, 101_325.0, 3.0, 0.0, 120.0)
+                .unwrap();
+        assert!(globe < 303.15 && globe > 298.15);
+
+        let natural_wet_bulb = wet_bulb_globe_temperature::natural_wet_bulb_temperature1(
+            303.15, 0.5, 101_325.0, 3.0, 0.0, 120.0,
+        )
+        .unwrap();
+        assert!(natural_wet_bulb < 297.0 && natural_wet_bulb > 293.0);
+
+        //solar radiation is ignored when the sun is below horizon
+        let result =
+            wet_bulb_globe_temperature::liljegren1(303.15, 0.5, 101_325.0, 3.0, 500.0, 120.0)
+                .unwrap();
+        let expected =
+            wet_bulb_globe_temperature::liljegren1(303.15, 0.5, 101_325.0, 3.0, 0.0, 120.0)
+                .unwrap();
+        assert_approx_eq!(Float, result, expected, epsilon = 0.01);
+
+        assert_eq!(
+            wet_bulb_globe_temperature::liljegren1(303.15, 0.5, 101_325.0, 3.0, 2000.0, 30.0)
+                .unwrap_err(),
+            InputError::OutOfRange(String::from("solar_radiation"))
+        );
+    }
+}
@@ -2,9 +2,11 @@
 
 use crate::Float;
 use crate::{
-    constants::{C_P, R_D, ZERO_CELSIUS},
+    constants::{KAPPA, ZERO_CELSIUS},
+    dual::Number,
     errors::InputError,
 };
+use std::ops::{Div, Mul, Sub};
 
 #[cfg(feature="debug")]
 use floccus_proc::logerr;
@@ -18,20 +20,24 @@ use floccus_proc::logerr;
 ///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
 ///Valid `temperature` range: 257K - 377K\
 #[cfg_attr(feature = "debug", logerr)]
-pub fn davies_jones1(equivalent_potential_temperature: Float) -> Result<Float, InputError> {
-    if !(257.0..=377.0).contains(&equivalent_potential_temperature) {
+pub fn davies_jones1<N: Number>(equivalent_potential_temperature: N) -> Result<N, InputError>
+where
+    Float: Sub<N, Output = N> + Mul<N, Output = N> + Div<N, Output = N>,
+{
+    if !(257.0..=377.0).contains(&equivalent_potential_temperature.value()) {
         return Err(InputError::OutOfRange(String::from(
             "equivalent_potential_temperature",
         )));
     }
 
-    let lambda = C_P / R_D;
+    let lambda = KAPPA.recip();
 
     let result = 45.114 - 51.489 * (ZERO_CELSIUS / equivalent_potential_temperature).powf(lambda);
     Ok(result + ZERO_CELSIUS)
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
         tests_framework::{self, Argument},
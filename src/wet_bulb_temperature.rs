@@ -1,7 +1,13 @@
 //!Functions to calculate wet bulb temperature of unsaturated air in K.
 
-use crate::{constants::ZERO_CELSIUS, errors::InputError};
+use crate::{
+    constants::{C_P, C_PV, EPSILON, R_D, ZERO_CELSIUS},
+    dual::Number,
+    errors::InputError,
+    latent_heat, mixing_ratio, moist_adiabat, parcel, vapour_pressure,
+};
 use crate::Float;
+use std::ops::Mul;
 
 #[cfg(feature="debug")]
 use floccus_proc::logerr;
@@ -18,12 +24,15 @@ use floccus_proc::logerr;
 ///Valid `temperature` range: 253K - 324K\
 ///Valid `relative_humidity` range: 0.05 - 0.99
 #[cfg_attr(feature = "debug", logerr)]
-pub fn stull1(temperature: Float, relative_humidity: Float) -> Result<Float, InputError> {
-    if !(253.0..=324.0).contains(&temperature) {
+pub fn stull1<N: Number>(temperature: N, relative_humidity: N) -> Result<N, InputError>
+where
+    Float: Mul<N, Output = N>,
+{
+    if !(253.0..=324.0).contains(&temperature.value()) {
         return Err(InputError::OutOfRange(String::from("temperature")));
     }
 
-    if !(0.05..=0.99).contains(&relative_humidity) {
+    if !(0.05..=0.99).contains(&relative_humidity.value()) {
         return Err(InputError::OutOfRange(String::from("relative_humidity")));
     }
 
@@ -40,12 +49,332 @@ pub fn stull1(temperature: Float, relative_humidity: Float) -> Result<Float, Inp
     Ok(result + ZERO_CELSIUS)
 }
 
+///Formula for computing wet bulb temperature from dry bulb temperature, dewpoint and pressure
+///with Normand's rule.
+///
+///Air is lifted dry-adiabatically to its lifting condensation level ([`parcel::lifting_condensation_level1`])
+///and then lowered back to its initial pressure along saturated pseudo-adiabat ([`moist_adiabat::saturated_descent1`])
+///over liquid water.
+///The result is adiabatic wet bulb temperature, which slightly differs from the isobaric one.
+///
+///Derived by C. W. B. Normand (1921). Wet bulb temperatures and the thermodynamics of the air.
+///Memoirs of the India Meteorological Department, 23, 1-22.
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 253K - 324K\
+///Valid `dewpoint` range: 232K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `dewpoint` is higher than `temperature`.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn normand1(temperature: Float, dewpoint: Float, pressure: Float) -> Result<Float, InputError> {
+    if !(253.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(232.0..=324.0).contains(&dewpoint) {
+        return Err(InputError::OutOfRange(String::from("dewpoint")));
+    }
+
+    if !(100.0..=150_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    if dewpoint > temperature {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "dewpoint cannot be higher than temperature",
+        )));
+    }
+
+    let vapour_pressure = vapour_pressure::buck1(dewpoint, pressure)?;
+    let mixing_ratio = mixing_ratio::general1(pressure, vapour_pressure)?;
+
+    let [lcl_pressure, lcl_temperature] =
+        parcel::lifting_condensation_level1(temperature, pressure, mixing_ratio)?;
+
+    //parcel is saturated at the LCL
+    let result =
+        moist_adiabat::saturated_descent1(lcl_temperature, lcl_pressure, &[pressure], None)?[0]
+            .temperature;
+
+    Ok(result)
+}
+
+///Formula for computing isobaric wet bulb temperature from temperature, pressure and vapour pressure
+///by solving the psychrometric energy balance.
+///
+///Air is cooled at constant pressure by evaporating liquid water at wet bulb temperature into it until saturation,
+///with conserved enthalpy. Heat capacities of water vapour and liquid water, and therefore the temperature
+///dependence of latent heat of vaporization ([`latent_heat::vaporisation1`]), are included. Saturation vapour pressure
+///over (supercooled) water is computed with [`vapour_pressure::murphy_koop1`] and the energy balance is solved with bisection.
+///
+///Vapour pressure can be computed from any humidity variable: from dewpoint with [`vapour_pressure::buck1`],
+///from specific humidity with [`vapour_pressure::general1`], from mixing ratio with [`vapour_pressure::general2`]
+///or from relative humidity with [`vapour_pressure::saturation_specific1`].
+///
+///Provided in J. V. Iribarne and W. L. Godson (1981). Atmospheric Thermodynamics. Springer, Dordrecht.
+///[(doi:10.1007/978-94-009-8561-2)](https://doi.org/10.1007/978-94-009-8561-2)
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 193K - 324K\
+///Valid `pressure` range: 100Pa - 150000Pa\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `vapour_pressure` is higher than saturation vapour pressure,
+///saturation vapour pressure is not lower than `pressure` or wet bulb temperature is below 173K.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn psychrometric1(
+    temperature: Float,
+    pressure: Float,
+    vapour_pressure: Float,
+) -> Result<Float, InputError> {
+    if !(193.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(100.0..=150_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+        return Err(InputError::OutOfRange(String::from("vapour_pressure")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::murphy_koop1(temperature)?;
+
+    if saturation_vapour_pressure >= pressure {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "saturation vapour pressure must be lower than pressure",
+        )));
+    }
+
+    if vapour_pressure > saturation_vapour_pressure {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "vapour_pressure cannot be higher than saturation vapour pressure",
+        )));
+    }
+
+    let mixing_ratio = mixing_ratio::general1(pressure, vapour_pressure)?;
+
+    //heat released by cooling the air to wet bulb temperature minus heat used to evaporate water,
+    //it decreases monotonically with wet bulb temperature
+    let energy_balance = |wet_bulb: Float| -> Result<Float, InputError> {
+        let saturation_vapour_pressure = vapour_pressure::murphy_koop1(wet_bulb)?;
+        let saturation_mixing_ratio = mixing_ratio::general1(pressure, saturation_vapour_pressure)?;
+        let latent_heat = latent_heat::vaporisation1(wet_bulb)?;
+
+        Ok((C_P + mixing_ratio * C_PV) * (temperature - wet_bulb)
+            - (saturation_mixing_ratio - mixing_ratio) * latent_heat)
+    };
+
+    let mut lower = 173.0;
+    let mut upper = temperature;
+
+    if energy_balance(lower)? < 0.0 {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "wet bulb temperature is below 173K",
+        )));
+    }
+
+    for _ in 0..50 {
+        let middle = 0.5 * (lower + upper);
+
+        if energy_balance(middle)? > 0.0 {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+
+    Ok(0.5 * (lower + upper))
+}
+
+///Formula for computing wet bulb temperature from temperature, pressure and vapour pressure
+///with the method of Davies-Jones.
+///
+///Equivalent potential temperature is computed with the formula of D. Bolton (1980)
+///[(doi:10.1175/1520-0493(1980)108<1046:TCOEPT>2.0.CO;2)](https://doi.org/10.1175/1520-0493(1980)108%3C1046:TCOEPT%3E2.0.CO;2)
+///and wet bulb temperature is the temperature of saturated air with the same equivalent potential temperature at `pressure`.
+///It is found from the rational first guess refined with Newton's method.
+///The result is adiabatic wet bulb temperature, which is lower than isobaric one ([`psychrometric1`])
+///by up to about 2K in hot and dry conditions.
+///
+///Vapour pressure can be computed from any humidity variable as described in [`psychrometric1`].
+///
+///Derived by R. Davies-Jones (2008) [(doi:10.1175/2007MWR2224.1)](https://doi.org/10.1175/2007MWR2224.1)
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 193K - 324K\
+///Valid `pressure` range: 10000Pa - 110000Pa\
+///Valid `vapour_pressure` range: 0Pa - 10000Pa
+///
+///Returns [`InputError::IncorrectArgumentSet`] when `vapour_pressure` is higher than saturation vapour pressure
+///(computed with [`vapour_pressure::murphy_koop1`]), saturation vapour pressure is not lower than `pressure`
+///or Newton's method does not converge.
+#[cfg_attr(feature = "debug", logerr)]
+pub fn davies_jones1(
+    temperature: Float,
+    pressure: Float,
+    vapour_pressure: Float,
+) -> Result<Float, InputError> {
+    if !(193.0..=324.0).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(10_000.0..=110_000.0).contains(&pressure) {
+        return Err(InputError::OutOfRange(String::from("pressure")));
+    }
+
+    if !(0.0..=10_000.0).contains(&vapour_pressure) {
+        return Err(InputError::OutOfRange(String::from("vapour_pressure")));
+    }
+
+    let saturation_vapour_pressure = vapour_pressure::murphy_koop1(temperature)?;
+
+    if saturation_vapour_pressure >= pressure {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "saturation vapour pressure must be lower than pressure",
+        )));
+    }
+
+    if vapour_pressure > saturation_vapour_pressure {
+        return Err(InputError::IncorrectArgumentSet(String::from(
+            "vapour_pressure cannot be higher than saturation vapour pressure",
+        )));
+    }
+
+    //saturation vapour pressure used in the method is slightly different
+    let vapour_pressure =
+        vapour_pressure.min(davies_jones::saturation_vapour_pressure(temperature));
+
+    davies_jones::wet_bulb_temperature(temperature, pressure, vapour_pressure).ok_or_else(|| {
+        InputError::IncorrectArgumentSet(String::from(
+            "Newton's method for wet bulb temperature did not converge",
+        ))
+    })
+}
+
+//Implementation of the method from Davies-Jones (2008), temperatures in K unless stated otherwise
+mod davies_jones {
+    use super::{C_P, EPSILON, R_D, ZERO_CELSIUS};
+    use crate::Float;
+
+    const P0: Float = 100_000.0;
+
+    pub(super) fn wet_bulb_temperature(
+        temperature: Float,
+        pressure: Float,
+        vapour_pressure: Float,
+    ) -> Option<Float> {
+        let lambda = C_P / R_D;
+        let pi = (pressure / P0).powf(1.0 / lambda);
+
+        let mixing_ratio = EPSILON * vapour_pressure / (pressure - vapour_pressure);
+        let lcl_temperature =
+            2840.0 / (3.5 * temperature.ln() - (vapour_pressure / 100.0).ln() - 4.805) + 55.0;
+
+        let equivalent_temperature =
+            equivalent_potential_temperature(temperature, pressure, mixing_ratio, lcl_temperature)
+                * pi;
+        let target = (ZERO_CELSIUS / equivalent_temperature).powf(lambda);
+
+        //first guess (in C) from rational approximations of pseudoadiabats
+        let d = 1.0 / (0.1859 * pressure / P0 + 0.6512);
+        let k1 = -38.5 * pi * pi + 137.81 * pi - 53.737;
+        let k2 = -4.392 * pi * pi + 56.831 * pi - 0.384;
+
+        let first_guess = if target > d {
+            let a = 2675.0;
+            let saturation_mixing_ratio = saturation_mixing_ratio(equivalent_temperature, pressure);
+            let derivative = 17.67 * 243.5 / (equivalent_temperature - 29.65).powi(2);
+
+            equivalent_temperature
+                - ZERO_CELSIUS
+                - a * saturation_mixing_ratio / (1.0 + a * saturation_mixing_ratio * derivative)
+        } else if target >= 1.0 {
+            k1 - k2 * target
+        } else if target >= 0.4 {
+            (k1 - 1.21) - (k2 - 1.21) * target
+        } else {
+            (k1 - 2.66) - (k2 - 1.21) * target + 0.58 / target
+        };
+
+        //refinement with Newton's method
+        let function = |wet_bulb: Float| {
+            let saturation_mixing_ratio = saturation_mixing_ratio(wet_bulb, pressure);
+            let equivalent_temperature = equivalent_potential_temperature(
+                wet_bulb,
+                pressure,
+                saturation_mixing_ratio,
+                wet_bulb,
+            ) * pi;
+
+            (ZERO_CELSIUS / equivalent_temperature).powf(lambda) - target
+        };
+
+        let mut wet_bulb = (first_guess + ZERO_CELSIUS).min(temperature);
+        let step = 0.05;
+
+        for _ in 0..10 {
+            let derivative = (function(wet_bulb + step) - function(wet_bulb - step)) / (2.0 * step);
+            let correction = function(wet_bulb) / derivative;
+            wet_bulb -= correction;
+
+            //the first guess can be too far from the solution for very high equivalent potential temperature,
+            //then the iteration diverges or leaves the domain of the formulas
+            if !(173.0..=temperature + 0.01).contains(&wet_bulb) {
+                return None;
+            }
+
+            if correction.abs() < 0.001 {
+                return Some(wet_bulb);
+            }
+        }
+
+        None
+    }
+
+    //formula (10) of Bolton (1980) used in the paper
+    pub(super) fn saturation_vapour_pressure(temperature: Float) -> Float {
+        611.2 * (17.67 * (temperature - ZERO_CELSIUS) / (temperature - 29.65)).exp()
+    }
+
+    fn saturation_mixing_ratio(temperature: Float, pressure: Float) -> Float {
+        let saturation_vapour_pressure = saturation_vapour_pressure(temperature);
+        EPSILON * saturation_vapour_pressure / (pressure - saturation_vapour_pressure)
+    }
+
+    //formula (39) of Bolton (1980) with mixing ratio in kg/kg
+    fn equivalent_potential_temperature(
+        temperature: Float,
+        pressure: Float,
+        mixing_ratio: Float,
+        lcl_temperature: Float,
+    ) -> Float {
+        let mixing_ratio = mixing_ratio * 1000.0;
+
+        temperature
+            * (P0 / pressure).powf(0.2854 * (1.0 - 0.28e-3 * mixing_ratio))
+            * ((3.376 / lcl_temperature - 0.00254) * mixing_ratio * (1.0 + 0.81e-3 * mixing_ratio))
+                .exp()
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
     use crate::{
+        errors::InputError,
         tests_framework::{self, Argument},
-        wet_bulb_temperature,
+        vapour_pressure, wet_bulb_temperature, Float,
     };
+    use float_cmp::assert_approx_eq;
 
     #[test]
     fn stull1() {
@@ -64,4 +393,137 @@ mod tests {
             292.73867410526674
         ));
     }
+
+    #[test]
+    fn normand1() {
+        let result = wet_bulb_temperature::normand1(300.0, 290.0, 101325.0).unwrap();
+        assert_approx_eq!(Float, result, 293.256, epsilon = 0.01);
+
+        //relative humidity of 300K and 290K dewpoint
+        let stull = wet_bulb_temperature::stull1(300.0, 0.5434).unwrap();
+        assert_approx_eq!(Float, result, stull, epsilon = 1.0);
+
+        let saturated = wet_bulb_temperature::normand1(280.0, 280.0, 90000.0).unwrap();
+        assert_approx_eq!(Float, saturated, 280.0, epsilon = 0.01);
+
+        assert_eq!(
+            wet_bulb_temperature::normand1(250.0, 240.0, 101325.0).unwrap_err(),
+            InputError::OutOfRange(String::from("temperature"))
+        );
+        assert_eq!(
+            wet_bulb_temperature::normand1(300.0, 230.0, 101325.0).unwrap_err(),
+            InputError::OutOfRange(String::from("dewpoint"))
+        );
+        assert_eq!(
+            wet_bulb_temperature::normand1(300.0, 290.0, 50.0).unwrap_err(),
+            InputError::OutOfRange(String::from("pressure"))
+        );
+        assert!(matches!(
+            wet_bulb_temperature::normand1(290.0, 300.0, 101325.0),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+
+    #[test]
+    fn psychrometric1() {
+        assert!(tests_framework::test_with_3args(
+            &wet_bulb_temperature::psychrometric1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [193.0, 324.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 101325.0,
+                range: [100.0, 150_000.0]
+            },
+            Argument {
+                name: "vapour_pressure",
+                def_val: 2000.0,
+                range: [0.0, 10000.0]
+            },
+            293.659_667_215
+        ));
+
+        let vapour_pressure = vapour_pressure::buck1(290.0, 101325.0).unwrap();
+        let result =
+            wet_bulb_temperature::psychrometric1(300.0, 101325.0, vapour_pressure).unwrap();
+        assert_approx_eq!(Float, result, 293.317, epsilon = 0.01);
+
+        //isobaric wet bulb is warmer than adiabatic one
+        let normand = wet_bulb_temperature::normand1(300.0, 290.0, 101325.0).unwrap();
+        assert!(result > normand && result - normand < 0.1);
+
+        let vapour_pressure = vapour_pressure::buck1(240.0, 20000.0).unwrap();
+        let result = wet_bulb_temperature::psychrometric1(280.0, 20000.0, vapour_pressure).unwrap();
+        assert_approx_eq!(Float, result, 262.105, epsilon = 0.01);
+
+        //upper troposphere
+        let result = wet_bulb_temperature::psychrometric1(210.0, 20000.0, 0.5).unwrap();
+        assert_approx_eq!(Float, result, 209.941, epsilon = 0.01);
+
+        let saturation_vapour_pressure = vapour_pressure::murphy_koop1(200.0).unwrap();
+        let saturated =
+            wet_bulb_temperature::psychrometric1(200.0, 10000.0, saturation_vapour_pressure)
+                .unwrap();
+        assert_approx_eq!(Float, saturated, 200.0, epsilon = 0.01);
+
+        assert!(matches!(
+            wet_bulb_temperature::psychrometric1(300.0, 101325.0, 5000.0),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
+
+    #[test]
+    fn davies_jones1() {
+        assert!(tests_framework::test_with_3args(
+            &wet_bulb_temperature::davies_jones1,
+            Argument {
+                name: "temperature",
+                def_val: 300.0,
+                range: [193.0, 324.0]
+            },
+            Argument {
+                name: "pressure",
+                def_val: 101325.0,
+                range: [10000.0, 110_000.0]
+            },
+            Argument {
+                name: "vapour_pressure",
+                def_val: 2000.0,
+                range: [0.0, 10000.0]
+            },
+            293.639_687_184
+        ));
+
+        //method approximates the pseudo-adiabatic wet bulb temperature
+        for (temperature, dewpoint, pressure) in [
+            (300.0, 290.0, 101325.0),
+            (320.0, 250.0, 101325.0),
+            (280.0, 240.0, 20000.0),
+            (324.0, 300.0, 70000.0),
+        ] {
+            let vapour_pressure = vapour_pressure::buck1(dewpoint, pressure).unwrap();
+            let result =
+                wet_bulb_temperature::davies_jones1(temperature, pressure, vapour_pressure)
+                    .unwrap();
+            let normand = wet_bulb_temperature::normand1(temperature, dewpoint, pressure).unwrap();
+            assert_approx_eq!(Float, result, normand, epsilon = 0.15);
+        }
+
+        //upper troposphere
+        let result = wet_bulb_temperature::davies_jones1(210.0, 20000.0, 0.5).unwrap();
+        let psychrometric = wet_bulb_temperature::psychrometric1(210.0, 20000.0, 0.5).unwrap();
+        assert_approx_eq!(Float, result, psychrometric, epsilon = 0.01);
+
+        let dry = wet_bulb_temperature::davies_jones1(233.0, 10000.0, 0.0).unwrap();
+        assert_approx_eq!(Float, dry, 230.524, epsilon = 0.01);
+
+        //iteration does not converge for very high equivalent potential temperature
+        assert!(matches!(
+            wet_bulb_temperature::davies_jones1(308.0, 10000.0, 4000.0),
+            Err(InputError::IncorrectArgumentSet(_))
+        ));
+    }
 }
@@ -0,0 +1,72 @@
+//!Functions to calculate wind chill temperature in K.
+//!
+//!Wind chill is the temperature perceived by humans on exposed skin
+//!when cold air is combined with wind [Wikipedia](https://en.wikipedia.org/wiki/Wind_chill).
+//!Wind speed is measured at the standard height of 10m.
+
+use crate::Float;
+use crate::{constants::ZERO_CELSIUS, errors::InputError};
+
+#[cfg(feature = "debug")]
+use floccus_proc::logerr;
+
+///Formula for computing wind chill temperature from air temperature and wind speed
+///adopted by the Joint Action Group for Temperature Indices (JAG/TI) in 2001
+///and used by the U.S. National Weather Service and the Meteorological Service of Canada.
+///
+///The formula is defined only for temperature at or below 10°C (283.15K)
+///and wind speed at or above 4.8km/h (1.34m/s).
+///
+///Derived by R. Osczevski and M. Bluestein (2005)
+///[(doi:10.1175/BAMS-86-10-1453)](https://doi.org/10.1175/BAMS-86-10-1453).
+///
+///# Errors
+///
+///Returns [`InputError::OutOfRange`] when one of inputs is out of range.\
+///Valid `temperature` range: 223K - 283.15K\
+///Valid `wind_speed` range: 1.34m/s - 50m/s
+#[cfg_attr(feature = "debug", logerr)]
+pub fn osczevski_bluestein1(temperature: Float, wind_speed: Float) -> Result<Float, InputError> {
+    if !(223.0..=283.15).contains(&temperature) {
+        return Err(InputError::OutOfRange(String::from("temperature")));
+    }
+
+    if !(1.34..=50.0).contains(&wind_speed) {
+        return Err(InputError::OutOfRange(String::from("wind_speed")));
+    }
+
+    //convert units
+    let temperature = temperature - ZERO_CELSIUS;
+    let wind_factor = (wind_speed * 3.6).powf(0.16);
+
+    let result =
+        13.12 + 0.6215 * temperature - 11.37 * wind_factor + 0.3965 * temperature * wind_factor;
+
+    Ok(result + ZERO_CELSIUS)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests_framework::{self, Argument},
+        wind_chill,
+    };
+
+    #[test]
+    fn osczevski_bluestein1() {
+        assert!(tests_framework::test_with_2args(
+            &wind_chill::osczevski_bluestein1,
+            Argument {
+                name: "temperature",
+                def_val: 263.15,
+                range: [223.0, 283.15]
+            },
+            Argument {
+                name: "wind_speed",
+                def_val: 5.5556,
+                range: [1.34, 50.0]
+            },
+            255.289
+        ));
+    }
+}